@@ -0,0 +1,107 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::geometry::{GridGeometry, TOLERANCE};
+use crate::ordering::grid_flips_or_default;
+use crate::{Data, Header, ISG};
+
+/// Reason why two grids are not aligned, see [`ISG::is_aligned_with`]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Misalignment {
+    /// Either is not grid
+    NotGrid,
+    /// `coord type` differs
+    CoordType,
+    /// `coord units` differs
+    CoordUnits,
+    /// `data units` differs
+    DataUnits,
+    /// `data ordering` differs
+    DataOrdering,
+    /// Deltas differ
+    Delta,
+    /// Offset of origins is not an integer number of cells
+    Origin,
+}
+
+impl ISG {
+    /// Checks that grid nodes of `self` and `other` coincide,
+    /// so cell-wise operations between them are meaningful.
+    ///
+    /// Both must be grid of the same `coord type`, `coord units`, `data units`,
+    /// `data ordering` (where [`None`] is regarded as `N-to-S, W-to-E`) and deltas,
+    /// and the offset of origins must be an integer number of cells.
+    /// The extents may differ.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] of the first [`Misalignment`] found.
+    ///
+    /// ```
+    /// # use libisg::{Misalignment, Registration};
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// let sub = isg.subgrid(1..3, 2..5).unwrap();
+    /// assert_eq!(isg.is_aligned_with(&sub), Ok(()));
+    ///
+    /// let mut shifted = isg.clone();
+    /// assert!(shifted.shift_registration(Registration::CellCenters));
+    /// assert_eq!(isg.is_aligned_with(&shifted), Err(Misalignment::Origin));
+    /// ```
+    pub fn is_aligned_with(&self, other: &ISG) -> Result<(), Misalignment> {
+        if !matches!((&self.data, &other.data), (Data::Grid(_), Data::Grid(_))) {
+            return Err(Misalignment::NotGrid);
+        }
+        if self.header.coord_type != other.header.coord_type {
+            return Err(Misalignment::CoordType);
+        }
+        if self.header.coord_units != other.header.coord_units {
+            return Err(Misalignment::CoordUnits);
+        }
+        if self.header.data_units != other.header.data_units {
+            return Err(Misalignment::DataUnits);
+        }
+        if grid_flips_or_default(self.header.data_ordering)
+            != grid_flips_or_default(other.header.data_ordering)
+        {
+            return Err(Misalignment::DataOrdering);
+        }
+
+        check_nodes(&self.header, &other.header)
+    }
+}
+
+/// Checks deltas and origins of two grid headers of the same `coord units`.
+pub(crate) fn check_nodes(header: &Header, other: &Header) -> Result<(), Misalignment> {
+    let (bounds, other_bounds) = (&header.data_bounds, &other.data_bounds);
+    if bounds.delta_a() != other_bounds.delta_a() || bounds.delta_b() != other_bounds.delta_b() {
+        return Err(Misalignment::Delta);
+    }
+
+    let (geometry, other_geometry) = match (GridGeometry::new(header), GridGeometry::new(other)) {
+        (Some(geometry), Some(other_geometry)) => (geometry, other_geometry),
+        _ => return Err(Misalignment::NotGrid),
+    };
+
+    let (row, col) = other_geometry.frac_index(geometry.a_max, geometry.b_min);
+    if (row - row.round()).abs() > TOLERANCE || (col - col.round()).abs() > TOLERANCE {
+        return Err(Misalignment::Origin);
+    }
+
+    Ok(())
+}
+
+impl Error for Misalignment {}
+
+impl Display for Misalignment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotGrid => f.write_str("data is not grid"),
+            Self::CoordType => f.write_str("`coord type` mismatch"),
+            Self::CoordUnits => f.write_str("`coord units` mismatch"),
+            Self::DataUnits => f.write_str("`data units` mismatch"),
+            Self::DataOrdering => f.write_str("`data ordering` mismatch"),
+            Self::Delta => f.write_str("delta mismatch"),
+            Self::Origin => f.write_str("grid nodes are not aligned"),
+        }
+    }
+}
@@ -1,7 +1,7 @@
 // TODO: better impl
 
-use core::ops::{Add, Mul, Neg};
-use std::ops::Sub;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
 
 use crate::Coord;
 
@@ -21,14 +21,11 @@ impl Neg for &Coord {
     fn neg(self) -> Self::Output {
         match self {
             Coord::DMS {
+                negative,
                 degree,
                 minutes,
                 second,
-            } => Coord::DMS {
-                degree: -degree,
-                minutes: *minutes,
-                second: *second,
-            },
+            } => Coord::with_signed_dms(!negative, *degree, *minutes, *second),
             Coord::Dec(f) => Coord::Dec(-f),
         }
     }
@@ -63,31 +60,35 @@ macro_rules! impl_mul {
             }
         }
 
+        /// [`Coord::DMS`] is multiplied in seconds,
+        /// so minutes and second carry into the next unit.
+        ///
+        /// # Panics
+        ///
+        /// Panics when the degree of the result exceeds [`u16::MAX`] and `self` is [`Coord::DMS`].
         impl Mul<&$type> for &Coord {
             type Output = Coord;
 
             fn mul(self, rhs: &$type) -> Self::Output {
                 if *rhs == 0 {
                     return match self {
-                        Coord::DMS { .. } => Coord::DMS {
-                            degree: 0,
-                            minutes: 0,
-                            second: 0,
-                        },
+                        Coord::DMS { .. } => Coord::with_dms(0, 0, 0),
                         Coord::Dec(..) => Coord::Dec(0.0),
                     };
                 }
 
                 match self {
                     Coord::DMS {
+                        negative,
                         degree,
                         minutes,
                         second,
                     } => {
-                        let second = *second as u64;
-                        let minutes = *minutes as u64;
-                        let degree = *degree as i64;
-                        let rhs = *rhs as u64;
+                        // u128 holds every intermediate value without overflow
+                        let second = *second as u128;
+                        let minutes = *minutes as u128;
+                        let degree = *degree as u128;
+                        let rhs = *rhs as u128;
 
                         let temp = second * rhs;
                         let (second, carry) = (temp % 60, temp / 60);
@@ -95,14 +96,10 @@ macro_rules! impl_mul {
                         let temp = minutes * rhs + carry;
                         let (minutes, carry) = (temp % 60, temp / 60);
 
-                        let degree = if !degree.is_negative() {
-                            degree * rhs as i64 + carry as i64
-                        } else {
-                            degree * rhs as i64 - carry as i64
-                        };
-
                         Coord::DMS {
-                            degree: degree as i16,
+                            negative: *negative,
+                            degree: u16::try_from(degree * rhs + carry)
+                                .expect("`Coord::DMS` overflows"),
                             minutes: minutes as u8,
                             second: second as u8,
                         }
@@ -147,51 +144,18 @@ impl Add<Coord> for &Coord {
     }
 }
 
+/// [`Coord::DMS`] is added in seconds, so minutes and second carry into the next unit.
+///
+/// # Panics
+///
+/// Panics when the variants differ,
+/// or the degree of the result exceeds [`u16::MAX`].
 impl Add<&Coord> for &Coord {
     type Output = Coord;
 
     fn add(self, rhs: &Coord) -> Self::Output {
         match (self, rhs) {
-            (
-                Coord::DMS {
-                    degree: a_deg,
-                    minutes: a_min,
-                    second: a_sec,
-                },
-                Coord::DMS {
-                    degree: b_deg,
-                    minutes: b_min,
-                    second: b_sec,
-                },
-            ) => {
-                let a_sec = *a_sec as u64;
-                let b_sec = *b_sec as u64;
-                let a_min = *a_min as u64;
-                let b_min = *b_min as u64;
-                let a_deg = *a_deg as i64;
-                let b_deg = *b_deg as i64;
-
-                let temp = a_sec + b_sec;
-                let (second, carry) = if 60 <= temp {
-                    (temp - 60, 1)
-                } else {
-                    (temp, 0)
-                };
-
-                let temp = a_min + b_min + carry;
-                let (minutes, carry) = if 60 <= temp {
-                    (temp - 60, 1)
-                } else {
-                    (temp, 0)
-                };
-
-                let degree = a_deg + b_deg + carry as i64;
-                Coord::DMS {
-                    degree: degree as _,
-                    minutes: minutes as _,
-                    second: second as _,
-                }
-            }
+            (Coord::DMS { .. }, Coord::DMS { .. }) => from_seconds(seconds(self) + seconds(rhs)),
             (Coord::Dec(a), Coord::Dec(b)) => Coord::Dec(a + b),
             _ => unimplemented!("not supported ops: `Coord::DMS` + `Coord::Dec`"),
         }
@@ -225,49 +189,18 @@ impl Sub<Coord> for &Coord {
     }
 }
 
+/// [`Coord::DMS`] is subtracted in seconds, so minutes and second borrow from the next unit.
+///
+/// # Panics
+///
+/// Panics when the variants differ,
+/// or the degree of the result exceeds [`u16::MAX`].
 impl Sub<&Coord> for &Coord {
     type Output = Coord;
 
     fn sub(self, rhs: &Coord) -> Self::Output {
         match (self, rhs) {
-            (
-                Coord::DMS {
-                    degree: a_deg,
-                    minutes: a_min,
-                    second: a_sec,
-                },
-                Coord::DMS {
-                    degree: b_deg,
-                    minutes: b_min,
-                    second: b_sec,
-                },
-            ) => {
-                let a_sec = *a_sec as i64;
-                let b_sec = *b_sec as i64;
-                let a_min = *a_min as i64;
-                let b_min = *b_min as i64;
-                let a_deg = *a_deg as i64;
-                let b_deg = *b_deg as i64;
-
-                let (second, carry) = if a_sec >= b_sec {
-                    (a_sec - b_sec, 0)
-                } else {
-                    (60 + a_sec - b_sec, 1)
-                };
-
-                let (minutes, carry) = if a_min >= b_min + carry {
-                    (a_min - b_min - carry, 0)
-                } else {
-                    (60 + a_min - b_min - carry, 1)
-                };
-
-                let degree = a_deg - b_deg - carry;
-                Coord::DMS {
-                    degree: degree as _,
-                    minutes: minutes as _,
-                    second: second as _,
-                }
-            }
+            (Coord::DMS { .. }, Coord::DMS { .. }) => from_seconds(seconds(self) - seconds(rhs)),
             (Coord::Dec(a), Coord::Dec(b)) => Coord::Dec(a - b),
             _ => unimplemented!(
                 "not supported ops: `Coord::DMS` - `Coord::Dec` or `Coord::Dec` - `Coord::DMS`"
@@ -276,6 +209,219 @@ impl Sub<&Coord> for &Coord {
     }
 }
 
+macro_rules! impl_assign {
+    ($trait:ident, $method:ident, $op:ident, $op_method:ident) => {
+        impl $trait<Coord> for Coord {
+            #[inline]
+            fn $method(&mut self, rhs: Coord) {
+                *self = $op::$op_method(&*self, &rhs);
+            }
+        }
+
+        impl $trait<&Coord> for Coord {
+            #[inline]
+            fn $method(&mut self, rhs: &Coord) {
+                *self = $op::$op_method(&*self, rhs);
+            }
+        }
+    };
+}
+
+impl_assign!(AddAssign, add_assign, Add, add);
+impl_assign!(SubAssign, sub_assign, Sub, sub);
+
+macro_rules! impl_div {
+    ($type:tt) => {
+        impl Div<$type> for Coord {
+            type Output = Coord;
+
+            #[inline]
+            fn div(self, rhs: $type) -> Self::Output {
+                Div::div(&self, &rhs)
+            }
+        }
+
+        impl Div<$type> for &Coord {
+            type Output = Coord;
+
+            #[inline]
+            fn div(self, rhs: $type) -> Self::Output {
+                Div::div(self, &rhs)
+            }
+        }
+
+        impl Div<&$type> for Coord {
+            type Output = Coord;
+
+            #[inline]
+            fn div(self, rhs: &$type) -> Self::Output {
+                Div::div(&self, rhs)
+            }
+        }
+
+        /// [`Coord::DMS`] is divided in seconds,
+        /// and rounded to the nearest second (half away from zero).
+        ///
+        /// # Panics
+        ///
+        /// Panics when `rhs` is zero and `self` is [`Coord::DMS`].
+        impl Div<&$type> for &Coord {
+            type Output = Coord;
+
+            fn div(self, rhs: &$type) -> Self::Output {
+                match self {
+                    Coord::DMS { .. } => {
+                        // u128 holds any divisor without wrapping
+                        let (value, rhs) = (seconds(self), *rhs as u128);
+                        assert!(rhs != 0, "attempt to divide `Coord::DMS` by zero");
+
+                        // the quotient does not exceed `value`, so it fits into i64
+                        let quotient =
+                            ((2 * value.unsigned_abs() as u128 + rhs) / (2 * rhs)) as i64;
+                        from_seconds(if value.is_negative() {
+                            -quotient
+                        } else {
+                            quotient
+                        })
+                    }
+                    Coord::Dec(coord) => Coord::Dec(coord / *rhs as f64),
+                }
+            }
+        }
+    };
+}
+
+impl_div!(u8);
+impl_div!(u16);
+impl_div!(u32);
+impl_div!(u64);
+impl_div!(usize);
+
+macro_rules! impl_float {
+    ($trait:ident, $method:ident, $doc:literal) => {
+        impl $trait<f64> for Coord {
+            type Output = Coord;
+
+            #[inline]
+            fn $method(self, rhs: f64) -> Self::Output {
+                $trait::$method(&self, &rhs)
+            }
+        }
+
+        impl $trait<f64> for &Coord {
+            type Output = Coord;
+
+            #[inline]
+            fn $method(self, rhs: f64) -> Self::Output {
+                $trait::$method(self, &rhs)
+            }
+        }
+
+        impl $trait<&f64> for Coord {
+            type Output = Coord;
+
+            #[inline]
+            fn $method(self, rhs: &f64) -> Self::Output {
+                $trait::$method(&self, rhs)
+            }
+        }
+
+        #[doc = $doc]
+        ///
+        /// # Panics
+        ///
+        /// Panics when the result is not finite and `self` is [`Coord::DMS`].
+        impl $trait<&f64> for &Coord {
+            type Output = Coord;
+
+            fn $method(self, rhs: &f64) -> Self::Output {
+                match self {
+                    Coord::DMS { .. } => {
+                        let value = $trait::$method(seconds(self) as f64, rhs).round();
+                        assert!(value.is_finite(), "`Coord::DMS` overflows");
+                        from_seconds(value as i64)
+                    }
+                    Coord::Dec(coord) => Coord::Dec($trait::$method(coord, rhs)),
+                }
+            }
+        }
+    };
+}
+
+impl_float!(
+    Mul,
+    mul,
+    "[`Coord::DMS`] is multiplied in seconds, and rounded to the nearest second (half away from zero)."
+);
+impl_float!(
+    Div,
+    div,
+    "[`Coord::DMS`] is divided in seconds, and rounded to the nearest second (half away from zero)."
+);
+
+/// The sum of an empty iterator is `Coord::Dec(0.0)`.
+///
+/// # Panics
+///
+/// Panics when the variants differ, see [`Add`].
+impl Sum<Coord> for Coord {
+    #[inline]
+    fn sum<I: Iterator<Item = Coord>>(iter: I) -> Self {
+        iter.reduce(|acc, coord| acc + coord)
+            .unwrap_or(Coord::Dec(0.0))
+    }
+}
+
+/// The sum of an empty iterator is `Coord::Dec(0.0)`.
+///
+/// # Panics
+///
+/// Panics when the variants differ, see [`Add`].
+impl<'a> Sum<&'a Coord> for Coord {
+    #[inline]
+    fn sum<I: Iterator<Item = &'a Coord>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+/// Returns the signed total seconds of [`Coord::DMS`].
+#[inline]
+fn seconds(coord: &Coord) -> i64 {
+    match coord {
+        Coord::DMS {
+            negative,
+            degree,
+            minutes,
+            second,
+        } => {
+            let value = *degree as i64 * 3600 + *minutes as i64 * 60 + *second as i64;
+            if *negative {
+                -value
+            } else {
+                value
+            }
+        }
+        Coord::Dec(_) => unreachable!(),
+    }
+}
+
+/// Returns [`Coord::DMS`] of the signed total seconds.
+///
+/// # Panics
+///
+/// Panics when the degree exceeds [`u16::MAX`].
+#[inline]
+fn from_seconds(value: i64) -> Coord {
+    let abs = value.unsigned_abs();
+
+    Coord::with_signed_dms(
+        value.is_negative(),
+        u16::try_from(abs / 3600).expect("`Coord::DMS` overflows"),
+        (abs % 3600 / 60) as u8,
+        (abs % 60) as u8,
+    )
+}
+
 #[cfg(test)]
 mod test {
     use std::fs;
@@ -316,7 +462,7 @@ mod test {
 
         let pos: Vec<Vec<_>> = match &isg.data {
             Data::Grid(data) => data
-                .iter()
+                .rows()
                 .enumerate()
                 .map(|(nrow, row)| {
                     row.iter()
@@ -368,4 +514,124 @@ mod test {
             ]
         )
     }
+
+    #[test]
+    fn add_sub() {
+        let (a, b) = (Coord::with_dms(1, 30, 40), Coord::with_dms(0, 40, 30));
+        assert_eq!(a + b, Coord::with_dms(2, 11, 10));
+        assert_eq!(a - b, Coord::with_dms(0, 50, 10));
+        assert_eq!(-a + -a, Coord::with_dms(-3, 1, 20));
+        assert_eq!(-a - b, Coord::with_dms(-2, 11, 10));
+        assert_eq!(b - (a + a), Coord::with_dms(-2, 20, 50));
+        assert_eq!(-a + a, Coord::with_dms(0, 0, 0));
+
+        let mut c = a;
+        c += b;
+        assert_eq!(c, a + b);
+        c -= &b;
+        assert_eq!(c, a);
+
+        let mut c = Coord::Dec(1.5);
+        c += Coord::Dec(0.25);
+        c -= Coord::Dec(1.0);
+        assert_eq!(c, Coord::Dec(0.75));
+    }
+
+    #[test]
+    fn div() {
+        assert_eq!(Coord::with_dms(1, 0, 0) / 3_u8, Coord::with_dms(0, 20, 0));
+        // rounded to the nearest second
+        assert_eq!(Coord::with_dms(0, 0, 5) / 2_usize, Coord::with_dms(0, 0, 3));
+        assert_eq!(Coord::with_dms(0, 0, 4) / 3_u32, Coord::with_dms(0, 0, 1));
+        assert_eq!(
+            Coord::with_dms(-3, 0, 5) / 2_u64,
+            Coord::with_dms(-1, 30, 3)
+        );
+        let one = Coord::Dec(1.0);
+        assert_eq!(&one / 4_u16, Coord::Dec(0.25));
+        assert_eq!(Div::div(one, &4_u16), Coord::Dec(0.25));
+    }
+
+    #[test]
+    #[should_panic]
+    fn div_by_zero() {
+        let _ = Coord::with_dms(1, 0, 0) / 0_u8;
+    }
+
+    #[test]
+    fn div_large() {
+        assert_eq!(
+            Coord::with_dms(1, 0, 0) / u64::MAX,
+            Coord::with_dms(0, 0, 0)
+        );
+        assert_eq!(
+            Coord::with_dms(-1, 0, 0) / (1_u64 << 63),
+            Coord::with_dms(0, 0, 0)
+        );
+        assert_eq!(
+            Coord::with_signed_dms(false, u16::MAX, 0, 0) / 1_usize,
+            Coord::with_signed_dms(false, u16::MAX, 0, 0)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "`Coord::DMS` overflows")]
+    fn add_overflow() {
+        let _ = Coord::with_signed_dms(false, 40000, 0, 0) + Coord::with_dms(30000, 0, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "`Coord::DMS` overflows")]
+    fn mul_overflow() {
+        let _ = Coord::with_dms(1, 0, 0) * 65536_u32;
+    }
+
+    #[test]
+    #[should_panic(expected = "`Coord::DMS` overflows")]
+    fn mul_overflow_large() {
+        let _ = Coord::with_dms(0, 0, 1) * u64::MAX;
+    }
+
+    #[test]
+    fn float() {
+        assert_eq!(Coord::with_dms(1, 0, 0) * 0.5, Coord::with_dms(0, 30, 0));
+        assert_eq!(Coord::with_dms(0, 0, 1) * 2.5, Coord::with_dms(0, 0, 3));
+        assert_eq!(Coord::with_dms(-2, 0, 0) * 1.5, Coord::with_dms(-3, 0, 0));
+        assert_eq!(Coord::with_dms(1, 0, 0) / 8.0, Coord::with_dms(0, 7, 30));
+        assert_eq!(Coord::with_dms(0, 0, 1) / 3.0, Coord::with_dms(0, 0, 0));
+        let one = Coord::Dec(1.0);
+        assert_eq!(&one * 0.5, Coord::Dec(0.5));
+        assert_eq!(Mul::mul(one, &0.5), Coord::Dec(0.5));
+        assert_eq!(Coord::Dec(1.0) / 0.5, Coord::Dec(2.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn float_overflow() {
+        let _ = Coord::with_dms(1, 0, 0) / 0.0;
+    }
+
+    #[test]
+    fn sum() {
+        let coords = [
+            Coord::with_dms(40, 10, 0),
+            Coord::with_dms(40, 30, 0),
+            Coord::with_dms(40, 50, 0),
+        ];
+        let sum: Coord = coords.iter().sum();
+        assert_eq!(sum, Coord::with_dms(121, 30, 0));
+        // mean
+        assert_eq!(sum / coords.len(), Coord::with_dms(40, 30, 0));
+
+        assert_eq!(
+            vec![Coord::Dec(1.0), Coord::Dec(2.0)]
+                .into_iter()
+                .sum::<Coord>(),
+            Coord::Dec(3.0)
+        );
+        assert_eq!(
+            Vec::<Coord>::new().into_iter().sum::<Coord>(),
+            Coord::Dec(0.0)
+        );
+    }
 }
@@ -0,0 +1,758 @@
+use crate::geometry::{is_signed, lon_span, wrap_lon, METERS_PER_FOOT, TOLERANCE};
+use crate::{Coord, CoordUnits, Data, DataBounds, DataOrdering, Header, ISG};
+
+/// Extent of data bounds, see [`ISG::extent`].
+///
+/// Values are in decimal degrees for geodetic and in meters for projected.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Extent {
+    /// `lon min` or `east min`
+    pub west: f64,
+    /// `lat min` or `north min`
+    pub south: f64,
+    /// `lon max` or `east max`
+    pub east: f64,
+    /// `lat max` or `north max`
+    pub north: f64,
+}
+
+/// Grid registration, what `data bounds` of grid denote
+///
+/// ISG adopts [`Registration::CellCenters`],
+/// however, many raster formats adopt [`Registration::CellEdges`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Registration {
+    /// Bounds denote the outermost cell centers (pixel-is-point)
+    CellCenters,
+    /// Bounds denote the outer edges of the outermost cells (pixel-is-area)
+    CellEdges,
+}
+
+impl Default for Registration {
+    #[inline]
+    fn default() -> Self {
+        Self::CellCenters
+    }
+}
+
+/// Policy of [`ISG::reconcile_header`], which field is trusted
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ReconcilePolicy {
+    /// Trusts deltas, and recomputes `lat min` and `lon max` (`north min` and `east max`)
+    TrustDeltas,
+    /// Trusts bounds, and recomputes deltas
+    TrustBounds,
+}
+
+/// Report of [`ISG::reconcile_header`], pairs of the old and the new value of changed fields.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Reconciliation {
+    /// `nrows`
+    pub nrows: Option<(usize, usize)>,
+    /// `ncols`
+    pub ncols: Option<(usize, usize)>,
+    /// `data bounds`, including deltas
+    pub data_bounds: Option<(DataBounds, DataBounds)>,
+}
+
+impl Reconciliation {
+    /// Returns `true` if nothing is changed.
+    pub fn is_empty(&self) -> bool {
+        self.nrows.is_none() && self.ncols.is_none() && self.data_bounds.is_none()
+    }
+}
+
+impl DataBounds {
+    /// Returns `lat min` or `north min`.
+    ///
+    /// ```
+    /// # use libisg::Coord;
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// assert_eq!(isg.header.data_bounds.a_min(), Coord::with_dms(39, 50, 0));
+    /// ```
+    pub fn a_min(&self) -> Coord {
+        match self {
+            Self::GridGeodetic { lat_min, .. } | Self::SparseGeodetic { lat_min, .. } => *lat_min,
+            Self::GridProjected { north_min, .. } | Self::SparseProjected { north_min, .. } => {
+                *north_min
+            }
+        }
+    }
+
+    /// Returns `lat max` or `north max`.
+    ///
+    /// ```
+    /// # use libisg::Coord;
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// assert_eq!(isg.header.data_bounds.a_max(), Coord::with_dms(41, 10, 0));
+    /// ```
+    pub fn a_max(&self) -> Coord {
+        match self {
+            Self::GridGeodetic { lat_max, .. } | Self::SparseGeodetic { lat_max, .. } => *lat_max,
+            Self::GridProjected { north_max, .. } | Self::SparseProjected { north_max, .. } => {
+                *north_max
+            }
+        }
+    }
+
+    /// Returns `lon min` or `east min`.
+    ///
+    /// ```
+    /// # use libisg::Coord;
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// assert_eq!(isg.header.data_bounds.b_min(), Coord::with_dms(119, 50, 0));
+    /// ```
+    pub fn b_min(&self) -> Coord {
+        match self {
+            Self::GridGeodetic { lon_min, .. } | Self::SparseGeodetic { lon_min, .. } => *lon_min,
+            Self::GridProjected { east_min, .. } | Self::SparseProjected { east_min, .. } => {
+                *east_min
+            }
+        }
+    }
+
+    /// Returns `lon max` or `east max`.
+    ///
+    /// ```
+    /// # use libisg::Coord;
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// assert_eq!(isg.header.data_bounds.b_max(), Coord::with_dms(121, 50, 0));
+    /// ```
+    pub fn b_max(&self) -> Coord {
+        match self {
+            Self::GridGeodetic { lon_max, .. } | Self::SparseGeodetic { lon_max, .. } => *lon_max,
+            Self::GridProjected { east_max, .. } | Self::SparseProjected { east_max, .. } => {
+                *east_max
+            }
+        }
+    }
+
+    /// Returns `delta lat` or `delta north`, [`None`] when sparse.
+    ///
+    /// ```
+    /// # use libisg::Coord;
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// assert_eq!(isg.header.data_bounds.delta_a(), Some(Coord::with_dms(0, 20, 0)));
+    /// ```
+    pub fn delta_a(&self) -> Option<Coord> {
+        match self {
+            Self::GridGeodetic { delta_lat, .. } => Some(*delta_lat),
+            Self::GridProjected { delta_north, .. } => Some(*delta_north),
+            Self::SparseGeodetic { .. } | Self::SparseProjected { .. } => None,
+        }
+    }
+
+    /// Returns `delta lon` or `delta east`, [`None`] when sparse.
+    ///
+    /// ```
+    /// # use libisg::Coord;
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// assert_eq!(isg.header.data_bounds.delta_b(), Some(Coord::with_dms(0, 20, 0)));
+    /// ```
+    pub fn delta_b(&self) -> Option<Coord> {
+        match self {
+            Self::GridGeodetic { delta_lon, .. } => Some(*delta_lon),
+            Self::GridProjected { delta_east, .. } => Some(*delta_east),
+            Self::SparseGeodetic { .. } | Self::SparseProjected { .. } => None,
+        }
+    }
+
+    /// Returns `(nrows, ncols)` of grid computed from bounds and deltas,
+    /// where the bounds denote the outermost cell centers.
+    ///
+    /// Returns [`None`] when `self` is sparse, deltas are not positive,
+    /// the span of bounds is not a multiple of the delta (within tolerance)
+    /// or values are not consistent with `coord_units`.
+    ///
+    /// ```
+    /// # use libisg::CoordUnits;
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.2.isg").unwrap()).unwrap();
+    /// let bounds = &isg.header.data_bounds;
+    /// assert_eq!(bounds.expected_shape(&CoordUnits::Deg), Some((4, 6)));
+    /// assert_eq!(bounds.expected_shape(&CoordUnits::DMS), None);
+    /// ```
+    pub fn expected_shape(&self, coord_units: &CoordUnits) -> Option<(usize, usize)> {
+        let (a, b) = self.spans(coord_units)?;
+        Some((exact_count(a)?, exact_count(b)?))
+    }
+
+    /// Returns the max `(nrows, ncols)` of grid which does not exceed bounds,
+    /// [`None`] on the same condition as [`DataBounds::expected_shape`] except the multiple.
+    pub(crate) fn max_shape(&self, coord_units: &CoordUnits) -> Option<(usize, usize)> {
+        let (a, b) = self.spans(coord_units)?;
+        Some((
+            (a + TOLERANCE).floor() as usize + 1,
+            (b + TOLERANCE).floor() as usize + 1,
+        ))
+    }
+
+    /// Returns spans of bounds in units of cells.
+    #[inline]
+    fn spans(&self, coord_units: &CoordUnits) -> Option<(f64, f64)> {
+        let value = |coord: Coord| match (coord_units, coord) {
+            (CoordUnits::DMS, Coord::DMS { .. })
+            | (CoordUnits::Deg | CoordUnits::Meters | CoordUnits::Feet, Coord::Dec(_)) => {
+                Some(coord.as_f64())
+            }
+            _ => None,
+        };
+
+        let (delta_a, delta_b) = (value(self.delta_a()?)?, value(self.delta_b()?)?);
+        let (a_min, a_max) = (value(self.a_min())?, value(self.a_max())?);
+        let (b_min, b_max) = (value(self.b_min())?, value(self.b_max())?);
+
+        let span_a = a_max - a_min;
+        let span_b = match self {
+            Self::GridGeodetic { .. } => lon_span(b_min, b_max),
+            _ => b_max - b_min,
+        };
+
+        if delta_a > 0.0 && delta_b > 0.0 && span_a >= 0.0 && span_b >= 0.0 {
+            Some((span_a / delta_a, span_b / delta_b))
+        } else {
+            None
+        }
+    }
+}
+
+impl Header {
+    /// Returns the registration inferred from `data bounds`, `nrows` and `ncols`.
+    ///
+    /// It is [`Registration::CellCenters`] if the spans of bounds are `delta * (n - 1)`,
+    /// and [`Registration::CellEdges`] if `delta * n` (within tolerance).
+    /// Returns [`None`] when it is neither (or ambiguous), or `data bounds` is sparse.
+    ///
+    /// ```
+    /// # use libisg::Registration;
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.2.isg").unwrap()).unwrap();
+    /// assert_eq!(isg.header.registration(), Some(Registration::CellCenters));
+    /// ```
+    pub fn registration(&self) -> Option<Registration> {
+        let (a, b) = self.data_bounds.spans(&self.coord_units)?;
+        let (a, b) = (exact_count(a)?, exact_count(b)?);
+
+        let centers = a == self.nrows && b == self.ncols;
+        let edges = a == self.nrows + 1 && b == self.ncols + 1;
+        match (centers, edges) {
+            (true, false) => Some(Registration::CellCenters),
+            (false, true) => Some(Registration::CellEdges),
+            _ => None,
+        }
+    }
+
+    /// Returns deltas `(delta lat, delta lon)` (or `(delta north, delta east)`) as [`f64`],
+    /// in decimal degrees for geodetic and in meters for projected.
+    ///
+    /// `dms` is converted to decimal degrees and `feet` to meters (international foot).
+    /// Returns [`None`] when sparse.
+    ///
+    /// ```
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// let (delta_lat, delta_lon) = isg.header.cell_size().unwrap();
+    /// assert!((delta_lat - 1.0 / 3.0).abs() < 1e-12);
+    /// assert!((delta_lon - 1.0 / 3.0).abs() < 1e-12);
+    /// ```
+    pub fn cell_size(&self) -> Option<(f64, f64)> {
+        let bounds = &self.data_bounds;
+        let (delta_a, delta_b) = (bounds.delta_a()?.as_f64(), bounds.delta_b()?.as_f64());
+
+        Some(match self.coord_units {
+            CoordUnits::Feet => (delta_a * METERS_PER_FOOT, delta_b * METERS_PER_FOOT),
+            CoordUnits::DMS | CoordUnits::Deg | CoordUnits::Meters => (delta_a, delta_b),
+        })
+    }
+}
+
+impl ISG {
+    /// Returns the extent of `data bounds`.
+    ///
+    /// `dms` is converted to decimal degrees and `feet` to meters (international foot).
+    /// `west` is greater than `east` when a geodetic grid crosses the antimeridian.
+    ///
+    /// ```
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.2.isg").unwrap()).unwrap();
+    /// let extent = isg.extent();
+    /// assert_eq!((extent.south, extent.north), (40.0, 41.0));
+    /// assert_eq!((extent.west, extent.east), (120.0, 121.666667));
+    /// ```
+    pub fn extent(&self) -> Extent {
+        let bounds = &self.header.data_bounds;
+        let scale = match self.header.coord_units {
+            CoordUnits::Feet => METERS_PER_FOOT,
+            CoordUnits::DMS | CoordUnits::Deg | CoordUnits::Meters => 1.0,
+        };
+
+        Extent {
+            west: bounds.b_min().as_f64() * scale,
+            south: bounds.a_min().as_f64() * scale,
+            east: bounds.b_max().as_f64() * scale,
+            north: bounds.a_max().as_f64() * scale,
+        }
+    }
+}
+
+impl ISG {
+    /// Returns the extent of grid in `registration`,
+    /// where `data bounds` is assumed to denote the outermost cell centers as ISG adopts.
+    ///
+    /// [`Registration::CellCenters`] is the same as [`ISG::extent`],
+    /// and [`Registration::CellEdges`] is extended by a half delta on each side,
+    /// which is the area covered by cells, e.g. for exporting to raster formats.
+    /// For sparse, this is the same as [`ISG::extent`].
+    ///
+    /// ```
+    /// # use libisg::Registration;
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.2.isg").unwrap()).unwrap();
+    /// let extent = isg.extent_with(Registration::CellEdges);
+    /// assert!((extent.south - (40.0 - 0.333333 / 2.0)).abs() < 1e-9);
+    /// assert!((extent.north - (41.0 + 0.333333 / 2.0)).abs() < 1e-9);
+    /// ```
+    pub fn extent_with(&self, registration: Registration) -> Extent {
+        let extent = self.extent();
+        let cell_size = match registration {
+            Registration::CellCenters => None,
+            Registration::CellEdges => self.header.cell_size(),
+        };
+
+        match cell_size {
+            Some((delta_a, delta_b)) => Extent {
+                west: extent.west - delta_b / 2.0,
+                south: extent.south - delta_a / 2.0,
+                east: extent.east + delta_b / 2.0,
+                north: extent.north + delta_a / 2.0,
+            },
+            None => extent,
+        }
+    }
+}
+
+impl ISG {
+    /// Shifts `data bounds` of grid by a half delta so that they denote `to`,
+    /// where the current registration is inferred by [`Header::registration`].
+    ///
+    /// For [`Registration::CellCenters`], bounds move inward,
+    /// e.g. to fix bounds taken from a raster format of [`Registration::CellEdges`],
+    /// and for [`Registration::CellEdges`], bounds move outward.
+    /// Deltas, `nrows`, `ncols` and data are unchanged.
+    ///
+    /// Returns `false` and does nothing when `self` is sparse, the registration is not inferred
+    /// or already `to`, a half delta is not representable in `dms` (odd seconds),
+    /// or the shifted header is invalid, e.g. latitude exceeds ±90°.
+    ///
+    /// ```
+    /// # use libisg::{Coord, DataBounds, Registration};
+    /// # let mut isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.2.isg").unwrap()).unwrap();
+    /// assert_eq!(isg.header.registration(), Some(Registration::CellCenters));
+    /// assert!(!isg.shift_registration(Registration::CellCenters));
+    ///
+    /// // e.g. for raster formats
+    /// let expected = isg.extent_with(Registration::CellEdges);
+    /// assert!(isg.shift_registration(Registration::CellEdges));
+    /// assert_eq!(isg.header.registration(), Some(Registration::CellEdges));
+    /// assert!((isg.extent().south - expected.south).abs() < 1e-9);
+    /// ```
+    pub fn shift_registration(&mut self, to: Registration) -> bool {
+        if matches!(self.data, Data::Sparse(_)) {
+            return false;
+        }
+
+        let from = match self.header.registration() {
+            Some(from) => from,
+            None => return false,
+        };
+        if from == to {
+            return false;
+        }
+
+        let mut header = self.header.clone();
+        let shifted = match &mut header.data_bounds {
+            DataBounds::GridGeodetic {
+                lat_min,
+                lat_max,
+                lon_min,
+                lon_max,
+                delta_lat,
+                delta_lon,
+            } => match (half(delta_lat), half(delta_lon)) {
+                (Some(half_lat), Some(half_lon)) => {
+                    let signed = is_signed(lon_min, lon_max);
+                    let values = [
+                        shift_bound(lat_min, &half_lat, to, true),
+                        shift_bound(lat_max, &half_lat, to, false),
+                        wrap_lon(shift_bound(lon_min, &half_lon, to, true), signed),
+                        wrap_lon(shift_bound(lon_max, &half_lon, to, false), signed),
+                    ];
+                    set([lat_min, lat_max, lon_min, lon_max], values);
+                    lat_min.as_f64() >= -90.0 && lat_max.as_f64() <= 90.0
+                }
+                _ => false,
+            },
+            DataBounds::GridProjected {
+                north_min,
+                north_max,
+                east_min,
+                east_max,
+                delta_north,
+                delta_east,
+            } => match (half(delta_north), half(delta_east)) {
+                (Some(half_north), Some(half_east)) => {
+                    let values = [
+                        shift_bound(north_min, &half_north, to, true),
+                        shift_bound(north_max, &half_north, to, false),
+                        shift_bound(east_min, &half_east, to, true),
+                        shift_bound(east_max, &half_east, to, false),
+                    ];
+                    set([north_min, north_max, east_min, east_max], values);
+                    true
+                }
+                _ => false,
+            },
+            DataBounds::SparseGeodetic { .. } | DataBounds::SparseProjected { .. } => false,
+        };
+
+        if shifted && header.validate().is_ok() {
+            self.header = header;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Recomputes `data bounds` from the data.
+    ///
+    /// For grid, `lat min` and `lon max` (`north min` and `east max`) are recomputed
+    /// from `lat max`, `lon min`, deltas and the shape of data,
+    /// in the registration inferred from either axis of which span is consistent,
+    /// see [`Header::registration`], and [`Registration::CellCenters`] when it is not inferred.
+    /// For sparse, the bounds become min/max of records.
+    ///
+    /// Returns `false` and does nothing when data is empty.
+    ///
+    /// ```
+    /// # use libisg::{Coord, DataBounds};
+    /// # let mut isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// let expected = isg.header.data_bounds.clone();
+    /// if let DataBounds::GridGeodetic { lat_min, .. } = &mut isg.header.data_bounds {
+    ///     *lat_min = Coord::with_dms(0, 0, 0);
+    /// }
+    ///
+    /// assert!(isg.recompute_bounds());
+    /// assert_eq!(isg.header.data_bounds, expected);
+    /// ```
+    pub fn recompute_bounds(&mut self) -> bool {
+        match &self.data {
+            Data::Grid(grid) => {
+                if grid.nrows() == 0 || grid.ncols() == 0 {
+                    return false;
+                }
+
+                let registration = infer_registration(&self.header, grid.nrows(), grid.ncols());
+                let (nrows, ncols) = (
+                    intervals(grid.nrows(), registration),
+                    intervals(grid.ncols(), registration),
+                );
+                match &mut self.header.data_bounds {
+                    DataBounds::GridGeodetic {
+                        lat_min,
+                        lat_max,
+                        lon_min,
+                        lon_max,
+                        delta_lat,
+                        delta_lon,
+                    } => {
+                        *lat_min = *lat_max - *delta_lat * nrows;
+                        *lon_max =
+                            wrap_lon(*lon_min + *delta_lon * ncols, is_signed(lon_min, lon_max));
+                    }
+                    DataBounds::GridProjected {
+                        north_min,
+                        north_max,
+                        east_min,
+                        east_max,
+                        delta_north,
+                        delta_east,
+                    } => {
+                        *north_min = *north_max - *delta_north * nrows;
+                        *east_max = *east_min + *delta_east * ncols;
+                    }
+                    DataBounds::SparseGeodetic { .. } | DataBounds::SparseProjected { .. } => {
+                        return false
+                    }
+                }
+            }
+            Data::Sparse(data) => {
+                let first = match data.first() {
+                    Some(first) => first,
+                    None => return false,
+                };
+
+                let swap = matches!(self.header.data_ordering, Some(DataOrdering::EastNorthN));
+                let (mut a_min, mut a_max, mut b_min, mut b_max) =
+                    (first.0, first.0, first.1, first.1);
+                for (a, b, _) in data {
+                    for (c, min, max) in [(a, &mut a_min, &mut a_max), (b, &mut b_min, &mut b_max)]
+                    {
+                        if c.as_f64() < min.as_f64() {
+                            *min = *c;
+                        }
+                        if c.as_f64() > max.as_f64() {
+                            *max = *c;
+                        }
+                    }
+                }
+                if swap {
+                    std::mem::swap(&mut a_min, &mut b_min);
+                    std::mem::swap(&mut a_max, &mut b_max);
+                }
+
+                match &mut self.header.data_bounds {
+                    DataBounds::SparseGeodetic {
+                        lat_min,
+                        lat_max,
+                        lon_min,
+                        lon_max,
+                    } => set(
+                        [lat_min, lat_max, lon_min, lon_max],
+                        [a_min, a_max, b_min, b_max],
+                    ),
+                    DataBounds::SparseProjected {
+                        north_min,
+                        north_max,
+                        east_min,
+                        east_max,
+                    } => set(
+                        [north_min, north_max, east_min, east_max],
+                        [a_min, a_max, b_min, b_max],
+                    ),
+                    DataBounds::GridGeodetic { .. } | DataBounds::GridProjected { .. } => {
+                        return false
+                    }
+                }
+            }
+        }
+
+        true
+    }
+}
+
+impl ISG {
+    /// Fixes the header of which bounds, deltas and `nrows`/`ncols` are inconsistent,
+    /// and returns a report of changed fields.
+    ///
+    /// `nrows` and `ncols` follow the shape of data.
+    /// When the span of bounds of grid is not `delta * (n - 1)` ([`Registration::CellCenters`])
+    /// nor `delta * n` ([`Registration::CellEdges`]) in the registration of the header,
+    /// bounds or deltas are recomputed by `policy`,
+    /// where the registration is inferred as [`ISG::recompute_bounds`] does.
+    /// Deltas of axes which have no span (a cell of [`Registration::CellCenters`]) are not recomputed,
+    /// and bounds of grid which has no cell are not either.
+    ///
+    /// ```
+    /// # use libisg::{Coord, DataBounds, ReconcilePolicy};
+    /// # let mut isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// assert!(isg.reconcile_header(ReconcilePolicy::TrustBounds).is_empty());
+    ///
+    /// if let DataBounds::GridGeodetic { delta_lat, .. } = &mut isg.header.data_bounds {
+    ///     *delta_lat = Coord::with_dms(0, 30, 0);
+    /// }
+    /// let report = isg.reconcile_header(ReconcilePolicy::TrustBounds);
+    /// assert!(!report.is_empty());
+    /// assert!(matches!(
+    ///     isg.header.data_bounds,
+    ///     DataBounds::GridGeodetic { delta_lat, .. } if delta_lat == Coord::with_dms(0, 20, 0)
+    /// ));
+    /// ```
+    pub fn reconcile_header(&mut self, policy: ReconcilePolicy) -> Reconciliation {
+        let mut report = Reconciliation::default();
+
+        let (nrows, ncols) = match &self.data {
+            Data::Grid(grid) => (grid.nrows(), grid.ncols()),
+            Data::Sparse(data) => (data.len(), self.header.ncols),
+        };
+        if self.header.nrows != nrows {
+            report.nrows = Some((self.header.nrows, nrows));
+            self.header.nrows = nrows;
+        }
+        if self.header.ncols != ncols {
+            report.ncols = Some((self.header.ncols, ncols));
+            self.header.ncols = ncols;
+        }
+
+        if matches!(self.data, Data::Sparse(_)) || nrows == 0 || ncols == 0 {
+            return report;
+        }
+
+        let registration = infer_registration(&self.header, nrows, ncols);
+        let (nrows, ncols) = (
+            intervals(nrows, registration),
+            intervals(ncols, registration),
+        );
+
+        let old = self.header.data_bounds.clone();
+        match &mut self.header.data_bounds {
+            DataBounds::GridGeodetic {
+                lat_min,
+                lat_max,
+                lon_min,
+                lon_max,
+                delta_lat,
+                delta_lon,
+            } => {
+                let span = lat_max.as_f64() - lat_min.as_f64();
+                if !is_consistent(span, delta_lat, nrows) {
+                    match policy {
+                        ReconcilePolicy::TrustDeltas => {
+                            *lat_min = *lat_max - *delta_lat * nrows;
+                        }
+                        ReconcilePolicy::TrustBounds => rescale(delta_lat, span, nrows),
+                    }
+                }
+
+                let span = lon_span(lon_min.as_f64(), lon_max.as_f64());
+                if !is_consistent(span, delta_lon, ncols) {
+                    match policy {
+                        ReconcilePolicy::TrustDeltas => {
+                            *lon_max = wrap_lon(
+                                *lon_min + *delta_lon * ncols,
+                                is_signed(lon_min, lon_max),
+                            );
+                        }
+                        ReconcilePolicy::TrustBounds => rescale(delta_lon, span, ncols),
+                    }
+                }
+            }
+            DataBounds::GridProjected {
+                north_min,
+                north_max,
+                east_min,
+                east_max,
+                delta_north,
+                delta_east,
+            } => {
+                let span = north_max.as_f64() - north_min.as_f64();
+                if !is_consistent(span, delta_north, nrows) {
+                    match policy {
+                        ReconcilePolicy::TrustDeltas => {
+                            *north_min = *north_max - *delta_north * nrows;
+                        }
+                        ReconcilePolicy::TrustBounds => rescale(delta_north, span, nrows),
+                    }
+                }
+
+                let span = east_max.as_f64() - east_min.as_f64();
+                if !is_consistent(span, delta_east, ncols) {
+                    match policy {
+                        ReconcilePolicy::TrustDeltas => {
+                            *east_max = *east_min + *delta_east * ncols;
+                        }
+                        ReconcilePolicy::TrustBounds => rescale(delta_east, span, ncols),
+                    }
+                }
+            }
+            DataBounds::SparseGeodetic { .. } | DataBounds::SparseProjected { .. } => {}
+        }
+
+        if self.header.data_bounds != old {
+            report.data_bounds = Some((old, self.header.data_bounds.clone()));
+        }
+
+        report
+    }
+}
+
+/// Returns the number of cells of which span is `cells`,
+/// [`None`] when `cells` is not an integer within tolerance.
+#[inline]
+fn exact_count(cells: f64) -> Option<usize> {
+    let rounded = cells.round();
+    if (cells - rounded).abs() <= TOLERANCE {
+        Some(rounded as usize + 1)
+    } else {
+        None
+    }
+}
+
+/// Returns the registration of grid of `nrows` and `ncols` inferred from either axis,
+/// [`Registration::CellCenters`] when it is not inferred or the axes disagree.
+#[inline]
+fn infer_registration(header: &Header, nrows: usize, ncols: usize) -> Registration {
+    let (a, b) = match header.data_bounds.spans(&header.coord_units) {
+        Some(spans) => spans,
+        None => return Registration::CellCenters,
+    };
+
+    let infer = |cells: f64, n: usize| match exact_count(cells) {
+        Some(count) if count == n => Some(Registration::CellCenters),
+        Some(count) if count == n + 1 => Some(Registration::CellEdges),
+        _ => None,
+    };
+
+    match (infer(a, nrows), infer(b, ncols)) {
+        (Some(Registration::CellEdges), None | Some(Registration::CellEdges))
+        | (None, Some(Registration::CellEdges)) => Registration::CellEdges,
+        _ => Registration::CellCenters,
+    }
+}
+
+/// Returns the number of deltas in the span of `n` cells in `registration`, `n` must be positive.
+#[inline]
+fn intervals(n: usize, registration: Registration) -> usize {
+    match registration {
+        Registration::CellCenters => n - 1,
+        Registration::CellEdges => n,
+    }
+}
+
+/// Returns a half of `delta`, [`None`] when it is not representable (odd seconds of DMS).
+#[inline]
+fn half(delta: &Coord) -> Option<Coord> {
+    match delta {
+        Coord::DMS { .. } => {
+            let seconds = (delta.as_f64().abs() * 3600.0).round() as u64;
+            if seconds % 2 == 0 {
+                Some(Coord::dms_from_f64(delta.as_f64() / 2.0))
+            } else {
+                None
+            }
+        }
+        Coord::Dec(value) => Some(Coord::Dec(value / 2.0)),
+    }
+}
+
+/// Moves bound `coord` by `half` inward for [`Registration::CellCenters`]
+/// and outward for [`Registration::CellEdges`], where `is_min` is `true` for min bounds.
+#[inline]
+fn shift_bound(coord: &Coord, half: &Coord, to: Registration, is_min: bool) -> Coord {
+    if is_min == (to == Registration::CellCenters) {
+        coord + half
+    } else {
+        coord - half
+    }
+}
+
+/// Returns `true` if `span` is `delta * intervals`.
+#[inline]
+fn is_consistent(span: f64, delta: &Coord, intervals: usize) -> bool {
+    (span / delta.as_f64() - intervals as f64).abs() <= TOLERANCE
+}
+
+/// Sets `delta` to `span / intervals` keeping the variant, does nothing when there is no interval.
+#[inline]
+fn rescale(delta: &mut Coord, span: f64, intervals: usize) {
+    if intervals == 0 {
+        return;
+    }
+
+    let value = span / intervals as f64;
+    *delta = match delta {
+        Coord::DMS { .. } => Coord::dms_from_f64(value),
+        Coord::Dec(_) => Coord::Dec(value),
+    };
+}
+
+#[inline]
+fn set(fields: [&mut Coord; 4], values: [Coord; 4]) {
+    for (field, value) in fields.into_iter().zip(values) {
+        *field = value;
+    }
+}
@@ -0,0 +1,805 @@
+use crate::error::ValidationError;
+use crate::parse::HeaderField;
+use crate::{
+    Coord, CoordType, CoordUnits, CreationDate, Data, DataBounds, DataFormat, DataOrdering,
+    DataType, DataUnits, DmsRounding, Grid, Header, ModelType, TideSystem, ISG,
+};
+
+/// Builder of [`Header`], see [`Header::builder`].
+///
+/// `coord type`, `coord units` and bounds are required.
+/// `data format` is grid if deltas are given, otherwise sparse, unless it is given explicitly.
+/// `nrows` and `ncols` of grid are derived from bounds and deltas if not given,
+/// see [`DataBounds::expected_shape`], and `ncols` of sparse is `3` if not given.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct HeaderBuilder {
+    model_name: Option<String>,
+    model_year: Option<String>,
+    model_type: Option<ModelType>,
+    data_type: Option<DataType>,
+    data_units: Option<DataUnits>,
+    data_format: Option<DataFormat>,
+    data_ordering: Option<DataOrdering>,
+    ref_ellipsoid: Option<String>,
+    ref_frame: Option<String>,
+    height_datum: Option<String>,
+    tide_system: Option<TideSystem>,
+    coord_type: Option<CoordType>,
+    coord_units: Option<CoordUnits>,
+    map_projection: Option<String>,
+    epsg_code: Option<String>,
+    bounds: Option<[Coord; 4]>,
+    deltas: Option<[Coord; 2]>,
+    nrows: Option<usize>,
+    ncols: Option<usize>,
+    nodata: Option<f64>,
+    creation_date: Option<CreationDate>,
+    dms_rounding: Option<DmsRounding>,
+}
+
+/// Builder of [`ISG`], see [`ISG::builder`].
+///
+/// `data format`, `nrows` and `ncols` of the header are derived from data,
+/// unless `nrows` and `ncols` are given explicitly.
+#[derive(Debug, Default)]
+pub struct IsgBuilder {
+    comment: String,
+    header: HeaderBuilder,
+    data: Option<Result<Data, ValidationError>>,
+}
+
+impl Header {
+    /// Makes a new [`HeaderBuilder`].
+    ///
+    /// ```
+    /// # use libisg::{Coord, CoordType, CoordUnits, DataFormat, Header};
+    /// let header = Header::builder()
+    ///     .model_name("EXAMPLE")
+    ///     .coord_type(CoordType::Geodetic)
+    ///     .coord_units(CoordUnits::Deg)
+    ///     .bounds(Coord::Dec(40.0), Coord::Dec(41.0), Coord::Dec(120.0), Coord::Dec(122.0))
+    ///     .deltas(Coord::Dec(0.5), Coord::Dec(0.5))
+    ///     .nodata(-9999.0)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(header.data_format, DataFormat::Grid);
+    /// assert_eq!((header.nrows, header.ncols), (3, 5));
+    /// ```
+    #[inline]
+    pub fn builder() -> HeaderBuilder {
+        HeaderBuilder::new()
+    }
+
+    /// Makes a header of geodetic grid, the other optional fields are [`None`].
+    ///
+    /// Values of [`Coord`] should be consistent with `coord_units`, see [`ISG::validate`].
+    ///
+    /// ```
+    /// # use libisg::{Coord, CoordUnits, DataFormat, Header};
+    /// let header = Header::grid_geodetic(
+    ///     CoordUnits::Deg,
+    ///     Coord::Dec(40.0),
+    ///     Coord::Dec(41.0),
+    ///     Coord::Dec(120.0),
+    ///     Coord::Dec(122.0),
+    ///     Coord::Dec(0.5),
+    ///     Coord::Dec(0.5),
+    ///     3,
+    ///     5,
+    /// );
+    ///
+    /// assert_eq!(header.data_format, DataFormat::Grid);
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn grid_geodetic(
+        coord_units: CoordUnits,
+        lat_min: Coord,
+        lat_max: Coord,
+        lon_min: Coord,
+        lon_max: Coord,
+        delta_lat: Coord,
+        delta_lon: Coord,
+        nrows: usize,
+        ncols: usize,
+    ) -> Self {
+        Self::with_bounds(
+            DataFormat::Grid,
+            CoordType::Geodetic,
+            coord_units,
+            DataBounds::GridGeodetic {
+                lat_min,
+                lat_max,
+                lon_min,
+                lon_max,
+                delta_lat,
+                delta_lon,
+            },
+            nrows,
+            ncols,
+        )
+    }
+
+    /// Makes a header of projected grid, the other optional fields are [`None`].
+    ///
+    /// Values of [`Coord`] should be consistent with `coord_units`, see [`ISG::validate`].
+    ///
+    /// ```
+    /// # use libisg::{Coord, CoordType, CoordUnits, Header};
+    /// let header = Header::grid_projected(
+    ///     CoordUnits::Meters,
+    ///     Coord::Dec(0.0),
+    ///     Coord::Dec(100.0),
+    ///     Coord::Dec(0.0),
+    ///     Coord::Dec(200.0),
+    ///     Coord::Dec(50.0),
+    ///     Coord::Dec(50.0),
+    ///     3,
+    ///     5,
+    /// );
+    ///
+    /// assert_eq!(header.coord_type, CoordType::Projected);
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn grid_projected(
+        coord_units: CoordUnits,
+        north_min: Coord,
+        north_max: Coord,
+        east_min: Coord,
+        east_max: Coord,
+        delta_north: Coord,
+        delta_east: Coord,
+        nrows: usize,
+        ncols: usize,
+    ) -> Self {
+        Self::with_bounds(
+            DataFormat::Grid,
+            CoordType::Projected,
+            coord_units,
+            DataBounds::GridProjected {
+                north_min,
+                north_max,
+                east_min,
+                east_max,
+                delta_north,
+                delta_east,
+            },
+            nrows,
+            ncols,
+        )
+    }
+
+    /// Makes a header of geodetic sparse of which `ncols` is `3`,
+    /// the other optional fields are [`None`].
+    ///
+    /// Values of [`Coord`] should be consistent with `coord_units`, see [`ISG::validate`].
+    ///
+    /// ```
+    /// # use libisg::{Coord, CoordUnits, DataFormat, Header};
+    /// let header = Header::sparse_geodetic(
+    ///     CoordUnits::Deg,
+    ///     Coord::Dec(40.0),
+    ///     Coord::Dec(41.0),
+    ///     Coord::Dec(120.0),
+    ///     Coord::Dec(122.0),
+    ///     20,
+    /// );
+    ///
+    /// assert_eq!(header.data_format, DataFormat::Sparse);
+    /// ```
+    pub fn sparse_geodetic(
+        coord_units: CoordUnits,
+        lat_min: Coord,
+        lat_max: Coord,
+        lon_min: Coord,
+        lon_max: Coord,
+        nrows: usize,
+    ) -> Self {
+        Self::with_bounds(
+            DataFormat::Sparse,
+            CoordType::Geodetic,
+            coord_units,
+            DataBounds::SparseGeodetic {
+                lat_min,
+                lat_max,
+                lon_min,
+                lon_max,
+            },
+            nrows,
+            3,
+        )
+    }
+
+    /// Makes a header of projected sparse of which `ncols` is `3`,
+    /// the other optional fields are [`None`].
+    ///
+    /// Values of [`Coord`] should be consistent with `coord_units`, see [`ISG::validate`].
+    ///
+    /// ```
+    /// # use libisg::{Coord, CoordType, CoordUnits, Header};
+    /// let header = Header::sparse_projected(
+    ///     CoordUnits::Meters,
+    ///     Coord::Dec(0.0),
+    ///     Coord::Dec(100.0),
+    ///     Coord::Dec(0.0),
+    ///     Coord::Dec(200.0),
+    ///     20,
+    /// );
+    ///
+    /// assert_eq!(header.coord_type, CoordType::Projected);
+    /// ```
+    pub fn sparse_projected(
+        coord_units: CoordUnits,
+        north_min: Coord,
+        north_max: Coord,
+        east_min: Coord,
+        east_max: Coord,
+        nrows: usize,
+    ) -> Self {
+        Self::with_bounds(
+            DataFormat::Sparse,
+            CoordType::Projected,
+            coord_units,
+            DataBounds::SparseProjected {
+                north_min,
+                north_max,
+                east_min,
+                east_max,
+            },
+            nrows,
+            3,
+        )
+    }
+
+    #[inline]
+    fn with_bounds(
+        data_format: DataFormat,
+        coord_type: CoordType,
+        coord_units: CoordUnits,
+        data_bounds: DataBounds,
+        nrows: usize,
+        ncols: usize,
+    ) -> Self {
+        Self {
+            model_name: None,
+            model_year: None,
+            model_type: None,
+            data_type: None,
+            data_units: None,
+            data_format,
+            data_ordering: None,
+            ref_ellipsoid: None,
+            ref_frame: None,
+            height_datum: None,
+            tide_system: None,
+            coord_type,
+            coord_units,
+            map_projection: None,
+            EPSG_code: None,
+            data_bounds,
+            nrows,
+            ncols,
+            nodata: None,
+            creation_date: None,
+            ISG_format: "2.0".into(),
+        }
+    }
+
+    /// Makes a [`HeaderBuilder`] pre-populated from `self`.
+    ///
+    /// ```
+    /// # use libisg::CreationDate;
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// let header = isg
+    ///     .header
+    ///     .derive()
+    ///     .model_name("EXAMPLE-CORRECTED")
+    ///     .creation_date(CreationDate::new(2024, 1, 1))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(header.model_name.as_deref(), Some("EXAMPLE-CORRECTED"));
+    /// assert_eq!(header.data_bounds, isg.header.data_bounds);
+    /// ```
+    pub fn derive(&self) -> HeaderBuilder {
+        let bounds = &self.data_bounds;
+
+        HeaderBuilder {
+            model_name: self.model_name.clone(),
+            model_year: self.model_year.clone(),
+            model_type: self.model_type,
+            data_type: self.data_type,
+            data_units: self.data_units,
+            data_format: Some(self.data_format),
+            data_ordering: self.data_ordering,
+            ref_ellipsoid: self.ref_ellipsoid.clone(),
+            ref_frame: self.ref_frame.clone(),
+            height_datum: self.height_datum.clone(),
+            tide_system: self.tide_system,
+            coord_type: Some(self.coord_type),
+            coord_units: Some(self.coord_units),
+            map_projection: self.map_projection.clone(),
+            epsg_code: self.EPSG_code.clone(),
+            bounds: Some([
+                bounds.a_min(),
+                bounds.a_max(),
+                bounds.b_min(),
+                bounds.b_max(),
+            ]),
+            deltas: bounds
+                .delta_a()
+                .and_then(|delta_a| Some([delta_a, bounds.delta_b()?])),
+            nrows: Some(self.nrows),
+            ncols: Some(self.ncols),
+            nodata: self.nodata,
+            creation_date: self.creation_date,
+            dms_rounding: None,
+        }
+    }
+}
+
+impl HeaderBuilder {
+    /// Makes an empty [`HeaderBuilder`].
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets `model name`.
+    pub fn model_name(mut self, value: impl Into<String>) -> Self {
+        self.model_name = Some(value.into());
+        self
+    }
+
+    /// Sets `model year`.
+    pub fn model_year(mut self, value: impl Into<String>) -> Self {
+        self.model_year = Some(value.into());
+        self
+    }
+
+    /// Sets `model type`.
+    pub fn model_type(mut self, value: ModelType) -> Self {
+        self.model_type = Some(value);
+        self
+    }
+
+    /// Sets `data type`.
+    pub fn data_type(mut self, value: DataType) -> Self {
+        self.data_type = Some(value);
+        self
+    }
+
+    /// Sets `data units`.
+    pub fn data_units(mut self, value: DataUnits) -> Self {
+        self.data_units = Some(value);
+        self
+    }
+
+    /// Sets `data format`.
+    pub fn data_format(mut self, value: DataFormat) -> Self {
+        self.data_format = Some(value);
+        self
+    }
+
+    /// Sets `data ordering`.
+    pub fn data_ordering(mut self, value: DataOrdering) -> Self {
+        self.data_ordering = Some(value);
+        self
+    }
+
+    /// Sets `ref ellipsoid`.
+    pub fn ref_ellipsoid(mut self, value: impl Into<String>) -> Self {
+        self.ref_ellipsoid = Some(value.into());
+        self
+    }
+
+    /// Sets `ref frame`.
+    pub fn ref_frame(mut self, value: impl Into<String>) -> Self {
+        self.ref_frame = Some(value.into());
+        self
+    }
+
+    /// Sets `height datum`.
+    pub fn height_datum(mut self, value: impl Into<String>) -> Self {
+        self.height_datum = Some(value.into());
+        self
+    }
+
+    /// Sets `tide system`.
+    pub fn tide_system(mut self, value: TideSystem) -> Self {
+        self.tide_system = Some(value);
+        self
+    }
+
+    /// Sets `coord type`.
+    pub fn coord_type(mut self, value: CoordType) -> Self {
+        self.coord_type = Some(value);
+        self
+    }
+
+    /// Sets `coord units`.
+    pub fn coord_units(mut self, value: CoordUnits) -> Self {
+        self.coord_units = Some(value);
+        self
+    }
+
+    /// Sets `map projection`.
+    pub fn map_projection(mut self, value: impl Into<String>) -> Self {
+        self.map_projection = Some(value.into());
+        self
+    }
+
+    /// Sets `EPSG code`.
+    pub fn epsg_code(mut self, value: impl Into<String>) -> Self {
+        self.epsg_code = Some(value.into());
+        self
+    }
+
+    /// Sets `lat min`, `lat max`, `lon min` and `lon max`
+    /// (`north min`, `north max`, `east min` and `east max`).
+    pub fn bounds(mut self, a_min: Coord, a_max: Coord, b_min: Coord, b_max: Coord) -> Self {
+        self.bounds = Some([a_min, a_max, b_min, b_max]);
+        self
+    }
+
+    /// Sets `delta lat` and `delta lon` (`delta north` and `delta east`).
+    pub fn deltas(mut self, delta_a: Coord, delta_b: Coord) -> Self {
+        self.deltas = Some([delta_a, delta_b]);
+        self
+    }
+
+    /// Sets `nrows`.
+    pub fn nrows(mut self, value: usize) -> Self {
+        self.nrows = Some(value);
+        self
+    }
+
+    /// Sets `ncols`.
+    pub fn ncols(mut self, value: usize) -> Self {
+        self.ncols = Some(value);
+        self
+    }
+
+    /// Sets `nodata`.
+    pub fn nodata(mut self, value: f64) -> Self {
+        self.nodata = Some(value);
+        self
+    }
+
+    /// Sets `creation date`.
+    pub fn creation_date(mut self, value: CreationDate) -> Self {
+        self.creation_date = Some(value);
+        self
+    }
+
+    /// Converts bounds and deltas in decimal degrees into DMS by `value` on build,
+    /// when `coord type` is geodetic and `coord units` is `dms`.
+    ///
+    /// Without this, [`Coord::Dec`] is an error for `dms`.
+    ///
+    /// ```
+    /// # use libisg::{Coord, CoordType, CoordUnits, DataBounds, DmsRounding, Header};
+    /// let builder = Header::builder()
+    ///     .coord_type(CoordType::Geodetic)
+    ///     .coord_units(CoordUnits::DMS)
+    ///     .bounds(Coord::Dec(40.0), Coord::Dec(41.0), Coord::Dec(120.0), Coord::Dec(121.666667))
+    ///     .deltas(Coord::Dec(0.25), Coord::Dec(1.0 / 3.0));
+    ///
+    /// let header = builder.clone().dms_rounding(DmsRounding::Nearest).build().unwrap();
+    /// assert!(matches!(
+    ///     header.data_bounds,
+    ///     DataBounds::GridGeodetic { lon_max, delta_lon, .. }
+    ///         if lon_max == Coord::with_dms(121, 40, 0) && delta_lon == Coord::with_dms(0, 20, 0)
+    /// ));
+    ///
+    /// // 121.666667 is 121°40'00.0012"
+    /// let err = builder.dms_rounding(DmsRounding::Exact).build().unwrap_err();
+    /// assert_eq!(err.to_string(), "`lon max` is not representable in DMS");
+    /// ```
+    pub fn dms_rounding(mut self, value: DmsRounding) -> Self {
+        self.dms_rounding = Some(value);
+        self
+    }
+
+    /// Builds [`Header`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] when a required field is missing,
+    /// deltas are given to sparse, or the header is not valid (see [`ISG::validate`](crate::ISG::validate)).
+    ///
+    /// ```
+    /// # use libisg::{Coord, CoordType, CoordUnits, DataFormat, Header};
+    /// let err = Header::builder()
+    ///     .coord_type(CoordType::Geodetic)
+    ///     .coord_units(CoordUnits::DMS)
+    ///     .bounds(Coord::Dec(40.0), Coord::Dec(41.0), Coord::Dec(120.0), Coord::Dec(122.0))
+    ///     .nrows(10)
+    ///     .build()
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(err.to_string(), "unexpected data format on `lat min`");
+    /// ```
+    pub fn build(self) -> Result<Header, ValidationError> {
+        let coord_type = self
+            .coord_type
+            .ok_or_else(|| ValidationError::missing_header(HeaderField::CoordType))?;
+        let coord_units = self
+            .coord_units
+            .ok_or_else(|| ValidationError::missing_header(HeaderField::CoordUnits))?;
+        let bounds = self.bounds.ok_or_else(|| {
+            ValidationError::missing_header(match coord_type {
+                CoordType::Geodetic => HeaderField::LatMin,
+                CoordType::Projected => HeaderField::NorthMin,
+            })
+        })?;
+
+        let (bounds, deltas) = match (coord_type, coord_units, self.dms_rounding) {
+            (CoordType::Geodetic, CoordUnits::DMS, Some(rounding)) => (
+                to_dms(
+                    bounds,
+                    [
+                        HeaderField::LatMin,
+                        HeaderField::LatMax,
+                        HeaderField::LonMin,
+                        HeaderField::LonMax,
+                    ],
+                    rounding,
+                )?,
+                self.deltas
+                    .map(|deltas| {
+                        to_dms(
+                            deltas,
+                            [HeaderField::DeltaLat, HeaderField::DeltaLon],
+                            rounding,
+                        )
+                    })
+                    .transpose()?,
+            ),
+            _ => (bounds, self.deltas),
+        };
+        let [a_min, a_max, b_min, b_max] = bounds;
+
+        let data_format = self.data_format.unwrap_or(match deltas {
+            Some(_) => DataFormat::Grid,
+            None => DataFormat::Sparse,
+        });
+        let data_bounds = match (data_format, coord_type, deltas) {
+            (DataFormat::Grid, CoordType::Geodetic, Some([delta_lat, delta_lon])) => {
+                DataBounds::GridGeodetic {
+                    lat_min: a_min,
+                    lat_max: a_max,
+                    lon_min: b_min,
+                    lon_max: b_max,
+                    delta_lat,
+                    delta_lon,
+                }
+            }
+            (DataFormat::Grid, CoordType::Projected, Some([delta_north, delta_east])) => {
+                DataBounds::GridProjected {
+                    north_min: a_min,
+                    north_max: a_max,
+                    east_min: b_min,
+                    east_max: b_max,
+                    delta_north,
+                    delta_east,
+                }
+            }
+            (DataFormat::Sparse, CoordType::Geodetic, None) => DataBounds::SparseGeodetic {
+                lat_min: a_min,
+                lat_max: a_max,
+                lon_min: b_min,
+                lon_max: b_max,
+            },
+            (DataFormat::Sparse, CoordType::Projected, None) => DataBounds::SparseProjected {
+                north_min: a_min,
+                north_max: a_max,
+                east_min: b_min,
+                east_max: b_max,
+            },
+            (DataFormat::Grid, CoordType::Geodetic, None) => {
+                return Err(ValidationError::missing_header(HeaderField::DeltaLat))
+            }
+            (DataFormat::Grid, CoordType::Projected, None) => {
+                return Err(ValidationError::missing_header(HeaderField::DeltaNorth))
+            }
+            (DataFormat::Sparse, _, Some(_)) => {
+                return Err(ValidationError::data_bounds(data_format, coord_type))
+            }
+        };
+
+        let expected = match data_format {
+            DataFormat::Grid => data_bounds.expected_shape(&coord_units),
+            DataFormat::Sparse => None,
+        };
+        let nrows = self.nrows.or_else(|| expected.map(|shape| shape.0));
+        let ncols = match data_format {
+            DataFormat::Grid => self.ncols.or_else(|| expected.map(|shape| shape.1)),
+            DataFormat::Sparse => self.ncols.or(Some(3)),
+        };
+
+        let header = Header {
+            model_name: self.model_name,
+            model_year: self.model_year,
+            model_type: self.model_type,
+            data_type: self.data_type,
+            data_units: self.data_units,
+            data_format,
+            data_ordering: self.data_ordering,
+            ref_ellipsoid: self.ref_ellipsoid,
+            ref_frame: self.ref_frame,
+            height_datum: self.height_datum,
+            tide_system: self.tide_system,
+            coord_type,
+            coord_units,
+            map_projection: self.map_projection,
+            EPSG_code: self.epsg_code,
+            data_bounds,
+            nrows: nrows.unwrap_or(0),
+            ncols: ncols.unwrap_or(0),
+            nodata: self.nodata,
+            creation_date: self.creation_date,
+            ISG_format: "2.0".into(),
+        };
+        // reports an invalid header rather than undetermined nrows/ncols
+        header.validate()?;
+
+        if nrows.is_none() {
+            return Err(ValidationError::missing_header(HeaderField::NRows));
+        } else if ncols.is_none() {
+            return Err(ValidationError::missing_header(HeaderField::NCols));
+        }
+
+        Ok(header)
+    }
+}
+
+/// Converts [`Coord::Dec`] in `coords` into [`Coord::DMS`] by `rounding`.
+fn to_dms<const N: usize>(
+    mut coords: [Coord; N],
+    fields: [HeaderField; N],
+    rounding: DmsRounding,
+) -> Result<[Coord; N], ValidationError> {
+    for (coord, field) in coords.iter_mut().zip(fields) {
+        if let Coord::Dec(value) = coord {
+            *coord = Coord::dms_from_f64_with(*value, rounding)
+                .ok_or_else(|| ValidationError::unrepresentable(field))?;
+        }
+    }
+    Ok(coords)
+}
+
+impl ISG {
+    /// Makes a new [`IsgBuilder`].
+    ///
+    /// ```
+    /// # use libisg::{Coord, CoordType, CoordUnits, Header, ISG};
+    /// let header = Header::builder()
+    ///     .coord_type(CoordType::Geodetic)
+    ///     .coord_units(CoordUnits::Deg)
+    ///     .bounds(Coord::Dec(40.0), Coord::Dec(40.5), Coord::Dec(120.0), Coord::Dec(121.0))
+    ///     .deltas(Coord::Dec(0.5), Coord::Dec(0.5));
+    ///
+    /// let isg = ISG::builder()
+    ///     .comment("This is an example.")
+    ///     .header(header)
+    ///     .grid([[Some(1.0), Some(2.0), None], [Some(4.0), Some(5.0), Some(6.0)]])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!((isg.header.nrows, isg.header.ncols), (2, 3));
+    /// assert_eq!(isg.data[(1, 2)], Some(6.0));
+    /// ```
+    #[inline]
+    pub fn builder() -> IsgBuilder {
+        IsgBuilder::new()
+    }
+
+    /// Decomposes `self` into the comment, the header and data.
+    ///
+    /// ```
+    /// # use libisg::ISG;
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// let (comment, mut header, data) = isg.into_parts();
+    /// header.model_name = Some("EXAMPLE-RENAMED".into());
+    ///
+    /// let isg = ISG::from_parts(comment, header, data).unwrap();
+    /// assert_eq!(isg.header.model_name.as_deref(), Some("EXAMPLE-RENAMED"));
+    /// ```
+    #[inline]
+    pub fn into_parts(self) -> (String, Header, Data) {
+        (self.comment, self.header, self.data)
+    }
+
+    /// Assembles [`ISG`] from the comment, the header and data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] when the result is not valid, see [`ISG::validate`].
+    pub fn from_parts(
+        comment: impl Into<String>,
+        header: Header,
+        data: Data,
+    ) -> Result<ISG, ValidationError> {
+        let isg = ISG {
+            comment: comment.into(),
+            header,
+            data,
+        };
+        isg.validate()?;
+
+        Ok(isg)
+    }
+}
+
+impl IsgBuilder {
+    /// Makes an empty [`IsgBuilder`].
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the comment.
+    pub fn comment(mut self, value: impl Into<String>) -> Self {
+        self.comment = value.into();
+        self
+    }
+
+    /// Sets the header.
+    pub fn header(mut self, value: HeaderBuilder) -> Self {
+        self.header = value;
+        self
+    }
+
+    /// Sets data.
+    pub fn data(mut self, value: Data) -> Self {
+        self.data = Some(Ok(value));
+        self
+    }
+
+    /// Sets grid data from rows, see [`Data::new_grid`].
+    ///
+    /// Rows of different lengths are reported by [`IsgBuilder::build`].
+    pub fn grid(
+        mut self,
+        rows: impl IntoIterator<Item = impl IntoIterator<Item = impl Into<Option<f64>>>>,
+    ) -> Self {
+        let rows: Vec<Vec<_>> = rows
+            .into_iter()
+            .map(|row| row.into_iter().map(Into::into).collect())
+            .collect();
+
+        self.data = Some(Grid::try_from(rows).map(Data::Grid));
+        self
+    }
+
+    /// Sets sparse data from records, see [`Data::new_sparse`].
+    pub fn sparse(
+        mut self,
+        records: impl IntoIterator<Item = impl Into<(Coord, Coord, f64)>>,
+    ) -> Self {
+        self.data = Some(Ok(Data::new_sparse(records)));
+        self
+    }
+
+    /// Builds [`ISG`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] when data is missing, rows of grid have different lengths,
+    /// [`HeaderBuilder::build`] fails or the result is not valid (see [`ISG::validate`]).
+    pub fn build(self) -> Result<ISG, ValidationError> {
+        let data = self.data.ok_or_else(ValidationError::missing_data)??;
+
+        let mut header = self.header;
+        let (data_format, nrows, ncols) = match &data {
+            Data::Grid(grid) => (DataFormat::Grid, grid.nrows(), grid.ncols()),
+            Data::Sparse(records) => (DataFormat::Sparse, records.len(), 3),
+        };
+        header.data_format = Some(data_format);
+        header.nrows = header.nrows.or(Some(nrows));
+        header.ncols = header.ncols.or(Some(ncols));
+
+        ISG::from_parts(self.comment, header.build()?, data)
+    }
+}
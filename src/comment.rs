@@ -0,0 +1,175 @@
+use std::fmt::{Display, Formatter};
+
+use crate::ISG;
+
+impl ISG {
+    /// Returns iterator of lines of the comment section.
+    #[inline]
+    pub fn comment_lines(&self) -> std::str::Lines<'_> {
+        self.comment.lines()
+    }
+
+    /// Appends a line to the comment section.
+    ///
+    /// Notes, the comment becomes invalid when `line` starts with `begin_of_head`,
+    /// see [`ISG::validate`].
+    pub fn push_comment_line(&mut self, line: &str) {
+        if !self.comment.is_empty() && !self.comment.ends_with('\n') {
+            self.comment.push('\n');
+        }
+        self.comment.push_str(line);
+        self.comment.push('\n');
+    }
+
+    /// Returns key-value metadata in the comment section.
+    #[inline]
+    pub fn comment_metadata(&self) -> CommentMetadata {
+        CommentMetadata::from_comment(&self.comment)
+    }
+
+    /// Writes key-value metadata into the comment section.
+    ///
+    /// Lines of existing keys are updated in place,
+    /// lines of keys not in `metadata` are removed
+    /// and new keys are appended to the end.
+    pub fn set_comment_metadata(&mut self, metadata: &CommentMetadata) {
+        let mut written = vec![false; metadata.len()];
+        let mut comment = String::with_capacity(self.comment.len());
+
+        for line in self.comment.lines() {
+            match parse_entry(line) {
+                None => {
+                    comment.push_str(line);
+                    comment.push('\n');
+                }
+                Some((key, _)) => {
+                    if let Some(pos) = metadata.position(key) {
+                        if !written[pos] {
+                            let (key, value) = &metadata.entries[pos];
+                            comment.push_str(&format!("{}: {}\n", key, value));
+                            written[pos] = true;
+                        }
+                    }
+                }
+            }
+        }
+
+        for ((key, value), written) in metadata.entries.iter().zip(written) {
+            if !written {
+                comment.push_str(&format!("{}: {}\n", key, value));
+            }
+        }
+
+        self.comment = comment;
+    }
+}
+
+/// Key-value metadata in the comment section.
+///
+/// A line of `key: value` form is an entry, e.g. `DOI: 10.5880/isg.2020.001`.
+/// The key consists of ASCII alphanumerics, space, `_`, `-` and `.`
+/// and starts with an ASCII alphabet,
+/// the separator is `:` followed by a space and the value is not empty.
+#[derive(Debug, Default, Eq, PartialEq, Clone)]
+pub struct CommentMetadata {
+    entries: Vec<(String, String)>,
+}
+
+impl CommentMetadata {
+    /// Makes empty [`CommentMetadata`].
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses entries from comment, lines of other form are ignored.
+    ///
+    /// The first one is taken if the key is duplicated.
+    pub fn from_comment(comment: &str) -> Self {
+        let mut this = Self::new();
+        for (key, value) in comment.lines().filter_map(parse_entry) {
+            if this.position(key).is_none() {
+                this.entries.push((key.into(), value.into()));
+            }
+        }
+        this
+    }
+
+    /// Returns the value of `key`.
+    #[inline]
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.position(key).map(|pos| self.entries[pos].1.as_str())
+    }
+
+    /// Inserts an entry, and returns old value if `key` exists.
+    ///
+    /// Notes, the entry is not parsable when `key` or `value` is invalid.
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) -> Option<String> {
+        let key = key.into();
+        let value = value.into();
+        match self.position(&key) {
+            Some(pos) => Some(std::mem::replace(&mut self.entries[pos].1, value)),
+            None => {
+                self.entries.push((key, value));
+                None
+            }
+        }
+    }
+
+    /// Removes an entry, and returns the value if `key` exists.
+    #[inline]
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        self.position(key).map(|pos| self.entries.remove(pos).1)
+    }
+
+    /// Returns iterator of entries by insertion order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    /// Returns the number of entries.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no entry.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    #[inline]
+    fn position(&self, key: &str) -> Option<usize> {
+        self.entries.iter().position(|(k, _)| k == key)
+    }
+}
+
+impl Display for CommentMetadata {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (key, value) in &self.entries {
+            writeln!(f, "{}: {}", key, value)?;
+        }
+        Ok(())
+    }
+}
+
+#[inline]
+fn parse_entry(line: &str) -> Option<(&str, &str)> {
+    let (key, value) = line.split_once(": ")?;
+
+    let key = key.trim();
+    let value = value.trim();
+
+    let valid_key = key.starts_with(|c: char| c.is_ascii_alphabetic())
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, ' ' | '_' | '-' | '.'));
+
+    if valid_key && !value.is_empty() {
+        Some((key, value))
+    } else {
+        None
+    }
+}
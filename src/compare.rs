@@ -0,0 +1,408 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+use crate::align::check_nodes;
+use crate::error::MismatchError;
+use crate::ordering::grid_flips_or_default;
+use crate::{Coord, Data, Header, Interpolator, Method, Misalignment, ISG};
+
+/// Number of discrepancies kept by [`ISG::compare`]
+const MAX_WORST: usize = 10;
+
+/// Report of [`ISG::compare`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct ComparisonReport {
+    /// Tolerance of absolute difference
+    pub tolerance: f64,
+    /// Differences of header fields
+    pub header: Vec<FieldDiff>,
+    /// Shapes of left and right data if they differ,
+    /// (`nrows`, `ncols`) of grid or (number of records, `3`) of sparse
+    pub shape: Option<((usize, usize), (usize, usize))>,
+    /// Number of cells defined on both sides
+    pub compared: usize,
+    /// Number of cells defined on only one side
+    pub nodata_mismatches: usize,
+    /// Number of cells of which absolute difference exceeds `tolerance`
+    pub exceeded: usize,
+    /// Maximum absolute difference, `0.0` when no cell is compared
+    pub max_abs_diff: f64,
+    /// Mean absolute difference, `0.0` when no cell is compared
+    pub mean_abs_diff: f64,
+    /// Cells of which absolute difference exceeds `tolerance`, the worst first, up to 10 cells
+    pub worst: Vec<Discrepancy>,
+}
+
+/// Policy on different resolutions of [`ISG::rms_difference`]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ResolutionPolicy {
+    /// Resamples the coarser grid at cell centers of the finer grid by the method
+    Resample(Method),
+    /// Requires deltas to be equal and grid nodes to be aligned
+    Strict,
+}
+
+impl Default for ResolutionPolicy {
+    fn default() -> Self {
+        Self::Resample(Method::Bilinear)
+    }
+}
+
+/// Statistics of differences, `self - other`, of [`ISG::rms_difference`]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DifferenceStats {
+    /// Number of compared cells
+    pub count: usize,
+    /// Root mean square of differences
+    pub rms: f64,
+    /// Mean of differences
+    pub mean: f64,
+    /// Maximum absolute difference
+    pub max: f64,
+}
+
+/// Difference of a header field, see [`ComparisonReport`].
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct FieldDiff {
+    /// Name of the field
+    pub field: &'static str,
+    /// Left value (debug format)
+    pub left: String,
+    /// Right value (debug format)
+    pub right: String,
+}
+
+/// Cell of which values differ beyond tolerance, see [`ComparisonReport`].
+///
+/// `row` and `col` are of the left data.
+/// For sparse data, `row` is the index of record and `col` is `2`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Discrepancy {
+    pub row: usize,
+    pub col: usize,
+    /// Coordinate `a` of the cell center (or record), lat or north
+    pub a: Coord,
+    /// Coordinate `b` of the cell center (or record), lon or east
+    pub b: Coord,
+    /// Left value
+    pub left: f64,
+    /// Right value
+    pub right: f64,
+}
+
+impl Discrepancy {
+    /// Returns the absolute difference.
+    #[inline]
+    pub fn abs_diff(&self) -> f64 {
+        (self.right - self.left).abs()
+    }
+}
+
+impl ComparisonReport {
+    /// Returns `true` if shapes and nodata cells match
+    /// and all differences are within `tolerance`.
+    ///
+    /// Header differences are not considered.
+    #[inline]
+    pub fn passed(&self) -> bool {
+        self.shape.is_none() && self.nodata_mismatches == 0 && self.exceeded == 0
+    }
+}
+
+impl ISG {
+    /// Compares `self` (left) with `other` (right) cell-wise by `tolerance` of absolute difference.
+    ///
+    /// Data is compared only when shapes match.
+    /// Grid cells are compared by their positions, respecting `data ordering` of both grids,
+    /// and sparse records are compared in order.
+    ///
+    /// ```
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// let mut other = isg.clone();
+    /// other.data[(1, 2)] = Some(43.5);
+    ///
+    /// let report = isg.compare(&other, 1e-3);
+    /// assert!(!report.passed());
+    /// assert_eq!(report.exceeded, 1);
+    /// assert_eq!((report.worst[0].row, report.worst[0].col), (1, 2));
+    ///
+    /// assert!(isg.compare(&other, 0.2).passed());
+    /// ```
+    pub fn compare(&self, other: &ISG, tolerance: f64) -> ComparisonReport {
+        let mut report = ComparisonReport {
+            tolerance,
+            header: header_diff(&self.header, &other.header),
+            shape: None,
+            compared: 0,
+            nodata_mismatches: 0,
+            exceeded: 0,
+            max_abs_diff: 0.0,
+            mean_abs_diff: 0.0,
+            worst: Vec::new(),
+        };
+
+        let pairs: Box<dyn Iterator<Item = (usize, usize, Option<f64>)>> =
+            match (&self.data, &other.data) {
+                (Data::Grid(left), Data::Grid(right))
+                    if (left.nrows(), left.ncols()) == (right.nrows(), right.ncols()) =>
+                {
+                    let (left_flips, right_flips) = (
+                        grid_flips_or_default(self.header.data_ordering),
+                        grid_flips_or_default(other.header.data_ordering),
+                    );
+                    let (flip_rows, flip_cols) =
+                        (left_flips.0 != right_flips.0, left_flips.1 != right_flips.1);
+                    let (nrows, ncols) = (right.nrows(), right.ncols());
+
+                    Box::new((0..nrows * ncols).map(move |i| {
+                        let (row, col) = (i / ncols, i % ncols);
+                        let value = right[(
+                            if flip_rows { nrows - 1 - row } else { row },
+                            if flip_cols { ncols - 1 - col } else { col },
+                        )];
+                        (row, col, value)
+                    }))
+                }
+                (Data::Sparse(left), Data::Sparse(right)) if left.len() == right.len() => {
+                    Box::new(right.iter().enumerate().map(|(i, r)| (i, 2, Some(r.2))))
+                }
+                _ => {
+                    if variant(&self.data) != variant(&other.data) {
+                        report.header.push(FieldDiff {
+                            field: "data",
+                            left: variant(&self.data).into(),
+                            right: variant(&other.data).into(),
+                        });
+                    }
+                    report.shape = Some((shape(&self.data), shape(&other.data)));
+                    return report;
+                }
+            };
+
+        let mut sum = 0.0;
+        for ((a, b, left), (row, col, right)) in self.cells().zip(pairs) {
+            match (left, right) {
+                (Some(left), Some(right)) => {
+                    let diff = (right - left).abs();
+                    report.compared += 1;
+                    sum += diff;
+                    report.max_abs_diff = report.max_abs_diff.max(diff);
+
+                    if diff > tolerance {
+                        report.worst.push(Discrepancy {
+                            row,
+                            col,
+                            a,
+                            b,
+                            left,
+                            right,
+                        });
+                    }
+                }
+                (Some(_), None) | (None, Some(_)) => report.nodata_mismatches += 1,
+                (None, None) => {}
+            }
+        }
+
+        if report.compared > 0 {
+            report.mean_abs_diff = sum / report.compared as f64;
+        }
+
+        report.exceeded = report.worst.len();
+        report.worst.sort_by(|x, y| {
+            y.abs_diff()
+                .partial_cmp(&x.abs_diff())
+                .unwrap_or(Ordering::Equal)
+        });
+        report.worst.truncate(MAX_WORST);
+
+        report
+    }
+
+    /// Returns statistics of differences `self - other` over the overlapping region of two grids.
+    ///
+    /// The finer grid (of the smaller cell area) is taken as reference,
+    /// and the other is sampled at cell centers of the reference by `policy`,
+    /// so only cells inside of both grids and defined in both are compared.
+    /// Grids must share `coord units` and `data units`, `data ordering` may differ.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] when either is not grid, they are not compatible,
+    /// grids mismatch under [`ResolutionPolicy::Strict`], or no cell is compared.
+    ///
+    /// ```
+    /// # use libisg::ResolutionPolicy;
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// let shifted = isg.subgrid(0..2, 0..6).unwrap().map_values(|v| v + 0.1);
+    ///
+    /// let stats = isg.rms_difference(&shifted, ResolutionPolicy::Strict).unwrap();
+    /// assert_eq!(stats.count, 12);
+    /// assert!((stats.rms - 0.1).abs() < 1e-9);
+    /// assert!((stats.mean + 0.1).abs() < 1e-9);
+    /// ```
+    pub fn rms_difference(
+        &self,
+        other: &ISG,
+        policy: ResolutionPolicy,
+    ) -> Result<DifferenceStats, MismatchError> {
+        if !matches!((&self.data, &other.data), (Data::Grid(_), Data::Grid(_))) {
+            return Err(MismatchError::not_grid());
+        }
+        if self.header.coord_units != other.header.coord_units {
+            return Err(MismatchError::coord_units());
+        }
+        if self.header.data_units != other.header.data_units {
+            return Err(MismatchError::data_units());
+        }
+
+        let method = match policy {
+            ResolutionPolicy::Resample(method) => method,
+            ResolutionPolicy::Strict => {
+                match check_nodes(&self.header, &other.header) {
+                    Ok(()) => {}
+                    Err(Misalignment::Origin) => return Err(MismatchError::misaligned()),
+                    Err(_) => return Err(MismatchError::delta()),
+                }
+
+                Method::Nearest
+            }
+        };
+
+        let area = |isg: &ISG| isg.header.cell_size().map(|(a, b)| a * b);
+        let (reference, target, sign) = if area(other) < area(self) {
+            (other, self, -1.0)
+        } else {
+            (self, other, 1.0)
+        };
+
+        // never fails, target is grid
+        let (grid, locator) = target.locator().unwrap();
+
+        let (mut count, mut sum, mut sum2, mut max) = (0, 0.0, 0.0, 0.0_f64);
+        for (a, b, value) in reference.cells() {
+            let value = match value {
+                Some(value) => value,
+                None => continue,
+            };
+
+            let (row, col) = locator.frac_index(a.as_f64(), b.as_f64());
+            if let Some(target) = method.sample(&grid, row, col) {
+                let diff = sign * (value - target);
+                count += 1;
+                sum += diff;
+                sum2 += diff * diff;
+                max = max.max(diff.abs());
+            }
+        }
+
+        if count == 0 {
+            return Err(MismatchError::no_overlap());
+        }
+
+        Ok(DifferenceStats {
+            count,
+            rms: (sum2 / count as f64).sqrt(),
+            mean: sum / count as f64,
+            max,
+        })
+    }
+}
+
+/// Returns differences of header fields
+pub(crate) fn header_diff(left: &Header, right: &Header) -> Vec<FieldDiff> {
+    let mut diff = Vec::new();
+
+    macro_rules! field {
+        ($name:ident) => {
+            if left.$name != right.$name {
+                diff.push(FieldDiff {
+                    field: stringify!($name),
+                    left: format!("{:?}", left.$name),
+                    right: format!("{:?}", right.$name),
+                });
+            }
+        };
+    }
+
+    field!(model_name);
+    field!(model_year);
+    field!(model_type);
+    field!(data_type);
+    field!(data_units);
+    field!(data_format);
+    field!(data_ordering);
+    field!(ref_ellipsoid);
+    field!(ref_frame);
+    field!(height_datum);
+    field!(tide_system);
+    field!(coord_type);
+    field!(coord_units);
+    field!(map_projection);
+    field!(EPSG_code);
+    field!(data_bounds);
+    field!(nrows);
+    field!(ncols);
+    field!(nodata);
+    field!(creation_date);
+    field!(ISG_format);
+
+    diff
+}
+
+#[inline]
+pub(crate) fn variant(data: &Data) -> &'static str {
+    match data {
+        Data::Grid(_) => "Data::Grid",
+        Data::Sparse(_) => "Data::Sparse",
+    }
+}
+
+#[inline]
+fn shape(data: &Data) -> (usize, usize) {
+    match data {
+        Data::Grid(grid) => (grid.nrows(), grid.ncols()),
+        Data::Sparse(data) => (data.len(), 3),
+    }
+}
+
+impl Display for ComparisonReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for field in &self.header {
+            writeln!(
+                f,
+                "header `{}`: left: {}, right: {}",
+                field.field, field.left, field.right
+            )?;
+        }
+
+        if let Some(((left_rows, left_cols), (right_rows, right_cols))) = self.shape {
+            return writeln!(
+                f,
+                "shape: left: {} x {}, right: {} x {}",
+                left_rows, left_cols, right_rows, right_cols
+            );
+        }
+
+        writeln!(
+            f,
+            "compared {} cell(s), {} nodata mismatch(es), {} cell(s) exceeding {}",
+            self.compared, self.nodata_mismatches, self.exceeded, self.tolerance
+        )?;
+        writeln!(
+            f,
+            "max abs diff: {}, mean abs diff: {}",
+            self.max_abs_diff, self.mean_abs_diff
+        )?;
+
+        for cell in &self.worst {
+            writeln!(
+                f,
+                "data ({}, {}) at ({}, {}): left: {}, right: {}",
+                cell.row, cell.col, cell.a, cell.b, cell.left, cell.right
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,432 @@
+//! Conformance test vectors.
+//!
+//! Alternative implementations (FFI consumers, ports to other languages etc.)
+//! can verify that they match the interpretation of [`libisg`](crate)
+//! on tricky corners of the specification.
+
+use crate::{from_str, to_string};
+
+/// Codec under conformance test.
+pub trait IsgCodec {
+    /// Parses `input` as ISG-format and serializes it again,
+    /// returns [`None`] if `input` is rejected.
+    ///
+    /// The output must be the padded layout, that is the one of [`to_string`].
+    fn reserialize(&self, input: &str) -> Option<String>;
+}
+
+/// [`IsgCodec`] of [`libisg`](crate) itself, the reference implementation.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LibIsg;
+
+impl IsgCodec for LibIsg {
+    #[inline]
+    fn reserialize(&self, input: &str) -> Option<String> {
+        from_str(input).ok().map(|isg| to_string(&isg))
+    }
+}
+
+/// Pair of input and expected output.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct ConformanceCase {
+    /// Name of the case
+    pub name: &'static str,
+    /// Input of ISG-format
+    pub input: &'static str,
+    /// Expected output, [`None`] if `input` must be rejected
+    pub expected: Option<&'static str>,
+}
+
+/// Failed case of [`run_conformance`].
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ConformanceFailure {
+    /// Name of the case
+    pub name: &'static str,
+    /// Expected output, [`None`] if `input` must be rejected
+    pub expected: Option<&'static str>,
+    /// Actual output
+    pub actual: Option<String>,
+}
+
+/// Result of [`run_conformance`].
+#[derive(Debug, Default, Eq, PartialEq, Clone)]
+pub struct ConformanceReport {
+    /// Names of passed cases
+    pub passed: Vec<&'static str>,
+    /// Failed cases
+    pub failed: Vec<ConformanceFailure>,
+}
+
+impl ConformanceReport {
+    /// Returns `true` if all cases passed.
+    #[inline]
+    pub fn is_ok(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Returns all conformance test vectors.
+#[inline]
+pub fn conformance_cases() -> &'static [ConformanceCase] {
+    CASES
+}
+
+/// Runs all conformance test vectors on `codec`.
+pub fn run_conformance(codec: impl IsgCodec) -> ConformanceReport {
+    let mut report = ConformanceReport::default();
+
+    for case in CASES {
+        let actual = codec.reserialize(case.input);
+        if actual.as_deref() == case.expected {
+            report.passed.push(case.name);
+        } else {
+            report.failed.push(ConformanceFailure {
+                name: case.name,
+                expected: case.expected,
+                actual,
+            });
+        }
+    }
+
+    report
+}
+
+const CASES: &[ConformanceCase] = &[
+    ConformanceCase {
+        name: "grid_dms",
+        input: GRID_DMS,
+        expected: Some(GRID_DMS),
+    },
+    // padding is normalized
+    ConformanceCase {
+        name: "minified_header",
+        input: MINIFIED_HEADER,
+        expected: Some(GRID_DMS),
+    },
+    // nodata is written by 4 decimal places, as well as data
+    ConformanceCase {
+        name: "nodata_format",
+        input: NODATA_FORMAT,
+        expected: Some(GRID_DMS),
+    },
+    // rounds binary representation, `30.12345` is `30.1234` not `30.1235`
+    ConformanceCase {
+        name: "value_rounding",
+        input: VALUE_ROUNDING,
+        expected: Some(GRID_DMS),
+    },
+    // accepts ISO 8601, writes DD/MM/YYYY
+    ConformanceCase {
+        name: "iso_creation_date",
+        input: ISO_CREATION_DATE,
+        expected: Some(GRID_DMS),
+    },
+    // seconds of DMS must be integer, no rounding
+    ConformanceCase {
+        name: "dms_fractional_seconds",
+        input: DMS_FRACTIONAL_SECONDS,
+        expected: None,
+    },
+    ConformanceCase {
+        name: "missing_end_of_head",
+        input: MISSING_END_OF_HEAD,
+        expected: None,
+    },
+    ConformanceCase {
+        name: "sparse_deg",
+        input: SPARSE_DEG,
+        expected: Some(SPARSE_DEG),
+    },
+    ConformanceCase {
+        name: "sparse_dms",
+        input: SPARSE_DMS,
+        expected: Some(SPARSE_DMS),
+    },
+];
+
+const GRID_DMS: &str = r##"begin_of_head ================================================
+model name     : EXAMPLE
+model year     : 2020
+model type     : gravimetric
+data type      : geoid
+data units     : meters
+data format    : grid
+data ordering  : N-to-S, W-to-E
+ref ellipsoid  : GRS80
+ref frame      : ITRF2014
+height datum   : ---
+tide system    : mean-tide
+coord type     : geodetic
+coord units    : dms
+map projection : ---
+EPSG code      : 7912
+lat min        =   39°50'00"
+lat max        =   40°30'00"
+lon min        =  119°50'00"
+lon max        =  120°30'00"
+delta lat      =    0°20'00"
+delta lon      =    0°20'00"
+nrows          =           2
+ncols          =           2
+nodata         =  -9999.0000
+creation date  =  31/05/2020
+ISG format     =         2.0
+end_of_head ==================================================
+   30.1234    31.2222
+   41.1111 -9999.0000
+"##;
+
+const MINIFIED_HEADER: &str = r##"begin_of_head ================================================
+model name:EXAMPLE
+model year:2020
+model type:gravimetric
+data type:geoid
+data units:meters
+data format:grid
+data ordering:N-to-S, W-to-E
+ref ellipsoid:GRS80
+ref frame:ITRF2014
+height datum:---
+tide system:mean-tide
+coord type:geodetic
+coord units:dms
+map projection:---
+EPSG code:7912
+lat min=39°50'00"
+lat max=40°30'00"
+lon min=119°50'00"
+lon max=120°30'00"
+delta lat=0°20'00"
+delta lon=0°20'00"
+nrows=2
+ncols=2
+nodata=-9999.0000
+creation date=31/05/2020
+ISG format=2.0
+end_of_head ==================================================
+30.1234 31.2222
+41.1111 -9999.0000
+"##;
+
+const NODATA_FORMAT: &str = r##"begin_of_head ================================================
+model name     : EXAMPLE
+model year     : 2020
+model type     : gravimetric
+data type      : geoid
+data units     : meters
+data format    : grid
+data ordering  : N-to-S, W-to-E
+ref ellipsoid  : GRS80
+ref frame      : ITRF2014
+height datum   : ---
+tide system    : mean-tide
+coord type     : geodetic
+coord units    : dms
+map projection : ---
+EPSG code      : 7912
+lat min        =   39°50'00"
+lat max        =   40°30'00"
+lon min        =  119°50'00"
+lon max        =  120°30'00"
+delta lat      =    0°20'00"
+delta lon      =    0°20'00"
+nrows          =           2
+ncols          =           2
+nodata         = -9999
+creation date  =  31/05/2020
+ISG format     =         2.0
+end_of_head ==================================================
+   30.1234    31.2222
+   41.1111 -9999
+"##;
+
+const VALUE_ROUNDING: &str = r##"begin_of_head ================================================
+model name     : EXAMPLE
+model year     : 2020
+model type     : gravimetric
+data type      : geoid
+data units     : meters
+data format    : grid
+data ordering  : N-to-S, W-to-E
+ref ellipsoid  : GRS80
+ref frame      : ITRF2014
+height datum   : ---
+tide system    : mean-tide
+coord type     : geodetic
+coord units    : dms
+map projection : ---
+EPSG code      : 7912
+lat min        =   39°50'00"
+lat max        =   40°30'00"
+lon min        =  119°50'00"
+lon max        =  120°30'00"
+delta lat      =    0°20'00"
+delta lon      =    0°20'00"
+nrows          =           2
+ncols          =           2
+nodata         =  -9999.0000
+creation date  =  31/05/2020
+ISG format     =         2.0
+end_of_head ==================================================
+30.12345 31.22225
+   41.1111 -9999.0000
+"##;
+
+const ISO_CREATION_DATE: &str = r##"begin_of_head ================================================
+model name     : EXAMPLE
+model year     : 2020
+model type     : gravimetric
+data type      : geoid
+data units     : meters
+data format    : grid
+data ordering  : N-to-S, W-to-E
+ref ellipsoid  : GRS80
+ref frame      : ITRF2014
+height datum   : ---
+tide system    : mean-tide
+coord type     : geodetic
+coord units    : dms
+map projection : ---
+EPSG code      : 7912
+lat min        =   39°50'00"
+lat max        =   40°30'00"
+lon min        =  119°50'00"
+lon max        =  120°30'00"
+delta lat      =    0°20'00"
+delta lon      =    0°20'00"
+nrows          =           2
+ncols          =           2
+nodata         =  -9999.0000
+creation date  =  2020-05-31
+ISG format     =         2.0
+end_of_head ==================================================
+   30.1234    31.2222
+   41.1111 -9999.0000
+"##;
+
+const DMS_FRACTIONAL_SECONDS: &str = r##"begin_of_head ================================================
+model name     : EXAMPLE
+model year     : 2020
+model type     : gravimetric
+data type      : geoid
+data units     : meters
+data format    : grid
+data ordering  : N-to-S, W-to-E
+ref ellipsoid  : GRS80
+ref frame      : ITRF2014
+height datum   : ---
+tide system    : mean-tide
+coord type     : geodetic
+coord units    : dms
+map projection : ---
+EPSG code      : 7912
+lat min        =   39°50'00.5"
+lat max        =   40°30'00"
+lon min        =  119°50'00"
+lon max        =  120°30'00"
+delta lat      =    0°20'00"
+delta lon      =    0°20'00"
+nrows          =           2
+ncols          =           2
+nodata         =  -9999.0000
+creation date  =  31/05/2020
+ISG format     =         2.0
+end_of_head ==================================================
+   30.1234    31.2222
+   41.1111 -9999.0000
+"##;
+
+const MISSING_END_OF_HEAD: &str = r##"begin_of_head ================================================
+model name     : EXAMPLE
+model year     : 2020
+model type     : gravimetric
+data type      : geoid
+data units     : meters
+data format    : grid
+data ordering  : N-to-S, W-to-E
+ref ellipsoid  : GRS80
+ref frame      : ITRF2014
+height datum   : ---
+tide system    : mean-tide
+coord type     : geodetic
+coord units    : dms
+map projection : ---
+EPSG code      : 7912
+lat min        =   39°50'00"
+lat max        =   40°30'00"
+lon min        =  119°50'00"
+lon max        =  120°30'00"
+delta lat      =    0°20'00"
+delta lon      =    0°20'00"
+nrows          =           2
+ncols          =           2
+nodata         =  -9999.0000
+creation date  =  31/05/2020
+ISG format     =         2.0
+   30.1234    31.2222
+   41.1111 -9999.0000
+"##;
+
+const SPARSE_DEG: &str = r##"begin_of_head ================================================
+model name     : EXAMPLE
+model year     : 2020
+model type     : gravimetric
+data type      : geoid
+data units     : meters
+data format    : sparse
+data ordering  : lat, lon, N
+ref ellipsoid  : GRS80
+ref frame      : ITRF2014
+height datum   : ---
+tide system    : mean-tide
+coord type     : geodetic
+coord units    : deg
+map projection : ---
+EPSG code      : 7912
+lat min        =   40.000000
+lat max        =   41.000000
+lon min        =  120.000000
+lon max        =  121.666667
+delta lat      = ---
+delta lon      = ---
+nrows          =           2
+ncols          =           3
+nodata         =  -9999.0000
+creation date  =  31/05/2020
+ISG format     =         2.0
+end_of_head ==================================================
+  40.000000  120.000000    30.1234
+  41.000000  121.666667    36.6666
+"##;
+
+const SPARSE_DMS: &str = r##"begin_of_head ================================================
+model name     : EXAMPLE
+model year     : 2020
+model type     : gravimetric
+data type      : geoid
+data units     : meters
+data format    : sparse
+data ordering  : lat, lon, N
+ref ellipsoid  : GRS80
+ref frame      : ITRF2014
+height datum   : ---
+tide system    : mean-tide
+coord type     : geodetic
+coord units    : dms
+map projection : ---
+EPSG code      : 7912
+lat min        =   40°00'00"
+lat max        =   41°00'00"
+lon min        =  120°00'00"
+lon max        =  121°40'00"
+delta lat      = ---
+delta lon      = ---
+nrows          =           2
+ncols          =           3
+nodata         =  -9999.0000
+creation date  =  31/05/2020
+ISG format     =         2.0
+end_of_head ==================================================
+  40°00'00"  120°00'00"    30.1234
+  41°00'00"  121°40'00"    36.6666
+"##;
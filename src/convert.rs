@@ -0,0 +1,437 @@
+use crate::error::ConversionError;
+use crate::geometry::{distance2, is_signed, lon_span, wrap_lon, GridGeometry, TOLERANCE};
+use crate::{Coord, Data, DataBounds, DataFormat, DataOrdering, Grid, Header, ISG};
+
+/// Sparse data in struct-of-arrays layout, see [`ISG::sparse_columns`].
+///
+/// Coordinates are decimal, `lats` are latitudes (or northings)
+/// and `lons` are longitudes (or eastings) regardless of `data ordering`.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct SparseColumns {
+    lats: Vec<f64>,
+    lons: Vec<f64>,
+    values: Vec<f64>,
+}
+
+impl SparseColumns {
+    /// Makes [`SparseColumns`], [`None`] when the lengths are different.
+    pub fn new(lats: Vec<f64>, lons: Vec<f64>, values: Vec<f64>) -> Option<Self> {
+        if lats.len() == lons.len() && lats.len() == values.len() {
+            Some(Self { lats, lons, values })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of records.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if there is no record.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns latitudes (or northings).
+    #[inline]
+    pub fn lats(&self) -> &[f64] {
+        &self.lats
+    }
+
+    /// Returns longitudes (or eastings).
+    #[inline]
+    pub fn lons(&self) -> &[f64] {
+        &self.lons
+    }
+
+    /// Returns values.
+    #[inline]
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Decomposes into `(lats, lons, values)`, without copying.
+    #[inline]
+    pub fn into_vecs(self) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+        (self.lats, self.lons, self.values)
+    }
+}
+
+impl ISG {
+    /// Returns sparse data in struct-of-arrays layout, [`None`] when `self` is grid.
+    ///
+    /// Columns are swapped when `data ordering` is `east, north, N`.
+    ///
+    /// ```
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.3.isg").unwrap()).unwrap();
+    /// let columns = isg.sparse_columns().unwrap();
+    ///
+    /// assert_eq!(columns.len(), 20);
+    /// assert_eq!(columns.lats()[0], 40.0);
+    /// assert_eq!(columns.lons()[0], 120.0);
+    /// assert_eq!(columns.values()[0], 30.1234);
+    /// ```
+    pub fn sparse_columns(&self) -> Option<SparseColumns> {
+        let data = match &self.data {
+            Data::Grid(_) => return None,
+            Data::Sparse(data) => data,
+        };
+
+        let swap = matches!(self.header.data_ordering, Some(DataOrdering::EastNorthN));
+        let mut columns = SparseColumns {
+            lats: Vec::with_capacity(data.len()),
+            lons: Vec::with_capacity(data.len()),
+            values: Vec::with_capacity(data.len()),
+        };
+        for (a, b, value) in data {
+            let (lat, lon) = if swap { (b, a) } else { (a, b) };
+            columns.lats.push(lat.as_f64());
+            columns.lons.push(lon.as_f64());
+            columns.values.push(*value);
+        }
+
+        Some(columns)
+    }
+}
+
+impl ISG {
+    /// Converts into sparse data, which has defined cells only.
+    ///
+    /// Each record has the coordinates of the cell, see [`ISG::cells`].
+    /// `data ordering` becomes `lat, lon, N` for geodetic or `east, north, N` for projected,
+    /// and records are sorted by it.
+    /// `data bounds` becomes the extent of the cells, `nrows` the number of records and `ncols` `3`.
+    ///
+    /// This returns a clone when `self` is sparse.
+    ///
+    /// ```
+    /// # use libisg::{Coord, Data, DataFormat};
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// let sparse = isg.to_sparse();
+    ///
+    /// assert_eq!(sparse.header.data_format, DataFormat::Sparse);
+    /// assert_eq!(sparse.header.nrows, 20);
+    /// assert_eq!(sparse.header.ncols, 3);
+    /// assert!(matches!(
+    ///     &sparse.data,
+    ///     Data::Sparse(data) if data[0] == (Coord::with_dms(40, 10, 0), Coord::with_dms(119, 50, 0), 61.9999)
+    /// ));
+    /// ```
+    pub fn to_sparse(&self) -> ISG {
+        let grid = match &self.data {
+            Data::Grid(grid) => grid,
+            Data::Sparse(_) => return self.clone(),
+        };
+
+        let (nrows, ncols) = (
+            grid.nrows().saturating_sub(1),
+            grid.ncols().saturating_sub(1),
+        );
+        let (data_bounds, data_ordering) = match &self.header.data_bounds {
+            DataBounds::GridGeodetic {
+                lat_max,
+                lon_min,
+                lon_max,
+                delta_lat,
+                delta_lon,
+                ..
+            } => (
+                DataBounds::SparseGeodetic {
+                    lat_min: lat_max - delta_lat * nrows,
+                    lat_max: *lat_max,
+                    lon_min: *lon_min,
+                    lon_max: wrap_lon(lon_min + delta_lon * ncols, is_signed(lon_min, lon_max)),
+                },
+                DataOrdering::LatLonN,
+            ),
+            DataBounds::GridProjected {
+                north_max,
+                east_min,
+                delta_north,
+                delta_east,
+                ..
+            } => (
+                DataBounds::SparseProjected {
+                    north_min: north_max - delta_north * nrows,
+                    north_max: *north_max,
+                    east_min: *east_min,
+                    east_max: east_min + delta_east * ncols,
+                },
+                DataOrdering::EastNorthN,
+            ),
+            DataBounds::SparseGeodetic { .. } | DataBounds::SparseProjected { .. } => {
+                (self.header.data_bounds.clone(), DataOrdering::LatLonN)
+            }
+        };
+
+        let swap = matches!(data_ordering, DataOrdering::EastNorthN);
+        let data: Vec<_> = self
+            .cells()
+            .filter_map(|(a, b, value)| {
+                value.map(|value| if swap { (b, a, value) } else { (a, b, value) })
+            })
+            .collect();
+
+        let mut header = self.header.clone();
+        header.data_format = DataFormat::Sparse;
+        header.data_ordering = Some(data_ordering);
+        header.data_bounds = data_bounds;
+        header.nrows = data.len();
+        header.ncols = 3;
+
+        let mut isg = ISG {
+            comment: self.comment.clone(),
+            header,
+            data: Data::Sparse(data),
+        };
+        isg.sort_sparse_by_ordering();
+        isg
+    }
+
+    /// Converts into grid data of which spacing is `delta_lat` × `delta_lon`
+    /// (`delta north` × `delta east` for projected).
+    ///
+    /// Grid nodes start from `lat max` and `lon min` of `data bounds`,
+    /// and the shape is determined by the bounds and the spacing,
+    /// where the bounds cross the antimeridian when `lon max` is less than `lon min`.
+    /// Cells without records are nodata.
+    /// `data ordering` becomes `N-to-S, W-to-E`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] when `self` is not sparse, deltas are not positive,
+    /// a record is off the grid nodes or two records are on the same node.
+    ///
+    /// ```
+    /// # use libisg::{Coord, DataFormat};
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.3.isg").unwrap()).unwrap();
+    /// let delta = Coord::with_dec(1.0 / 3.0);
+    /// let grid = isg.to_grid(delta, delta).unwrap();
+    ///
+    /// assert_eq!(grid.header.data_format, DataFormat::Grid);
+    /// assert_eq!((grid.header.nrows, grid.header.ncols), (4, 6));
+    /// assert_eq!(grid.data[(0, 0)], Some(61.9999));
+    /// assert_eq!(grid.data[(0, 5)], None);
+    ///
+    /// assert!(isg.to_grid(Coord::with_dec(0.5), delta).is_err());
+    /// ```
+    pub fn to_grid(&self, delta_lat: Coord, delta_lon: Coord) -> Result<ISG, ConversionError> {
+        let (header, geometry) = self.grid_header(delta_lat, delta_lon)?;
+
+        let mut grid = Grid::filled(header.nrows, header.ncols, None);
+        for (index, (a, b, value)) in self.cells().enumerate() {
+            let (a, b) = (a.as_f64(), b.as_f64());
+            let (row, col) = geometry.frac_index(a, b);
+            if (row - row.round()).abs() > TOLERANCE || (col - col.round()).abs() > TOLERANCE {
+                return Err(ConversionError::off_grid(index));
+            }
+
+            let (row, col) = geometry
+                .index(a, b)
+                .ok_or_else(|| ConversionError::off_grid(index))?;
+            // never fails, index is in bounds
+            let cell = grid.get_mut(row, col).unwrap();
+            if cell.is_some() {
+                return Err(ConversionError::duplicated(index));
+            }
+            *cell = value;
+        }
+
+        Ok(ISG {
+            comment: self.comment.clone(),
+            header,
+            data: Data::Grid(grid),
+        })
+    }
+
+    /// Converts into grid data of which spacing is `delta_lat` × `delta_lon`
+    /// by inverse distance weighting of records, see [`ISG::to_grid`] for the grid.
+    ///
+    /// The value of each cell is the mean of records within `radius` of the cell center
+    /// weighted by `1 / distance^power`, and nodata when there is no record within `radius`.
+    /// The distance is in coordinate space, that is, in decimal degrees for geodetic
+    /// (approximated by the equirectangular projection) and in `coord units` for projected.
+    /// A record on the cell center (within the tolerance of [`ISG::to_grid`]) takes the cell.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] when `self` is not sparse, deltas, `power` or `radius` is not positive.
+    ///
+    /// ```
+    /// # use libisg::Coord;
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.3.isg").unwrap()).unwrap();
+    /// let delta = Coord::with_dec(1.0 / 6.0);
+    /// let grid = isg.rasterize_idw(delta, delta, 2.0, 0.2).unwrap();
+    ///
+    /// assert_eq!((grid.header.nrows, grid.header.ncols), (7, 11));
+    /// // on a record
+    /// assert_eq!(grid.data[(0, 0)], Some(61.9999));
+    /// // between two records
+    /// assert!(grid.data[(0, 1)].is_some());
+    /// ```
+    pub fn rasterize_idw(
+        &self,
+        delta_lat: Coord,
+        delta_lon: Coord,
+        power: f64,
+        radius: f64,
+    ) -> Result<ISG, ConversionError> {
+        if !(power.is_finite() && power > 0.0) {
+            return Err(ConversionError::invalid_parameter("power"));
+        }
+        if radius.is_nan() || radius <= 0.0 {
+            return Err(ConversionError::invalid_parameter("radius"));
+        }
+
+        let (header, geometry) = self.grid_header(delta_lat, delta_lon)?;
+        let records: Vec<_> = self
+            .cells()
+            .filter_map(|(a, b, value)| value.map(|value| (a.as_f64(), b.as_f64(), value)))
+            .collect();
+
+        // records within the tolerance of `to_grid` are on the cell center
+        let snap = TOLERANCE * geometry.delta_a.min(geometry.delta_b);
+
+        let mut values = Vec::with_capacity(header.nrows * header.ncols);
+        for row in 0..header.nrows {
+            let a = geometry.a_max - geometry.delta_a * row as f64;
+            for col in 0..header.ncols {
+                let b = geometry.b_min + geometry.delta_b * col as f64;
+                values.push(idw(
+                    &records,
+                    (a, b),
+                    geometry.geodetic,
+                    power,
+                    radius,
+                    snap,
+                ));
+            }
+        }
+
+        Ok(ISG {
+            comment: self.comment.clone(),
+            data: Data::Grid(Grid::from_vec_unchecked(header.nrows, header.ncols, values)),
+            header,
+        })
+    }
+
+    /// Returns the header and the geometry of grid converted from sparse, see [`ISG::to_grid`].
+    fn grid_header(
+        &self,
+        delta_lat: Coord,
+        delta_lon: Coord,
+    ) -> Result<(Header, GridGeometry), ConversionError> {
+        if !(delta_lat.as_f64() > 0.0 && delta_lon.as_f64() > 0.0) {
+            return Err(ConversionError::invalid_delta());
+        }
+
+        let data_bounds = match &self.header.data_bounds {
+            DataBounds::SparseGeodetic {
+                lat_min,
+                lat_max,
+                lon_min,
+                lon_max,
+            } => DataBounds::GridGeodetic {
+                lat_min: *lat_min,
+                lat_max: *lat_max,
+                lon_min: *lon_min,
+                lon_max: *lon_max,
+                delta_lat,
+                delta_lon,
+            },
+            DataBounds::SparseProjected {
+                north_min,
+                north_max,
+                east_min,
+                east_max,
+            } => DataBounds::GridProjected {
+                north_min: *north_min,
+                north_max: *north_max,
+                east_min: *east_min,
+                east_max: *east_max,
+                delta_north: delta_lat,
+                delta_east: delta_lon,
+            },
+            DataBounds::GridGeodetic { .. } | DataBounds::GridProjected { .. } => {
+                return Err(ConversionError::not_sparse())
+            }
+        };
+        if matches!(self.data, Data::Grid(_)) {
+            return Err(ConversionError::not_sparse());
+        }
+
+        let mut header = self.header.clone();
+        header.data_format = DataFormat::Grid;
+        header.data_ordering = Some(DataOrdering::N2SW2E);
+        header.data_bounds = data_bounds;
+
+        // never fails, data bounds is grid
+        let mut geometry = GridGeometry::new(&header).unwrap();
+        let (nrows, ncols) = match &header.data_bounds {
+            DataBounds::GridGeodetic {
+                lat_min,
+                lon_min,
+                lon_max,
+                ..
+            } => (
+                geometry.frac_index(lat_min.as_f64(), geometry.b_min).0,
+                lon_span(lon_min.as_f64(), lon_max.as_f64()) / geometry.delta_b,
+            ),
+            DataBounds::GridProjected {
+                north_min,
+                east_max,
+                ..
+            } => geometry.frac_index(north_min.as_f64(), east_max.as_f64()),
+            DataBounds::SparseGeodetic { .. } | DataBounds::SparseProjected { .. } => {
+                unreachable!()
+            }
+        };
+        let (nrows, ncols) = (
+            (nrows + TOLERANCE).floor().max(-1.0) + 1.0,
+            (ncols + TOLERANCE).floor().max(-1.0) + 1.0,
+        );
+        geometry.nrows = nrows as usize;
+        geometry.ncols = ncols as usize;
+        header.nrows = geometry.nrows;
+        header.ncols = geometry.ncols;
+
+        Ok((header, geometry))
+    }
+}
+
+/// Returns the inverse distance weighted mean of `records` within `radius` of `point`,
+/// [`None`] when there is no record.
+///
+/// A record within `snap` takes the value.
+fn idw(
+    records: &[(f64, f64, f64)],
+    (a, b): (f64, f64),
+    geodetic: bool,
+    power: f64,
+    radius: f64,
+    snap: f64,
+) -> Option<f64> {
+    let (mut sum, mut weights) = (0.0, 0.0);
+    for &(ra, rb, value) in records {
+        let d2 = distance2(a, b, ra, rb, geodetic);
+        if d2 > radius * radius {
+            continue;
+        }
+        if d2 <= snap * snap {
+            return Some(value);
+        }
+
+        let weight = d2.powf(-power / 2.0);
+        sum += weight * value;
+        weights += weight;
+    }
+
+    if weights > 0.0 {
+        Some(sum / weights)
+    } else {
+        None
+    }
+}
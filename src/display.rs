@@ -1,302 +1,467 @@
 use std::fmt::{Display, Formatter, Write};
 
+use crate::ordering::sparse_cmp;
 use crate::*;
 
 /// Serialize [`ISG`] to [`String`].
 ///
 /// This simply calls [`ToString::to_string`] on `sig`.
 ///
+/// Data columns are 10 (value) and 11 (coordinate) chars width,
+/// and all columns are widened consistently when a value does not fit, e.g. `123456.7890`.
+///
 /// Notes, the behavior is unspecified when data has [`None`] even if `nodata` is [`None`].
 #[inline]
 pub fn to_string(isg: &ISG) -> String {
     isg.to_string()
 }
 
-impl Display for ISG {
-    /// Notes, the behavior is unspecified when data has [`None`] even if `nodata` is [`None`].
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        if !self.comment.is_empty() {
-            f.write_str(&self.comment)?;
-            if !self.comment.ends_with('\n') {
-                f.write_char('\n')?;
-            }
-        }
-
-        f.write_str("begin_of_head ================================================\n")?;
+/// Serialize [`ISG`] to [`String`] without padding.
+///
+/// Header values are written without alignment
+/// and data columns are separated by a single space.
+/// The result is still valid ISG format, which is about 20% smaller than [`to_string`].
+///
+/// Notes, the behavior is unspecified when data has [`None`] even if `nodata` is [`None`].
+#[inline]
+pub fn to_string_compact(isg: &ISG) -> String {
+    to_string_with_options(
+        isg,
+        &WriteOptions {
+            compact: true,
+            ..Default::default()
+        },
+    )
+}
 
-        Display::fmt(&self.header, f)?;
+/// Serialize [`ISG`] to [`String`] with options.
+///
+/// Notes, the behavior is unspecified when data has [`None`] even if `nodata` is [`None`].
+#[inline]
+pub fn to_string_with_options(isg: &ISG, options: &WriteOptions) -> String {
+    WithOptions { isg, options }.to_string()
+}
 
-        f.write_str("end_of_head ==================================================\n")?;
+/// Options of serialization.
+///
+/// The default is equivalent to [`to_string`].
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct WriteOptions {
+    /// Trims padding if `true`, see [`to_string_compact`]
+    pub compact: bool,
+    /// Format of DMS coordinates
+    pub dms: DmsFormat,
+    /// Format of `creation date`
+    pub creation_date: CreationDateFormat,
+    /// Writes optional headers whose value is [`None`] as `---` or omits them
+    pub optional_headers: OptionalHeaders,
+    /// Sorts sparse data by `data ordering` if `true`,
+    /// see [`ISG::sort_sparse_by_ordering`]
+    pub sort_sparse: bool,
+}
 
-        match &self.data {
-            Data::Grid(data) => {
-                for row in data {
-                    let mut first = true;
-                    for column in row {
-                        if !first {
-                            f.write_char(' ')?;
-                        }
+/// Emission of optional headers whose value is [`None`].
+///
+/// Notes, `nodata` is always written since it is a required key.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum OptionalHeaders {
+    /// Writes all keys, [`None`] as `---` (default)
+    Placeholder,
+    /// Omits keys entirely
+    Omit,
+}
 
-                        match (column, self.header.nodata.as_ref()) {
-                            // error branch
-                            // nodata is empty even value is None
-                            (None, None) => f.write_str("-9999.9999")?,
-                            (Some(v), _) | (None, Some(v)) => write!(f, "{:10.4}", v)?,
-                        }
+/// Format of `creation date`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum CreationDateFormat {
+    /// `DD/MM/YYYY`, e.g. `31/05/2020` (default)
+    DayMonthYear,
+    /// ISO 8601 `YYYY-MM-DD`, e.g. `2020-05-31`
+    Iso,
+}
 
-                        first = false;
-                    }
+/// Format of DMS coordinates, e.g. `  39°50'00"`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct DmsFormat {
+    /// Width of degree field, including sign (default: `4`)
+    pub degree_width: usize,
+    /// Pads degree field with `0` instead of space (default: `false`)
+    pub zero_pad: bool,
+    /// Writes `+` sign on non-negative degree (default: `false`)
+    pub plus_sign: bool,
+    /// Writes hemisphere letter instead of sign on latitude and longitude (default: `false`),
+    /// e.g. `39°50'00"N` and `121°50'00"W`
+    ///
+    /// `plus_sign` is ignored on them, and deltas are written with sign as usual.
+    pub hemisphere: bool,
+}
 
-                    f.write_char('\n')?;
-                }
-            }
-            Data::Sparse(data) => {
-                for (a, b, c) in data {
-                    f.write_str(&a._to_string(&self.header.coord_units))?;
-                    f.write_char(' ')?;
+impl Default for DmsFormat {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            degree_width: 4,
+            zero_pad: false,
+            plus_sign: false,
+            hemisphere: false,
+        }
+    }
+}
 
-                    f.write_str(&b._to_string(&self.header.coord_units))?;
-                    f.write_char(' ')?;
+impl Default for OptionalHeaders {
+    #[inline]
+    fn default() -> Self {
+        Self::Placeholder
+    }
+}
 
-                    write!(f, "{:10.4}", c)?;
+impl Default for CreationDateFormat {
+    #[inline]
+    fn default() -> Self {
+        Self::DayMonthYear
+    }
+}
 
-                    f.write_char('\n')?;
-                }
-            }
-        }
+struct WithOptions<'a> {
+    isg: &'a ISG,
+    options: &'a WriteOptions,
+}
 
-        Ok(())
+impl Display for WithOptions<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt_isg(self.isg, f, self.options)
     }
 }
 
-impl Display for Header {
+impl Display for ISG {
+    /// Notes, the behavior is unspecified when data has [`None`] even if `nodata` is [`None`].
+    #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("model name     : ")?;
-        match self.model_name.as_ref() {
-            None => f.write_str("---")?,
-            Some(s) => f.write_str(s)?,
-        }
-        f.write_char('\n')?;
-
-        f.write_str("model year     : ")?;
-        match self.model_year.as_ref() {
-            None => f.write_str("---")?,
-            Some(s) => f.write_str(s)?,
-        }
-        f.write_char('\n')?;
+        fmt_isg(self, f, &WriteOptions::default())
+    }
+}
 
-        f.write_str("model type     : ")?;
-        match self.model_type.as_ref() {
-            None => f.write_str("---")?,
-            Some(s) => Display::fmt(s, f)?,
+fn fmt_isg(isg: &ISG, f: &mut Formatter<'_>, options: &WriteOptions) -> std::fmt::Result {
+    if !isg.comment.is_empty() {
+        f.write_str(&isg.comment)?;
+        if !isg.comment.ends_with('\n') {
+            f.write_char('\n')?;
         }
-        f.write_char('\n')?;
+    }
 
-        f.write_str("data type      : ")?;
-        match self.data_type.as_ref() {
-            None => f.write_str("---")?,
-            Some(s) => Display::fmt(s, f)?,
-        }
-        f.write_char('\n')?;
+    f.write_str("begin_of_head ================================================\n")?;
 
-        f.write_str("data units     : ")?;
-        match self.data_units.as_ref() {
-            None => f.write_str("---")?,
-            Some(s) => Display::fmt(s, f)?,
-        }
-        f.write_char('\n')?;
+    fmt_header(&isg.header, f, options)?;
 
-        f.write_str("data format    : ")?;
-        Display::fmt(&self.data_format, f)?;
-        f.write_char('\n')?;
+    f.write_str("end_of_head ==================================================\n")?;
 
-        f.write_str("data ordering  : ")?;
-        match self.data_ordering.as_ref() {
-            None => f.write_str("---")?,
-            Some(s) => Display::fmt(s, f)?,
-        }
-        f.write_char('\n')?;
+    match &isg.data {
+        Data::Grid(data) => {
+            // widens all columns consistently when a value overflows
+            let width = data
+                .rows()
+                .flatten()
+                .filter_map(|v| v.as_ref().or(isg.header.nodata.as_ref()))
+                .map(value_width)
+                .fold(VALUE_WIDTH, usize::max);
 
-        f.write_str("ref ellipsoid  : ")?;
-        match self.ref_ellipsoid.as_ref() {
-            None => f.write_str("---")?,
-            Some(s) => f.write_str(s)?,
-        }
-        f.write_char('\n')?;
+            for row in data.rows() {
+                let mut first = true;
+                for column in row {
+                    if !first {
+                        f.write_char(' ')?;
+                    }
 
-        f.write_str("ref frame      : ")?;
-        match self.ref_frame.as_ref() {
-            None => f.write_str("---")?,
-            Some(s) => f.write_str(s)?,
-        }
-        f.write_char('\n')?;
+                    match (column, isg.header.nodata.as_ref()) {
+                        // error branch
+                        // nodata is empty even value is None
+                        (None, None) => write!(f, "{:>1$}", "-9999.9999", width)?,
+                        (Some(v), _) | (None, Some(v)) => fmt_value(v, width, f, options)?,
+                    }
 
-        f.write_str("height datum   : ")?;
-        match self.height_datum.as_ref() {
-            None => f.write_str("---")?,
-            Some(s) => f.write_str(s)?,
-        }
-        f.write_char('\n')?;
+                    first = false;
+                }
 
-        f.write_str("tide system    : ")?;
-        match self.tide_system.as_ref() {
-            None => f.write_str("---")?,
-            Some(s) => Display::fmt(s, f)?,
+                f.write_char('\n')?;
+            }
         }
-        f.write_char('\n')?;
-
-        f.write_str("coord type     : ")?;
-        Display::fmt(&self.coord_type, f)?;
-        f.write_char('\n')?;
+        Data::Sparse(data) => {
+            let mut data: Vec<_> = data.iter().collect();
+            if options.sort_sparse {
+                if let Some(cmp) = sparse_cmp(isg.header.data_ordering.as_ref()) {
+                    data.sort_by(|a, b| cmp((&a.0, &a.1), (&b.0, &b.1)));
+                }
+            }
 
-        f.write_str("coord units    : ")?;
-        Display::fmt(&self.coord_units, f)?;
-        f.write_char('\n')?;
+            let rows: Vec<_> = data
+                .into_iter()
+                .map(|(a, b, c)| {
+                    (
+                        a._to_string(&isg.header.coord_units, &options.dms, Some(Axis::Lat)),
+                        b._to_string(&isg.header.coord_units, &options.dms, Some(Axis::Lon)),
+                        c,
+                    )
+                })
+                .collect();
+
+            // widens all columns consistently when a value overflows
+            let (a_width, b_width, c_width) = rows.iter().fold(
+                (0, 0, VALUE_WIDTH),
+                |(a_width, b_width, c_width), (a, b, c)| {
+                    (
+                        a_width.max(a.chars().count()),
+                        b_width.max(b.chars().count()),
+                        c_width.max(value_width(c)),
+                    )
+                },
+            );
+
+            for (a, b, c) in rows {
+                if options.compact {
+                    fmt_str(&a, f, options)?;
+                    f.write_char(' ')?;
+                    fmt_str(&b, f, options)?;
+                } else {
+                    write!(f, "{:>aw$} {:>bw$}", a, b, aw = a_width, bw = b_width)?;
+                }
+                f.write_char(' ')?;
 
-        f.write_str("map projection : ")?;
-        match self.map_projection.as_ref() {
-            None => f.write_str("---")?,
-            Some(s) => f.write_str(s)?,
-        }
-        f.write_char('\n')?;
+                fmt_value(c, c_width, f, options)?;
 
-        f.write_str("EPSG code      : ")?;
-        match self.EPSG_code.as_ref() {
-            None => f.write_str("---")?,
-            Some(s) => f.write_str(s)?,
-        }
-        f.write_char('\n')?;
-
-        match &self.data_bounds {
-            DataBounds::GridGeodetic {
-                lat_min,
-                lat_max,
-                lon_min,
-                lon_max,
-                delta_lat,
-                delta_lon,
-            } => {
-                f.write_str("lat min        = ")?;
-                f.write_str(&lat_min._to_string(&self.coord_units))?;
                 f.write_char('\n')?;
-                f.write_str("lat max        = ")?;
-                f.write_str(&lat_max._to_string(&self.coord_units))?;
-                f.write_char('\n')?;
-                f.write_str("lon min        = ")?;
-                f.write_str(&lon_min._to_string(&self.coord_units))?;
-                f.write_char('\n')?;
-                f.write_str("lon max        = ")?;
-                f.write_str(&lon_max._to_string(&self.coord_units))?;
-                f.write_char('\n')?;
-                f.write_str("delta lat      = ")?;
-                f.write_str(&delta_lat._to_string(&self.coord_units))?;
-                f.write_char('\n')?;
-                f.write_str("delta lon      = ")?;
-                f.write_str(&delta_lon._to_string(&self.coord_units))?;
-                f.write_char('\n')?;
-            }
-            DataBounds::GridProjected {
-                north_min,
-                north_max,
-                east_min,
-                east_max,
-                delta_north,
-                delta_east,
-            } => {
-                f.write_str("north min      = ")?;
-                f.write_str(&north_min._to_string(&self.coord_units))?;
-                f.write_char('\n')?;
-                f.write_str("north max      = ")?;
-                f.write_str(&north_max._to_string(&self.coord_units))?;
-                f.write_char('\n')?;
-                f.write_str("east min       = ")?;
-                f.write_str(&east_min._to_string(&self.coord_units))?;
-                f.write_char('\n')?;
-                f.write_str("east max       = ")?;
-                f.write_str(&east_max._to_string(&self.coord_units))?;
-                f.write_char('\n')?;
-                f.write_str("delta north    = ")?;
-                f.write_str(&delta_north._to_string(&self.coord_units))?;
-                f.write_char('\n')?;
-                f.write_str("delta east     = ")?;
-                f.write_str(&delta_east._to_string(&self.coord_units))?;
-                f.write_char('\n')?;
-            }
-            DataBounds::SparseGeodetic {
-                lat_min,
-                lat_max,
-                lon_min,
-                lon_max,
-            } => {
-                f.write_str("lat min        = ")?;
-                f.write_str(&lat_min._to_string(&self.coord_units))?;
-                f.write_char('\n')?;
-                f.write_str("lat max        = ")?;
-                f.write_str(&lat_max._to_string(&self.coord_units))?;
-                f.write_char('\n')?;
-                f.write_str("lon min        = ")?;
-                f.write_str(&lon_min._to_string(&self.coord_units))?;
-                f.write_char('\n')?;
-                f.write_str("lon max        = ")?;
-                f.write_str(&lon_max._to_string(&self.coord_units))?;
-                f.write_char('\n')?;
-                f.write_str("delta lat      = ---\n")?;
-                f.write_str("delta lon      = ---\n")?;
-            }
-            DataBounds::SparseProjected {
-                north_min,
-                north_max,
-                east_min,
-                east_max,
-            } => {
-                f.write_str("north min      = ")?;
-                f.write_str(&north_min._to_string(&self.coord_units))?;
-                f.write_char('\n')?;
-                f.write_str("north max      = ")?;
-                f.write_str(&north_max._to_string(&self.coord_units))?;
-                f.write_char('\n')?;
-                f.write_str("east min       = ")?;
-                f.write_str(&east_min._to_string(&self.coord_units))?;
-                f.write_char('\n')?;
-                f.write_str("east max       = ")?;
-                f.write_str(&east_max._to_string(&self.coord_units))?;
-                f.write_char('\n')?;
-                f.write_str("delta north    = ---\n")?;
-                f.write_str("delta east     = ---\n")?;
             }
         }
+    }
+
+    Ok(())
+}
 
-        f.write_str("nrows          = ")?;
-        write!(f, "{:>11}", &self.nrows)?;
-        f.write_char('\n')?;
+/// Width of data value column
+const VALUE_WIDTH: usize = 10;
 
-        f.write_str("ncols          = ")?;
-        write!(f, "{:>11}", &self.ncols)?;
-        f.write_char('\n')?;
+/// Returns the number of chars of `v` without padding.
+#[inline]
+fn value_width(v: &f64) -> usize {
+    format!("{:.4}", v).len()
+}
 
-        f.write_str("nodata         = ")?;
-        match self.nodata.as_ref() {
-            None => f.write_str("---")?,
-            Some(v) => write!(f, " {:10.4}", v)?,
-        }
-        f.write_char('\n')?;
-
-        f.write_str("creation date  = ")?;
-        match self.creation_date.as_ref() {
-            None => f.write_str("---")?,
-            Some(v) => {
-                let s = format!("{:02}/{:02}/{:04}", v.day, v.month, v.year);
-                write!(f, "{:>11}", s)?
-            }
-        }
-        f.write_char('\n')?;
+#[inline]
+fn fmt_value(
+    v: &f64,
+    width: usize,
+    f: &mut Formatter<'_>,
+    options: &WriteOptions,
+) -> std::fmt::Result {
+    if options.compact {
+        write!(f, "{:.4}", v)
+    } else {
+        write!(f, "{:1$.4}", v, width)
+    }
+}
 
-        f.write_str("ISG format     = ")?;
-        write!(f, "{:>11}", &self.ISG_format)?;
-        f.write_char('\n')?;
+#[inline]
+fn fmt_str(s: &str, f: &mut Formatter<'_>, options: &WriteOptions) -> std::fmt::Result {
+    if options.compact {
+        f.write_str(s.trim())
+    } else {
+        f.write_str(s)
+    }
+}
+
+#[inline]
+fn fmt_header_line(
+    key: &str,
+    sep: char,
+    value: &str,
+    f: &mut Formatter<'_>,
+    options: &WriteOptions,
+) -> std::fmt::Result {
+    if options.compact {
+        f.write_str(key)?;
+        f.write_char(sep)?;
+    } else {
+        write!(f, "{:<15}{} ", key, sep)?;
+    }
+    fmt_str(value, f, options)?;
+    f.write_char('\n')
+}
+
+#[inline]
+fn fmt_optional_header_line<T: ToString>(
+    key: &str,
+    sep: char,
+    value: Option<&T>,
+    f: &mut Formatter<'_>,
+    options: &WriteOptions,
+) -> std::fmt::Result {
+    match (value, options.optional_headers) {
+        (Some(value), _) => fmt_header_line(key, sep, &value.to_string(), f, options),
+        (None, OptionalHeaders::Placeholder) => fmt_header_line(key, sep, "---", f, options),
+        (None, OptionalHeaders::Omit) => Ok(()),
+    }
+}
 
-        Ok(())
+impl Display for Header {
+    /// Serialize as header section,
+    /// that is lines between `begin_of_head` and `end_of_head` (exclusive).
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt_header(self, f, &WriteOptions::default())
+    }
+}
+
+fn fmt_header(header: &Header, f: &mut Formatter<'_>, options: &WriteOptions) -> std::fmt::Result {
+    fmt_optional_header_line("model name", ':', header.model_name.as_ref(), f, options)?;
+    fmt_optional_header_line("model year", ':', header.model_year.as_ref(), f, options)?;
+    fmt_optional_header_line("model type", ':', header.model_type.as_ref(), f, options)?;
+    fmt_optional_header_line("data type", ':', header.data_type.as_ref(), f, options)?;
+    fmt_optional_header_line("data units", ':', header.data_units.as_ref(), f, options)?;
+    fmt_header_line(
+        "data format",
+        ':',
+        &header.data_format.to_string(),
+        f,
+        options,
+    )?;
+    fmt_optional_header_line(
+        "data ordering",
+        ':',
+        header.data_ordering.as_ref(),
+        f,
+        options,
+    )?;
+    fmt_optional_header_line(
+        "ref ellipsoid",
+        ':',
+        header.ref_ellipsoid.as_ref(),
+        f,
+        options,
+    )?;
+    fmt_optional_header_line("ref frame", ':', header.ref_frame.as_ref(), f, options)?;
+    fmt_optional_header_line(
+        "height datum",
+        ':',
+        header.height_datum.as_ref(),
+        f,
+        options,
+    )?;
+    fmt_optional_header_line("tide system", ':', header.tide_system.as_ref(), f, options)?;
+    fmt_header_line(
+        "coord type",
+        ':',
+        &header.coord_type.to_string(),
+        f,
+        options,
+    )?;
+    fmt_header_line(
+        "coord units",
+        ':',
+        &header.coord_units.to_string(),
+        f,
+        options,
+    )?;
+    fmt_optional_header_line(
+        "map projection",
+        ':',
+        header.map_projection.as_ref(),
+        f,
+        options,
+    )?;
+    fmt_optional_header_line("EPSG code", ':', header.EPSG_code.as_ref(), f, options)?;
+
+    let coord = |c: &Coord| c._to_string(&header.coord_units, &options.dms, None);
+    let lat = |c: &Coord| c._to_string(&header.coord_units, &options.dms, Some(Axis::Lat));
+    let lon = |c: &Coord| c._to_string(&header.coord_units, &options.dms, Some(Axis::Lon));
+    match &header.data_bounds {
+        DataBounds::GridGeodetic {
+            lat_min,
+            lat_max,
+            lon_min,
+            lon_max,
+            delta_lat,
+            delta_lon,
+        } => {
+            fmt_header_line("lat min", '=', &lat(lat_min), f, options)?;
+            fmt_header_line("lat max", '=', &lat(lat_max), f, options)?;
+            fmt_header_line("lon min", '=', &lon(lon_min), f, options)?;
+            fmt_header_line("lon max", '=', &lon(lon_max), f, options)?;
+            fmt_header_line("delta lat", '=', &coord(delta_lat), f, options)?;
+            fmt_header_line("delta lon", '=', &coord(delta_lon), f, options)?;
+        }
+        DataBounds::GridProjected {
+            north_min,
+            north_max,
+            east_min,
+            east_max,
+            delta_north,
+            delta_east,
+        } => {
+            fmt_header_line("north min", '=', &coord(north_min), f, options)?;
+            fmt_header_line("north max", '=', &coord(north_max), f, options)?;
+            fmt_header_line("east min", '=', &coord(east_min), f, options)?;
+            fmt_header_line("east max", '=', &coord(east_max), f, options)?;
+            fmt_header_line("delta north", '=', &coord(delta_north), f, options)?;
+            fmt_header_line("delta east", '=', &coord(delta_east), f, options)?;
+        }
+        DataBounds::SparseGeodetic {
+            lat_min,
+            lat_max,
+            lon_min,
+            lon_max,
+        } => {
+            fmt_header_line("lat min", '=', &lat(lat_min), f, options)?;
+            fmt_header_line("lat max", '=', &lat(lat_max), f, options)?;
+            fmt_header_line("lon min", '=', &lon(lon_min), f, options)?;
+            fmt_header_line("lon max", '=', &lon(lon_max), f, options)?;
+            fmt_optional_header_line("delta lat", '=', None::<&Coord>, f, options)?;
+            fmt_optional_header_line("delta lon", '=', None::<&Coord>, f, options)?;
+        }
+        DataBounds::SparseProjected {
+            north_min,
+            north_max,
+            east_min,
+            east_max,
+        } => {
+            fmt_header_line("north min", '=', &coord(north_min), f, options)?;
+            fmt_header_line("north max", '=', &coord(north_max), f, options)?;
+            fmt_header_line("east min", '=', &coord(east_min), f, options)?;
+            fmt_header_line("east max", '=', &coord(east_max), f, options)?;
+            fmt_optional_header_line("delta north", '=', None::<&Coord>, f, options)?;
+            fmt_optional_header_line("delta east", '=', None::<&Coord>, f, options)?;
+        }
     }
+
+    fmt_header_line("nrows", '=', &format!("{:>11}", header.nrows), f, options)?;
+    fmt_header_line("ncols", '=', &format!("{:>11}", header.ncols), f, options)?;
+
+    let nodata = match header.nodata.as_ref() {
+        None => "---".to_string(),
+        Some(v) => format!(" {:10.4}", v),
+    };
+    fmt_header_line("nodata", '=', &nodata, f, options)?;
+
+    let creation_date = header
+        .creation_date
+        .as_ref()
+        .map(|v| match options.creation_date {
+            CreationDateFormat::DayMonthYear => format!(
+                "{:>11}",
+                format!("{:02}/{:02}/{:04}", v.day, v.month, v.year)
+            ),
+            CreationDateFormat::Iso => format!("{:>11}", v.to_string()),
+        });
+    fmt_optional_header_line("creation date", '=', creation_date.as_ref(), f, options)?;
+
+    fmt_header_line(
+        "ISG format",
+        '=',
+        &format!("{:>11}", header.ISG_format),
+        f,
+        options,
+    )?;
+
+    Ok(())
 }
 
 impl Display for ModelType {
@@ -348,6 +513,9 @@ impl Display for DataOrdering {
             Self::EastNorthN => "east, north, N",
             Self::N => "N",
             Self::Zeta => "zeta",
+            Self::S2NW2E => "S-to-N, W-to-E",
+            Self::N2SE2W => "N-to-S, E-to-W",
+            Self::S2NE2W => "S-to-N, E-to-W",
         };
         f.pad(s)
     }
@@ -396,19 +564,44 @@ impl Display for Coord {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let s = match self {
             Coord::DMS {
+                negative,
                 degree,
                 minutes,
                 second,
-            } => format!("{}°{:02}'{:02}\"", degree, minutes, second),
+            } => format!(
+                "{}{}°{:02}'{:02}\"",
+                if *negative { "-" } else { "" },
+                degree,
+                minutes,
+                second
+            ),
             Coord::Dec(value) => value.to_string(),
         };
         f.pad(&s)
     }
 }
 
+/// Axis of coordinate, which determines hemisphere letters
+#[derive(Clone, Copy)]
+enum Axis {
+    Lat,
+    Lon,
+}
+
+impl Axis {
+    /// Returns letters of (non-negative, negative)
+    #[inline]
+    fn letters(self) -> (char, char) {
+        match self {
+            Self::Lat => ('N', 'S'),
+            Self::Lon => ('E', 'W'),
+        }
+    }
+}
+
 impl Coord {
     #[inline]
-    fn _to_string(&self, coord_units: &CoordUnits) -> String {
+    fn _to_string(&self, coord_units: &CoordUnits, dms: &DmsFormat, axis: Option<Axis>) -> String {
         // Should be like the following code...?
         //
         // match (self, coord_units) {
@@ -420,10 +613,42 @@ impl Coord {
 
         match self {
             Self::DMS {
+                negative,
                 degree,
                 minutes,
                 second,
-            } => format!("{:>4}°{:02}'{:02}\"", degree, minutes, second),
+            } => {
+                let hemisphere = match axis {
+                    Some(axis) if dms.hemisphere => {
+                        let (plus, minus) = axis.letters();
+                        Some(if *negative { minus } else { plus })
+                    }
+                    _ => None,
+                };
+
+                // the sign is written by hand, since `degree` of -0°20'00" is 0
+                let sign = match (negative, dms.plus_sign) {
+                    _ if hemisphere.is_some() => "",
+                    (true, _) => "-",
+                    (false, true) => "+",
+                    (false, false) => "",
+                };
+                let width = dms.degree_width;
+                let degree = if dms.zero_pad {
+                    format!(
+                        "{}{:0width$}",
+                        sign,
+                        degree,
+                        width = width.saturating_sub(sign.len())
+                    )
+                } else {
+                    format!("{:>width$}", format!("{}{}", sign, degree), width = width)
+                };
+                match hemisphere {
+                    None => format!("{}°{:02}'{:02}\"", degree, minutes, second),
+                    Some(c) => format!("{}°{:02}'{:02}\"{}", degree, minutes, second, c),
+                }
+            }
             Self::Dec(value) => match coord_units {
                 CoordUnits::Deg => format!("{:11.6}", value),
                 CoordUnits::DMS => {
@@ -38,7 +38,9 @@ impl ParseError {
     pub fn is_data_section(&self) -> bool {
         matches!(
             self.kind,
-            ParseErrorKind::InvalidData { .. } | ParseErrorKind::InvalidDataLength { .. }
+            ParseErrorKind::InvalidData { .. }
+                | ParseErrorKind::InvalidDataLength { .. }
+                | ParseErrorKind::StrayLine { .. }
         )
     }
 
@@ -84,6 +86,8 @@ pub(crate) enum ParseErrorKind {
         direction: DataDirection,
         expected: usize,
     },
+    /// Non-data line found in data section
+    StrayLine { value: Box<str> },
 }
 
 impl ParseError {
@@ -205,6 +209,11 @@ impl ParseError {
         )
     }
 
+    #[cold]
+    pub(crate) fn stray_line(line: &str, lineno: usize) -> Self {
+        Self::with_lineno(ParseErrorKind::StrayLine { value: line.into() }, lineno)
+    }
+
     #[cold]
     pub(crate) fn too_short_data(direction: DataDirection, expected: usize, lineno: usize) -> Self {
         Self::with_lineno(
@@ -254,6 +263,7 @@ impl Display for ParseError {
             } => Display::fmt(&self.kind, f),
             ParseErrorKind::MissingSeparator
             | ParseErrorKind::InvalidDataBounds { .. }
+            | ParseErrorKind::StrayLine { .. }
             | ParseErrorKind::InvalidDataLength {
                 direction: DataDirection::Column,
                 ..
@@ -299,6 +309,7 @@ impl Display for ParseErrorKind {
             ),
             // data
             Self::InvalidData { value } => write!(f, "invalid data: `{}`", value),
+            Self::StrayLine { value } => write!(f, "unexpected non-data line: `{}`", value),
             Self::InvalidDataLength {
                 kind,
                 direction,
@@ -346,6 +357,42 @@ impl Display for InvalidDataLengthKind {
     }
 }
 
+/// Warning on lenient parsing, see [`from_str_lenient`](crate::from_str_lenient)
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ParseWarning {
+    line: Box<str>,
+    lineno: usize,
+}
+
+impl ParseWarning {
+    #[cold]
+    pub(crate) fn stray_line(line: &str, lineno: usize) -> Self {
+        Self {
+            line: line.into(),
+            lineno,
+        }
+    }
+
+    /// Returns the skipped line
+    pub fn line(&self) -> &str {
+        &self.line
+    }
+
+    pub fn lineno(&self) -> usize {
+        self.lineno
+    }
+}
+
+impl Display for ParseWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "skipped non-data line: `{}` (line: {})",
+            self.line, self.lineno
+        )
+    }
+}
+
 /// Error on parsing header value of ISG format
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct ParseValueError {
@@ -415,6 +462,9 @@ pub struct ValidationError {
 
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub(crate) enum ValidationErrorKind {
+    Comment {
+        lineno: usize,
+    },
     DataBounds {
         data_format: DataFormat,
         coord_type: CoordType,
@@ -422,6 +472,10 @@ pub(crate) enum ValidationErrorKind {
     CoordUnitsOnHeader {
         kind: HeaderField,
     },
+    MissingHeader {
+        kind: HeaderField,
+    },
+    MissingData,
     CoordUnitsOnData {
         lineno: usize,
         column: usize,
@@ -434,7 +488,14 @@ pub(crate) enum ValidationErrorKind {
         ncols: usize,
         actual: Option<usize>,
     },
+    Shape {
+        nrows: usize,
+        ncols: usize,
+    },
     ISGFormat,
+    Unrepresentable {
+        kind: HeaderField,
+    },
 }
 
 impl ValidationError {
@@ -443,6 +504,11 @@ impl ValidationError {
         Self { kind }
     }
 
+    #[cold]
+    pub(crate) fn comment(lineno: usize) -> Self {
+        Self::new(ValidationErrorKind::Comment { lineno })
+    }
+
     #[cold]
     pub(crate) fn data_bounds(data_format: DataFormat, coord_type: CoordType) -> Self {
         Self::new(ValidationErrorKind::DataBounds {
@@ -455,6 +521,16 @@ impl ValidationError {
     pub(crate) fn coord_units_header(kind: HeaderField) -> Self {
         Self::new(ValidationErrorKind::CoordUnitsOnHeader { kind })
     }
+    #[cold]
+    pub(crate) fn missing_header(kind: HeaderField) -> Self {
+        Self::new(ValidationErrorKind::MissingHeader { kind })
+    }
+
+    #[cold]
+    pub(crate) fn missing_data() -> Self {
+        Self::new(ValidationErrorKind::MissingData)
+    }
+
     #[cold]
     pub(crate) fn coord_units_data(lineno: usize, column: usize) -> Self {
         Self::new(ValidationErrorKind::CoordUnitsOnData { lineno, column })
@@ -470,10 +546,20 @@ impl ValidationError {
         Self::new(ValidationErrorKind::NoCols { ncols, actual })
     }
 
+    #[cold]
+    pub(crate) fn shape(nrows: usize, ncols: usize) -> Self {
+        Self::new(ValidationErrorKind::Shape { nrows, ncols })
+    }
+
     #[cold]
     pub(crate) fn isg_format() -> Self {
         Self::new(ValidationErrorKind::ISGFormat)
     }
+
+    #[cold]
+    pub(crate) fn unrepresentable(kind: HeaderField) -> Self {
+        Self::new(ValidationErrorKind::Unrepresentable { kind })
+    }
 }
 
 impl Error for ValidationError {}
@@ -487,6 +573,11 @@ impl Display for ValidationError {
 impl Display for ValidationErrorKind {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match &self {
+            Self::Comment { lineno } => write!(
+                f,
+                "comment line starts with `begin_of_head` (line: {})",
+                lineno
+            ),
             Self::DataBounds {
                 data_format,
                 coord_type,
@@ -505,6 +596,8 @@ impl Display for ValidationErrorKind {
             Self::CoordUnitsOnHeader { kind } => {
                 write!(f, "unexpected data format on `{}`", kind)
             }
+            Self::MissingHeader { kind } => write!(f, "missing header key: `{}`", kind),
+            Self::MissingData => f.write_str("missing data"),
             Self::CoordUnitsOnData { lineno, column } => write!(
                 f,
                 "unexpected data format on data (row: {}, column: {})",
@@ -523,7 +616,260 @@ impl Display for ValidationErrorKind {
                     ncols, a
                 ),
             },
+            Self::Shape { nrows, ncols } => write!(
+                f,
+                "nrows: {} and ncols: {} exceed `data_bounds`",
+                nrows, ncols
+            ),
             Self::ISGFormat => f.write_str("invalid `ISG format`, expected `\"2.0\"`"),
+            Self::Unrepresentable { kind } => {
+                write!(f, "`{}` is not representable in DMS", kind)
+            }
+        }
+    }
+}
+
+/// Error on operations between two ISGs which are not compatible,
+/// e.g. [`ISG::difference`](crate::ISG::difference)
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct MismatchError {
+    kind: MismatchErrorKind,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub(crate) enum MismatchErrorKind {
+    NotGrid,
+    DataBounds,
+    Shape {
+        expected: (usize, usize),
+        actual: (usize, usize),
+    },
+    CoordUnits,
+    DataUnits,
+    Delta,
+    Misaligned,
+    Empty,
+    NoOverlap,
+}
+
+impl MismatchError {
+    #[cold]
+    fn new(kind: MismatchErrorKind) -> Self {
+        Self { kind }
+    }
+
+    #[cold]
+    pub(crate) fn not_grid() -> Self {
+        Self::new(MismatchErrorKind::NotGrid)
+    }
+
+    #[cold]
+    pub(crate) fn data_bounds() -> Self {
+        Self::new(MismatchErrorKind::DataBounds)
+    }
+
+    #[cold]
+    pub(crate) fn shape(expected: (usize, usize), actual: (usize, usize)) -> Self {
+        Self::new(MismatchErrorKind::Shape { expected, actual })
+    }
+
+    #[cold]
+    pub(crate) fn coord_units() -> Self {
+        Self::new(MismatchErrorKind::CoordUnits)
+    }
+
+    #[cold]
+    pub(crate) fn data_units() -> Self {
+        Self::new(MismatchErrorKind::DataUnits)
+    }
+
+    #[cold]
+    pub(crate) fn delta() -> Self {
+        Self::new(MismatchErrorKind::Delta)
+    }
+
+    #[cold]
+    pub(crate) fn misaligned() -> Self {
+        Self::new(MismatchErrorKind::Misaligned)
+    }
+
+    #[cold]
+    pub(crate) fn empty() -> Self {
+        Self::new(MismatchErrorKind::Empty)
+    }
+
+    #[cold]
+    pub(crate) fn no_overlap() -> Self {
+        Self::new(MismatchErrorKind::NoOverlap)
+    }
+}
+
+impl Error for MismatchError {}
+
+impl Display for MismatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.kind, f)
+    }
+}
+
+impl Display for MismatchErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self {
+            Self::NotGrid => f.write_str("data is not grid"),
+            Self::DataBounds => f.write_str("`data bounds` mismatch"),
+            Self::Shape { expected, actual } => write!(
+                f,
+                "shape mismatch, expected: ({}, {}) but actual: ({}, {})",
+                expected.0, expected.1, actual.0, actual.1
+            ),
+            Self::CoordUnits => f.write_str("`coord units` mismatch"),
+            Self::DataUnits => f.write_str("`data units` mismatch"),
+            Self::Delta => f.write_str("delta mismatch"),
+            Self::Misaligned => f.write_str("grid nodes are not aligned"),
+            Self::Empty => f.write_str("no grid is given"),
+            Self::NoOverlap => f.write_str("no overlapping cell"),
+        }
+    }
+}
+
+/// Error on conversion of data format,
+/// e.g. [`ISG::to_grid`](crate::ISG::to_grid)
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ConversionError {
+    kind: ConversionErrorKind,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub(crate) enum ConversionErrorKind {
+    NotSparse,
+    InvalidDelta,
+    InvalidParameter { name: &'static str },
+    OffGrid { index: usize },
+    Duplicated { index: usize },
+}
+
+impl ConversionError {
+    #[cold]
+    fn new(kind: ConversionErrorKind) -> Self {
+        Self { kind }
+    }
+
+    #[cold]
+    pub(crate) fn not_sparse() -> Self {
+        Self::new(ConversionErrorKind::NotSparse)
+    }
+
+    #[cold]
+    pub(crate) fn invalid_delta() -> Self {
+        Self::new(ConversionErrorKind::InvalidDelta)
+    }
+
+    #[cold]
+    pub(crate) fn invalid_parameter(name: &'static str) -> Self {
+        Self::new(ConversionErrorKind::InvalidParameter { name })
+    }
+
+    #[cold]
+    pub(crate) fn off_grid(index: usize) -> Self {
+        Self::new(ConversionErrorKind::OffGrid { index })
+    }
+
+    #[cold]
+    pub(crate) fn duplicated(index: usize) -> Self {
+        Self::new(ConversionErrorKind::Duplicated { index })
+    }
+}
+
+impl Error for ConversionError {}
+
+impl Display for ConversionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.kind, f)
+    }
+}
+
+impl Display for ConversionErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self {
+            Self::NotSparse => f.write_str("data is not sparse"),
+            Self::InvalidDelta => f.write_str("delta must be positive"),
+            Self::InvalidParameter { name } => write!(f, "`{}` must be positive", name),
+            Self::OffGrid { index } => write!(f, "record is off grid (index: {})", index),
+            Self::Duplicated { index } => {
+                write!(f, "record is duplicated on grid (index: {})", index)
+            }
+        }
+    }
+}
+
+/// Error on making [`Coord`](crate::Coord) of invalid values,
+/// e.g. [`Coord::try_with_dms`](crate::Coord::try_with_dms)
+/// and [`Coord::dms_from_decimal_degrees`](crate::Coord::dms_from_decimal_degrees)
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct CoordError {
+    kind: CoordErrorKind,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub(crate) enum CoordErrorKind {
+    Degree { value: i16 },
+    Minutes { value: u8 },
+    Second { value: u8 },
+    Unrepresentable { value: String },
+}
+
+impl CoordError {
+    #[cold]
+    fn new(kind: CoordErrorKind) -> Self {
+        Self { kind }
+    }
+
+    #[cold]
+    pub(crate) fn degree(value: i16) -> Self {
+        Self::new(CoordErrorKind::Degree { value })
+    }
+
+    #[cold]
+    pub(crate) fn minutes(value: u8) -> Self {
+        Self::new(CoordErrorKind::Minutes { value })
+    }
+
+    #[cold]
+    pub(crate) fn second(value: u8) -> Self {
+        Self::new(CoordErrorKind::Second { value })
+    }
+
+    #[cold]
+    pub(crate) fn unrepresentable(value: f64) -> Self {
+        Self::new(CoordErrorKind::Unrepresentable {
+            value: value.to_string(),
+        })
+    }
+}
+
+impl Error for CoordError {}
+
+impl Display for CoordError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.kind, f)
+    }
+}
+
+impl Display for CoordErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self {
+            Self::Degree { value } => write!(
+                f,
+                "coordinate out of range, expected: [-360°, 360°] but actual: {}°",
+                value
+            ),
+            Self::Minutes { value } => {
+                write!(f, "minutes must be less than 60, actual: {}", value)
+            }
+            Self::Second { value } => write!(f, "second must be less than 60, actual: {}", value),
+            Self::Unrepresentable { value } => {
+                write!(f, "not representable in DMS, actual: {}°", value)
+            }
         }
     }
 }
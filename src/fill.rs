@@ -0,0 +1,146 @@
+use crate::{Data, Grid, ISG};
+
+/// Method of [`ISG::fill_nodata`]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum FillMethod {
+    /// Value of the nearest defined cell
+    Nearest,
+    /// Inverse distance (squared) weighted mean of defined cells
+    IDW,
+    /// Solution of Laplace's equation, of which boundary is defined cells
+    Laplace,
+}
+
+/// Max number of iterations of [`FillMethod::Laplace`]
+const MAX_ITERATION: usize = 10_000;
+
+/// Convergence tolerance of [`FillMethod::Laplace`]
+const CONVERGENCE: f64 = 1e-9;
+
+impl ISG {
+    /// Fills nodata cells by interpolation of `method`,
+    /// and returns the number of filled cells.
+    ///
+    /// Nodata cells within `max_gap` cells (in both rows and columns) of a defined cell are filled,
+    /// and the others are left as nodata.
+    /// [`FillMethod::Nearest`] and [`FillMethod::IDW`] use defined cells in that window.
+    /// Windows are symmetric in rows and columns, so `data ordering` does not matter.
+    ///
+    /// It does nothing when `self` is sparse.
+    ///
+    /// ```
+    /// # use libisg::FillMethod;
+    /// # let mut isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// // (3, 5) is 2 cells away from defined cells
+    /// assert_eq!(isg.fill_nodata(FillMethod::Nearest, 1), 3);
+    /// assert_eq!(isg.data[(3, 4)], Some(64.6666));
+    /// assert_eq!(isg.data[(3, 5)], None);
+    /// ```
+    pub fn fill_nodata(&mut self, method: FillMethod, max_gap: usize) -> usize {
+        let grid = match &mut self.data {
+            Data::Grid(grid) => grid,
+            Data::Sparse(_) => return 0,
+        };
+
+        let targets: Vec<_> = (0..grid.nrows())
+            .flat_map(|row| (0..grid.ncols()).map(move |col| (row, col)))
+            .filter(|&(row, col)| grid[(row, col)].is_none())
+            .filter(|&(row, col)| window(grid, row, col, max_gap).next().is_some())
+            .collect();
+
+        let values: Vec<_> = targets
+            .iter()
+            .map(|&(row, col)| match method {
+                FillMethod::Nearest => nearest(grid, row, col, max_gap),
+                FillMethod::IDW | FillMethod::Laplace => idw(grid, row, col, max_gap),
+            })
+            .collect();
+
+        for (&(row, col), value) in targets.iter().zip(values) {
+            grid[(row, col)] = Some(value);
+        }
+
+        if method == FillMethod::Laplace {
+            relax(grid, &targets);
+        }
+
+        targets.len()
+    }
+}
+
+/// Returns an iterator of defined cells within `gap` cells, `(distance^2, value)`.
+#[inline]
+fn window(
+    grid: &Grid,
+    row: usize,
+    col: usize,
+    gap: usize,
+) -> impl Iterator<Item = (f64, f64)> + '_ {
+    let rows = row.saturating_sub(gap)..(row + gap + 1).min(grid.nrows());
+    let cols = col.saturating_sub(gap)..(col + gap + 1).min(grid.ncols());
+
+    rows.flat_map(move |i| cols.clone().map(move |j| (i, j)))
+        .filter_map(move |(i, j)| {
+            grid[(i, j)].map(|value| {
+                let (di, dj) = (i as f64 - row as f64, j as f64 - col as f64);
+                (di * di + dj * dj, value)
+            })
+        })
+}
+
+#[inline]
+fn nearest(grid: &Grid, row: usize, col: usize, gap: usize) -> f64 {
+    window(grid, row, col, gap)
+        .fold((f64::INFINITY, f64::NAN), |acc, (d, value)| {
+            if d < acc.0 {
+                (d, value)
+            } else {
+                acc
+            }
+        })
+        .1
+}
+
+#[inline]
+fn idw(grid: &Grid, row: usize, col: usize, gap: usize) -> f64 {
+    let (sum, weight) = window(grid, row, col, gap).fold((0.0, 0.0), |acc, (d, value)| {
+        (acc.0 + value / d, acc.1 + 1.0 / d)
+    });
+    sum / weight
+}
+
+/// Relaxes filled cells to the solution of Laplace's equation by Gauss-Seidel method,
+/// where neighbors which are nodata or out of bounds are ignored.
+fn relax(grid: &mut Grid, targets: &[(usize, usize)]) {
+    for _ in 0..MAX_ITERATION {
+        let mut max_change: f64 = 0.0;
+
+        for &(row, col) in targets {
+            let neighbors = [
+                row.checked_sub(1).map(|i| (i, col)),
+                Some((row + 1, col)),
+                col.checked_sub(1).map(|j| (row, j)),
+                Some((row, col + 1)),
+            ];
+
+            let (sum, n) = neighbors
+                .iter()
+                .flatten()
+                .filter_map(|&(i, j)| *grid.get(i, j)?)
+                .fold((0.0, 0), |acc, value| (acc.0 + value, acc.1 + 1));
+            if n == 0 {
+                continue;
+            }
+
+            let value = sum / n as f64;
+            // never fails, target is in bounds and filled
+            let cell = grid.get_mut(row, col).unwrap();
+            max_change = max_change.max((value - cell.unwrap()).abs());
+            *cell = Some(value);
+        }
+
+        if max_change < CONVERGENCE {
+            break;
+        }
+    }
+}
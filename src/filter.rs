@@ -0,0 +1,285 @@
+use std::cmp::Ordering;
+
+use crate::stats::Accumulator;
+use crate::{Coord, Data, Grid, ISG};
+
+/// Action on spikes, see [`ISG::despike`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum SpikeAction {
+    /// Replaces the value with the median of the window
+    Replace,
+    /// Marks the cell as nodata
+    Nodata,
+}
+
+/// Cell detected by [`ISG::despike`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Spike {
+    /// Row of the cell
+    pub row: usize,
+    /// Column of the cell
+    pub col: usize,
+    /// Original value
+    pub value: f64,
+    /// Median of the window
+    pub median: f64,
+}
+
+/// Cell detected by [`ISG::detect_outliers`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Outlier {
+    /// Row of the cell
+    pub row: usize,
+    /// Column of the cell
+    pub col: usize,
+    /// Coordinate `a` of the cell center, lat or north
+    pub a: Coord,
+    /// Coordinate `b` of the cell center, lon or east
+    pub b: Coord,
+    /// Value of the cell
+    pub value: f64,
+    /// Mean of the neighbors
+    pub mean: f64,
+    /// Standard deviation of the neighbors
+    pub std: f64,
+}
+
+impl ISG {
+    /// Returns a grid smoothed by Gaussian filter of which standard deviation is `sigma_cells` cells.
+    ///
+    /// The kernel is truncated at `3 * sigma_cells` cells,
+    /// and normalized by weights of defined cells,
+    /// so nodata cells do not pull values toward zero and remain nodata.
+    /// Returns [`None`] when `self` is sparse.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `sigma_cells` is negative or not finite.
+    ///
+    /// ```
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// let smoothed = isg.smooth_gaussian(1.0).unwrap();
+    ///
+    /// assert_eq!(smoothed.nodata_count(), isg.nodata_count());
+    /// assert!(smoothed.data[(0, 0)] > isg.data[(0, 0)]);
+    /// ```
+    pub fn smooth_gaussian(&self, sigma_cells: f64) -> Option<ISG> {
+        assert!(
+            sigma_cells >= 0.0 && sigma_cells.is_finite(),
+            "sigma must be non-negative and finite"
+        );
+
+        let grid = match &self.data {
+            Data::Grid(grid) => grid,
+            Data::Sparse(_) => return None,
+        };
+
+        let kernel = gaussian_kernel(sigma_cells);
+        let radius = kernel.len() - 1;
+        let (nrows, ncols) = (grid.nrows(), grid.ncols());
+
+        // separable convolution of (value, weight) pairs, rows then columns
+        let mut horizontal = vec![(0.0, 0.0); nrows * ncols];
+        for (row, values) in grid.rows().enumerate() {
+            for col in 0..ncols {
+                let lo = col.saturating_sub(radius);
+                let hi = (col + radius + 1).min(ncols);
+                horizontal[row * ncols + col] = (lo..hi)
+                    .filter_map(|j| values[j].map(|v| (v, kernel[col.max(j) - col.min(j)])))
+                    .fold((0.0, 0.0), |acc, (v, w)| (acc.0 + w * v, acc.1 + w));
+            }
+        }
+
+        let mut values = Vec::with_capacity(nrows * ncols);
+        for row in 0..nrows {
+            let lo = row.saturating_sub(radius);
+            let hi = (row + radius + 1).min(nrows);
+            for col in 0..ncols {
+                // never fails, in bounds
+                if grid.get(row, col).unwrap().is_none() {
+                    values.push(None);
+                    continue;
+                }
+
+                let (sum, weight) = (lo..hi)
+                    .map(|i| {
+                        let (v, w) = horizontal[i * ncols + col];
+                        let k = kernel[row.max(i) - row.min(i)];
+                        (k * v, k * w)
+                    })
+                    .fold((0.0, 0.0), |acc, (v, w)| (acc.0 + v, acc.1 + w));
+                values.push(Some(sum / weight));
+            }
+        }
+
+        Some(ISG {
+            comment: self.comment.clone(),
+            header: self.header.clone(),
+            data: Data::Grid(Grid::from_vec_unchecked(nrows, ncols, values)),
+        })
+    }
+}
+
+impl ISG {
+    /// Detects spikes, cells of which absolute deviation from the median of the window
+    /// is greater than `threshold`, and modifies them by `action`.
+    ///
+    /// The window is `(2 * window + 1)` × `(2 * window + 1)` cells centered on the cell,
+    /// and the median is computed from defined cells of the original grid (including the center).
+    /// Cells without defined neighbors are not checked.
+    ///
+    /// Returns a report of the modified cells in row-major order,
+    /// it is empty when `self` is sparse.
+    ///
+    /// ```
+    /// # use libisg::SpikeAction;
+    /// # let mut isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// isg.data[(1, 2)] = Some(1000.0);
+    ///
+    /// let spikes = isg.despike(1, 10.0, SpikeAction::Nodata);
+    /// assert_eq!(spikes.len(), 1);
+    /// assert_eq!((spikes[0].row, spikes[0].col), (1, 2));
+    /// assert_eq!(isg.data[(1, 2)], None);
+    /// ```
+    pub fn despike(&mut self, window: usize, threshold: f64, action: SpikeAction) -> Vec<Spike> {
+        let grid = match &mut self.data {
+            Data::Grid(grid) => grid,
+            Data::Sparse(_) => return Vec::new(),
+        };
+
+        let (nrows, ncols) = (grid.nrows(), grid.ncols());
+        let mut buf = Vec::with_capacity((2 * window + 1) * (2 * window + 1));
+        let mut spikes = Vec::new();
+        for row in 0..nrows {
+            for col in 0..ncols {
+                let value = match grid[(row, col)] {
+                    Some(value) => value,
+                    None => continue,
+                };
+
+                buf.clear();
+                for i in row.saturating_sub(window)..(row + window + 1).min(nrows) {
+                    for j in col.saturating_sub(window)..(col + window + 1).min(ncols) {
+                        buf.extend(grid[(i, j)]);
+                    }
+                }
+                if buf.len() < 2 {
+                    continue;
+                }
+
+                let median = median(&mut buf);
+                if (value - median).abs() > threshold {
+                    spikes.push(Spike {
+                        row,
+                        col,
+                        value,
+                        median,
+                    });
+                }
+            }
+        }
+
+        for spike in &spikes {
+            grid[(spike.row, spike.col)] = match action {
+                SpikeAction::Replace => Some(spike.median),
+                SpikeAction::Nodata => None,
+            };
+        }
+
+        spikes
+    }
+}
+
+impl ISG {
+    /// Detects outliers, cells of which deviation from the mean of the neighbors
+    /// is greater than `sigma` times the standard deviation of the neighbors.
+    ///
+    /// The neighbors are defined cells in the `(2 * window + 1)` × `(2 * window + 1)` window
+    /// centered on the cell, excluding the cell itself,
+    /// so an outlier does not inflate its own threshold.
+    /// Cells with less than two defined neighbors are not checked.
+    ///
+    /// Returns a report in row-major order, it is empty when `self` is sparse.
+    /// `self` is not modified, see [`ISG::despike`] to remove them.
+    ///
+    /// ```
+    /// # let mut isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// isg.data[(1, 2)] = Some(1000.0);
+    ///
+    /// let outliers = isg.detect_outliers(1, 3.0);
+    /// assert_eq!(outliers.len(), 1);
+    /// assert_eq!((outliers[0].row, outliers[0].col), (1, 2));
+    /// assert_eq!(outliers[0].value, 1000.0);
+    /// ```
+    pub fn detect_outliers(&self, window: usize, sigma: f64) -> Vec<Outlier> {
+        let grid = match &self.data {
+            Data::Grid(grid) => grid,
+            Data::Sparse(_) => return Vec::new(),
+        };
+
+        let (nrows, ncols) = (grid.nrows(), grid.ncols());
+        let mut outliers = Vec::new();
+        for row in 0..nrows {
+            for col in 0..ncols {
+                let value = match grid[(row, col)] {
+                    Some(value) => value,
+                    None => continue,
+                };
+
+                let mut acc = Accumulator::default();
+                for i in row.saturating_sub(window)..(row + window + 1).min(nrows) {
+                    for j in col.saturating_sub(window)..(col + window + 1).min(ncols) {
+                        if (i, j) != (row, col) {
+                            grid[(i, j)].into_iter().for_each(|v| acc.push(v));
+                        }
+                    }
+                }
+
+                let stats = match acc.finish() {
+                    Some(stats) if stats.count >= 2 => stats,
+                    _ => continue,
+                };
+                if (value - stats.mean).abs() > sigma * stats.std {
+                    // never fails, in bounds of grid
+                    let (a, b) = self.cell_coord(row, col).unwrap();
+                    outliers.push(Outlier {
+                        row,
+                        col,
+                        a,
+                        b,
+                        value,
+                        mean: stats.mean,
+                        std: stats.std,
+                    });
+                }
+            }
+        }
+
+        outliers
+    }
+}
+
+/// Returns the median of non-empty `values`, the order of `values` is changed.
+#[inline]
+fn median(values: &mut [f64]) -> f64 {
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+    let mid = values.len() / 2;
+    if values.len() % 2 == 0 {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    }
+}
+
+/// Returns one side of Gaussian kernel, `[w(0), w(1), ..., w(radius)]`.
+fn gaussian_kernel(sigma: f64) -> Vec<f64> {
+    if sigma == 0.0 {
+        return vec![1.0];
+    }
+
+    let radius = (3.0 * sigma).ceil() as usize;
+    (0..=radius)
+        .map(|i| (-0.5 * (i as f64 / sigma).powi(2)).exp())
+        .collect()
+}
@@ -0,0 +1,152 @@
+use crate::{Data, Grid, Header, ISG};
+
+/// Per-cell quality flags of grid data.
+///
+/// This is an auxiliary layer of [`ISG`] which holds a byte code per cell,
+/// the meaning of each code is defined by users.
+/// It is serialized as a companion ISG file by [`GridFlags::to_isg`].
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct GridFlags {
+    nrows: usize,
+    ncols: usize,
+    flags: Vec<u8>,
+}
+
+impl GridFlags {
+    /// Makes new [`GridFlags`] which all flags are `0`.
+    #[inline]
+    pub fn new(nrows: usize, ncols: usize) -> Self {
+        Self {
+            nrows,
+            ncols,
+            flags: vec![0; nrows * ncols],
+        }
+    }
+
+    /// Returns the number of rows.
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    /// Returns the number of columns.
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    /// Returns flag of the cell, [`None`] if out of bounds.
+    #[inline]
+    pub fn get(&self, row: usize, col: usize) -> Option<u8> {
+        if row < self.nrows && col < self.ncols {
+            Some(self.flags[row * self.ncols + col])
+        } else {
+            None
+        }
+    }
+
+    /// Sets flag of the cell, returns `false` if out of bounds.
+    #[inline]
+    pub fn set(&mut self, row: usize, col: usize, flag: u8) -> bool {
+        if row < self.nrows && col < self.ncols {
+            self.flags[row * self.ncols + col] = flag;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns iterator of `(row, col)` of which flag satisfies `predicate`.
+    pub fn filter<'a, P>(&'a self, mut predicate: P) -> impl Iterator<Item = (usize, usize)> + 'a
+    where
+        P: FnMut(u8) -> bool + 'a,
+    {
+        let ncols = self.ncols;
+        self.flags
+            .iter()
+            .enumerate()
+            .filter(move |(_, flag)| predicate(**flag))
+            .map(move |(i, _)| (i / ncols, i % ncols))
+    }
+
+    /// Makes companion ISG of which data is flags.
+    ///
+    /// The header is copied from `header` except `nodata` which is [`None`].
+    pub fn to_isg(&self, header: &Header) -> ISG {
+        let mut header = header.clone();
+        header.nodata = None;
+        header.nrows = self.nrows;
+        header.ncols = self.ncols;
+
+        let data = self.flags.iter().map(|flag| Some(*flag as f64)).collect();
+
+        ISG {
+            comment: String::new(),
+            header,
+            data: Data::Grid(Grid::from_vec_unchecked(self.nrows, self.ncols, data)),
+        }
+    }
+
+    /// Makes [`GridFlags`] from companion ISG.
+    ///
+    /// Returns [`None`] when `isg` is sparse, or has a value
+    /// which is not an integer from `0` to `255`.
+    pub fn from_isg(isg: &ISG) -> Option<Self> {
+        let data = match &isg.data {
+            Data::Grid(data) => data,
+            Data::Sparse(_) => return None,
+        };
+
+        let nrows = data.nrows();
+        let ncols = data.ncols();
+
+        let mut flags = Vec::with_capacity(nrows * ncols);
+        for row in data.rows() {
+            for value in row {
+                match value {
+                    Some(v) if v.fract() == 0.0 && (0.0..=255.0).contains(v) => {
+                        flags.push(*v as u8)
+                    }
+                    _ => return None,
+                }
+            }
+        }
+
+        Some(Self {
+            nrows,
+            ncols,
+            flags,
+        })
+    }
+}
+
+impl ISG {
+    /// Sets cells to nodata of which flag satisfies `predicate`.
+    ///
+    /// Returns the number of masked cells,
+    /// cells out of bounds of `flags` are untouched.
+    pub fn mask_by_flags<P>(&mut self, flags: &GridFlags, mut predicate: P) -> usize
+    where
+        P: FnMut(u8) -> bool,
+    {
+        let data = match &mut self.data {
+            Data::Grid(data) => data,
+            Data::Sparse(_) => return 0,
+        };
+
+        let mut count = 0;
+        for (nrow, row) in data.rows_mut().enumerate() {
+            for (ncol, value) in row.iter_mut().enumerate() {
+                match flags.get(nrow, ncol) {
+                    Some(flag) if value.is_some() && predicate(flag) => {
+                        *value = None;
+                        count += 1;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        count
+    }
+}
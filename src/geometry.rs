@@ -0,0 +1,515 @@
+use crate::ordering::grid_flips_or_default;
+use crate::{Coord, Data, DataBounds, DataOrdering, DmsRounding, Header, ISG};
+
+/// Cell which a point belongs to, see [`ISG::join_points`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CellRef {
+    /// Point is in the cell which has a value
+    Value { row: usize, col: usize },
+    /// Point is in the cell which is nodata
+    Nodata { row: usize, col: usize },
+    /// Point is outside of the grid
+    Outside,
+}
+
+impl CellRef {
+    /// Returns `(row, col)` of the cell, [`None`] if outside.
+    #[inline]
+    pub fn index(&self) -> Option<(usize, usize)> {
+        match self {
+            Self::Value { row, col } | Self::Nodata { row, col } => Some((*row, *col)),
+            Self::Outside => None,
+        }
+    }
+}
+
+/// Trigonometric functions of [`Coord`] in (decimal) degrees.
+///
+/// ```
+/// use libisg::{Coord, CoordTrig};
+///
+/// let lat = Coord::with_dms(60, 0, 0);
+/// assert!((lat.cos() - 0.5).abs() < 1e-15);
+/// assert_eq!(lat.sin_cos(), (lat.sin(), lat.cos()));
+/// ```
+pub trait CoordTrig {
+    /// Returns the sine.
+    fn sin(&self) -> f64;
+    /// Returns the cosine.
+    fn cos(&self) -> f64;
+    /// Returns the sine and the cosine.
+    fn sin_cos(&self) -> (f64, f64);
+}
+
+impl CoordTrig for Coord {
+    #[inline]
+    fn sin(&self) -> f64 {
+        self.to_radians().sin()
+    }
+
+    #[inline]
+    fn cos(&self) -> f64 {
+        self.to_radians().cos()
+    }
+
+    #[inline]
+    fn sin_cos(&self) -> (f64, f64) {
+        self.to_radians().sin_cos()
+    }
+}
+
+impl ISG {
+    /// Maps points to cells which contain them.
+    ///
+    /// Each point is `(lat, lon)` for geodetic coordinates (in decimal degrees even if `coord units` is `dms`)
+    /// and `(north, east)` for projected coordinates.
+    ///
+    /// Values of the grid are taken at cell centers,
+    /// that is, the cell of `(row, col)` covers a half `delta` around its center.
+    /// Longitude is wrapped around the globe, so grids crossing the antimeridian
+    /// (`lon max` is less than `lon min`, e.g. 170°E to 170°W) are supported.
+    ///
+    /// Every point is [`CellRef::Outside`] when `self` is sparse.
+    pub fn join_points(&self, points: impl IntoIterator<Item = (f64, f64)>) -> Vec<CellRef> {
+        let (geom, data) = match (GridGeometry::new(&self.header), &self.data) {
+            (Some(geom), Data::Grid(data)) => (geom, data),
+            _ => return points.into_iter().map(|_| CellRef::Outside).collect(),
+        };
+        let flips = grid_flips_or_default(self.header.data_ordering);
+        let shape = (data.nrows(), data.ncols());
+
+        points
+            .into_iter()
+            .map(|(a, b)| {
+                match geom
+                    .index(a, b)
+                    .and_then(|index| flip_index(index, shape, flips))
+                {
+                    None => CellRef::Outside,
+                    Some((row, col)) => match data.get(row, col) {
+                        Some(Some(_)) => CellRef::Value { row, col },
+                        Some(None) => CellRef::Nodata { row, col },
+                        None => CellRef::Outside,
+                    },
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the value of the cell which contains the point (`a`, `b`).
+    ///
+    /// The point is `(lat, lon)` in decimal degrees or `(north, east)`,
+    /// and the cell is determined by [`ISG::cell_index`].
+    /// Returns [`None`] when the point is outside of the grid, the cell is nodata, or `self` is sparse.
+    ///
+    /// ```
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// assert_eq!(isg.value_at(41.1, 119.9), Some(30.1234));
+    /// assert_eq!(isg.value_at(0.0, 0.0), None);
+    /// ```
+    pub fn value_at(&self, a: f64, b: f64) -> Option<f64> {
+        let (row, col) = self.cell_index(a, b)?;
+        self.data.get(row, col).copied().flatten()
+    }
+
+    /// Returns index `(row, col)` of data of the cell which contains the point (`a`, `b`),
+    /// the inverse of [`ISG::cell_coord`].
+    ///
+    /// The point is `(lat, lon)` in decimal degrees or `(north, east)`.
+    /// The cell of `(row, col)` covers a half `delta` around its center,
+    /// and a point on the border between two cells belongs to the south (east) one,
+    /// except the outermost borders, which belong to the outermost cells.
+    /// Rounding errors of about `1e-9` cells are ignored, so the cell center always maps to the cell.
+    /// Grid orderings other than `N-to-S, W-to-E` are respected.
+    ///
+    /// Returns [`None`] when the point is outside of the grid or `self` is sparse.
+    ///
+    /// ```
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// assert_eq!(isg.cell_index(41.1, 119.9), Some((0, 0)));
+    /// // on the border between (1, 1) and (2, 1)
+    /// assert_eq!(isg.cell_index(40.5 + 10.0 / 60.0, 120.0 + 10.0 / 60.0), Some((2, 1)));
+    /// assert_eq!(isg.cell_index(0.0, 0.0), None);
+    /// ```
+    pub fn cell_index(&self, a: f64, b: f64) -> Option<(usize, usize)> {
+        let grid = match &self.data {
+            Data::Grid(grid) => grid,
+            Data::Sparse(_) => return None,
+        };
+
+        let index = GridGeometry::new(&self.header)?.index(a, b)?;
+        flip_index(
+            index,
+            (grid.nrows(), grid.ncols()),
+            grid_flips_or_default(self.header.data_ordering),
+        )
+    }
+
+    /// Sets the value of the cell which contains the point (`a`, `b`), see [`ISG::value_at`].
+    ///
+    /// Returns `false` when the point is outside of the grid or `self` is sparse.
+    pub fn set_at(&mut self, a: f64, b: f64, value: f64) -> bool {
+        match self.cell_index(a, b) {
+            Some((row, col)) => self.data.set(row, col, value),
+            None => false,
+        }
+    }
+}
+
+impl ISG {
+    /// Returns an iterator of cells with coordinates, `(a, b, value)`.
+    ///
+    /// `a` is lat or north and `b` is lon or east, independently of `data ordering`.
+    /// For grid, cells are yielded in the order of data (row-major),
+    /// and coordinates are of cell centers computed by [`ISG::cell_coord`],
+    /// and `value` is [`None`] when nodata.
+    /// For sparse, records are yielded as is (swapped if `data ordering` is `east, north, N`).
+    ///
+    /// ```
+    /// # use libisg::Coord;
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// let (a, b, value) = isg.cells().next().unwrap();
+    /// assert_eq!(a, Coord::with_dms(41, 10, 0));
+    /// assert_eq!(b, Coord::with_dms(119, 50, 0));
+    /// assert_eq!(value, Some(30.1234));
+    /// ```
+    pub fn cells(&self) -> impl Iterator<Item = (Coord, Coord, Option<f64>)> + '_ {
+        let (grid, sparse) = match &self.data {
+            Data::Grid(data) => (self.cell_coords().map(|coord| (data, coord)), None),
+            Data::Sparse(data) => (None, Some(data)),
+        };
+        let swap = matches!(self.header.data_ordering, Some(DataOrdering::EastNorthN));
+
+        grid.into_iter()
+            .flat_map(move |(data, coord)| {
+                let ncols = data.ncols();
+                data.as_slice().iter().enumerate().map(move |(i, value)| {
+                    let (a, b) = coord(i / ncols, i % ncols);
+                    (a, b, *value)
+                })
+            })
+            .chain(sparse.into_iter().flat_map(move |data| {
+                data.iter().map(move |(a, b, value)| {
+                    if swap {
+                        (*b, *a, Some(*value))
+                    } else {
+                        (*a, *b, Some(*value))
+                    }
+                })
+            }))
+    }
+
+    /// Returns the coordinate `(a, b)` of the center of the cell of data at (`row`, `col`),
+    /// the inverse of [`ISG::cell_index`].
+    ///
+    /// `a` is lat or north and `b` is lon or east, in `coord units` (DMS stays DMS).
+    /// Grid orderings other than `N-to-S, W-to-E` are respected,
+    /// e.g. the row `0` is at `lat min` for `S-to-N` grids,
+    /// and longitude is wrapped for grids crossing the antimeridian.
+    ///
+    /// Returns [`None`] when (`row`, `col`) is out of bounds or `self` is sparse.
+    ///
+    /// ```
+    /// # use libisg::{Coord, DataOrdering};
+    /// # let mut isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// assert_eq!(
+    ///     isg.cell_coord(0, 1),
+    ///     Some((Coord::with_dms(41, 10, 0), Coord::with_dms(120, 10, 0)))
+    /// );
+    ///
+    /// assert!(isg.reorder(DataOrdering::S2NW2E));
+    /// assert_eq!(
+    ///     isg.cell_coord(0, 1),
+    ///     Some((Coord::with_dms(40, 10, 0), Coord::with_dms(120, 10, 0)))
+    /// );
+    /// assert_eq!(isg.cell_coord(4, 0), None);
+    /// ```
+    pub fn cell_coord(&self, row: usize, col: usize) -> Option<(Coord, Coord)> {
+        let coord = self.cell_coords()?;
+        match &self.data {
+            Data::Grid(grid) if row < grid.nrows() && col < grid.ncols() => Some(coord(row, col)),
+            _ => None,
+        }
+    }
+
+    /// Returns the map from index of data to the coordinate of the cell center,
+    /// [`None`] when `self` is sparse.
+    fn cell_coords(&self) -> Option<impl Fn(usize, usize) -> (Coord, Coord)> {
+        let (nrows, ncols) = match &self.data {
+            Data::Grid(grid) => (grid.nrows(), grid.ncols()),
+            Data::Sparse(_) => return None,
+        };
+
+        let (a_max, b_min, delta_a, delta_b, wrap) = match &self.header.data_bounds {
+            DataBounds::GridGeodetic {
+                lat_max,
+                lon_min,
+                lon_max,
+                delta_lat,
+                delta_lon,
+                ..
+            } => (
+                *lat_max,
+                *lon_min,
+                *delta_lat,
+                *delta_lon,
+                // lon is wrapped for grids crossing the antimeridian
+                Some(is_signed(lon_min, lon_max)),
+            ),
+            DataBounds::GridProjected {
+                north_max,
+                east_min,
+                delta_north,
+                delta_east,
+                ..
+            } => (*north_max, *east_min, *delta_north, *delta_east, None),
+            DataBounds::SparseGeodetic { .. } | DataBounds::SparseProjected { .. } => return None,
+        };
+        let (flip_rows, flip_cols) = grid_flips_or_default(self.header.data_ordering);
+
+        Some(move |row: usize, col: usize| {
+            let row = if flip_rows { nrows - 1 - row } else { row };
+            let col = if flip_cols { ncols - 1 - col } else { col };
+
+            let b = b_min + delta_b * col;
+            let b = match wrap {
+                Some(signed) => wrap_lon(b, signed),
+                None => b,
+            };
+            (a_max - delta_a * row, b)
+        })
+    }
+}
+
+impl Coord {
+    /// Returns value as `f64`, DMS is converted to decimal degrees.
+    #[inline]
+    pub(crate) fn as_f64(&self) -> f64 {
+        match self {
+            Self::DMS {
+                negative,
+                degree,
+                minutes,
+                second,
+            } => {
+                let value = *degree as f64 + *minutes as f64 / 60.0 + *second as f64 / 3600.0;
+                if *negative {
+                    -value
+                } else {
+                    value
+                }
+            }
+            Self::Dec(value) => *value,
+        }
+    }
+
+    /// Returns [`Coord::DMS`] of decimal degrees `value` by `rounding`,
+    /// [`None`] if it is not representable, e.g. non-finite.
+    pub(crate) fn dms_from_f64_with(value: f64, rounding: DmsRounding) -> Option<Self> {
+        /// Tolerance of exact second, see [`DmsRounding`]
+        const TOLERANCE: f64 = 1e-6;
+
+        let seconds = value.abs() * 3600.0;
+        let nearest = seconds.round();
+        let total = match rounding {
+            DmsRounding::Nearest => nearest,
+            _ if (seconds - nearest).abs() <= TOLERANCE => nearest,
+            DmsRounding::Truncate => seconds.trunc(),
+            DmsRounding::Exact => return None,
+        };
+
+        // also rejects NaN
+        (total < (u16::MAX as f64 + 1.0) * 3600.0).then(|| {
+            let total = total as u64;
+            Self::with_signed_dms(
+                value.is_sign_negative(),
+                (total / 3600) as u16,
+                (total % 3600 / 60) as u8,
+                (total % 60) as u8,
+            )
+        })
+    }
+
+    /// Returns [`Coord::DMS`] of decimal degrees `value`,
+    /// rounded to the nearest second.
+    pub(crate) fn dms_from_f64(value: f64) -> Self {
+        let total = (value.abs() * 3600.0).round() as u64;
+
+        Self::with_signed_dms(
+            value.is_sign_negative(),
+            (total / 3600) as u16,
+            (total % 3600 / 60) as u8,
+            (total % 60) as u8,
+        )
+    }
+}
+
+/// Returns index of data of `(row, col)` in the order of rows from `a max` and columns from `b min`,
+/// flipped by `(flip_rows, flip_cols)` of `data ordering`.
+///
+/// Returns [`None`] when out of `(nrows, ncols)`.
+#[inline]
+pub(crate) fn flip_index(
+    (row, col): (usize, usize),
+    (nrows, ncols): (usize, usize),
+    (flip_rows, flip_cols): (bool, bool),
+) -> Option<(usize, usize)> {
+    if row >= nrows || col >= ncols {
+        return None;
+    }
+
+    Some((
+        if flip_rows { nrows - 1 - row } else { row },
+        if flip_cols { ncols - 1 - col } else { col },
+    ))
+}
+
+/// Returns `true` when the longitudes are in the signed convention, `[-180, 180]`.
+#[inline]
+pub(crate) fn is_signed(lon_min: &Coord, lon_max: &Coord) -> bool {
+    lon_min.as_f64() <= 180.0 && lon_max.as_f64() <= 180.0
+}
+
+/// Wraps `lon` into `[-180, 180]` when `signed`, or into `[-180, 360]` otherwise.
+///
+/// The result of arithmetic on grids crossing the antimeridian
+/// (`lon max` is less than `lon min`) can be out of the range.
+#[inline]
+pub(crate) fn wrap_lon(lon: Coord, signed: bool) -> Coord {
+    let value = lon.as_f64();
+    if value > 360.0 || (signed && value > 180.0) {
+        shift_lon(&lon, -1)
+    } else if value < -180.0 {
+        shift_lon(&lon, 1)
+    } else {
+        lon
+    }
+}
+
+/// Shifts `lon` by `turns` × 360°.
+#[inline]
+pub(crate) fn shift_lon(lon: &Coord, turns: i32) -> Coord {
+    if turns == 0 {
+        return *lon;
+    }
+
+    let value = lon.as_f64() + 360.0 * turns as f64;
+    match lon {
+        Coord::DMS { .. } => Coord::dms_from_f64(value),
+        Coord::Dec(_) => Coord::Dec(value),
+    }
+}
+
+/// Returns the span from `lon_min` to `lon_max` eastward,
+/// which is wrapped when `lon_max` is less than `lon_min`.
+#[inline]
+pub(crate) fn lon_span(lon_min: f64, lon_max: f64) -> f64 {
+    if lon_min <= lon_max {
+        lon_max - lon_min
+    } else {
+        lon_max - lon_min + 360.0
+    }
+}
+
+/// Returns the squared distance from (`a`, `b`) to (`a1`, `b1`) in coordinate space.
+///
+/// For geodetic, this is approximated by the equirectangular projection at `a`
+/// and longitude is wrapped around the globe.
+#[inline]
+pub(crate) fn distance2(a: f64, b: f64, a1: f64, b1: f64, geodetic: bool) -> f64 {
+    let da = a1 - a;
+    let db = if geodetic {
+        ((b1 - b + 180.0).rem_euclid(360.0) - 180.0) * a.to_radians().cos()
+    } else {
+        b1 - b
+    };
+    da * da + db * db
+}
+
+/// Max distance of fractional index to be snapped to the integer,
+/// which absorbs rounding errors of coordinates
+pub(crate) const SNAP_EPSILON: f64 = 1e-9;
+
+/// Snaps fractional index `x` to the nearest integer within [`SNAP_EPSILON`].
+#[inline]
+pub(crate) fn snap(x: f64) -> f64 {
+    let rounded = x.round();
+    if (x - rounded).abs() < SNAP_EPSILON {
+        rounded
+    } else {
+        x
+    }
+}
+
+/// Tolerance of the distance from grid nodes, in units of cells
+pub(crate) const TOLERANCE: f64 = 1e-3;
+
+/// Meters per international foot
+pub(crate) const METERS_PER_FOOT: f64 = 0.3048;
+
+/// Geometry of grid data in decimal units.
+///
+/// `a` is lat or north, `b` is lon or east.
+/// Rows go from `a max` to `a min` and columns go from `b min` to `b max`.
+/// For geodetic, `b` is wrapped around the globe, so grids can cross the antimeridian.
+#[derive(Debug, Clone)]
+pub(crate) struct GridGeometry {
+    pub(crate) a_max: f64,
+    pub(crate) b_min: f64,
+    pub(crate) delta_a: f64,
+    pub(crate) delta_b: f64,
+    pub(crate) nrows: usize,
+    pub(crate) ncols: usize,
+    pub(crate) geodetic: bool,
+}
+
+impl GridGeometry {
+    /// Returns [`None`] when `header` is not grid.
+    pub(crate) fn new(header: &Header) -> Option<Self> {
+        let bounds = &header.data_bounds;
+        let (delta_a, delta_b) = (bounds.delta_a()?, bounds.delta_b()?);
+
+        Some(Self {
+            a_max: bounds.a_max().as_f64(),
+            b_min: bounds.b_min().as_f64(),
+            delta_a: delta_a.as_f64(),
+            delta_b: delta_b.as_f64(),
+            nrows: header.nrows,
+            ncols: header.ncols,
+            geodetic: matches!(bounds, DataBounds::GridGeodetic { .. }),
+        })
+    }
+
+    /// Returns fractional index `(row, col)` of the point.
+    #[inline]
+    pub(crate) fn frac_index(&self, a: f64, b: f64) -> (f64, f64) {
+        let mut db = b - self.b_min;
+        if self.geodetic {
+            // into [-delta / 2, 360 - delta / 2)
+            db -= 360.0 * ((db + 0.5 * self.delta_b) / 360.0).floor();
+        }
+
+        ((self.a_max - a) / self.delta_a, db / self.delta_b)
+    }
+
+    /// Returns index of the cell which contains the point,
+    /// in the order of rows from `a max` and columns from `b min`.
+    ///
+    /// A point on the border between two cells belongs to the south (east) one,
+    /// except the outermost borders, which belong to the outermost cells.
+    /// Borders are snapped within [`SNAP_EPSILON`].
+    pub(crate) fn index(&self, a: f64, b: f64) -> Option<(usize, usize)> {
+        #[inline]
+        fn round(x: f64, n: usize) -> Option<usize> {
+            let x = snap(x + 0.5);
+            if n != 0 && (0.0..=n as f64).contains(&x) {
+                Some((x.floor() as usize).min(n - 1))
+            } else {
+                None
+            }
+        }
+
+        let (row, col) = self.frac_index(a, b);
+        Some((round(row, self.nrows)?, round(col, self.ncols)?))
+    }
+}
@@ -0,0 +1,104 @@
+use crate::ordering::grid_flips_or_default;
+use crate::profile::EARTH_RADIUS;
+use crate::{CoordTrig, CoordType, Data, Grid, ISG};
+
+impl ISG {
+    /// Returns grids of the gradient, `(∂N/∂x, ∂N/∂y)`, toward east and north.
+    ///
+    /// Values are in `data units` per meter, where cell spacing is converted into meters:
+    /// a spherical earth (mean radius) is assumed for geodetic, e.g. `dms` deltas in arc-seconds,
+    /// and the spacing toward east shrinks by cos(lat) of each row.
+    /// For geoid undulations in meters, the deflections of the vertical are
+    /// `ξ = -∂N/∂y` and `η = -∂N/∂x` in radians.
+    ///
+    /// Central differences are used, falling back to one-sided differences at edges and next to nodata.
+    /// Cells without defined neighbors along an axis, and nodata cells, are nodata.
+    /// `data ordering` is respected.
+    /// Returns [`None`] when `self` is sparse.
+    ///
+    /// ```
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// let (east, north) = isg.gradient().unwrap();
+    ///
+    /// // values decrease toward north
+    /// assert!(north.data[(1, 1)].unwrap() < 0.0);
+    /// assert!(east.data[(1, 1)].unwrap() > 0.0);
+    /// ```
+    pub fn gradient(&self) -> Option<(ISG, ISG)> {
+        let grid = match &self.data {
+            Data::Grid(grid) => grid,
+            Data::Sparse(_) => return None,
+        };
+        let (delta_a, delta_b) = self.header.cell_size()?;
+        let (flip_rows, flip_cols) = grid_flips_or_default(self.header.data_ordering);
+
+        // meters toward north per row and toward east per column
+        let geodetic = matches!(self.header.coord_type, CoordType::Geodetic);
+        let (step_a, step_b) = if geodetic {
+            (
+                delta_a.to_radians() * EARTH_RADIUS,
+                delta_b.to_radians() * EARTH_RADIUS,
+            )
+        } else {
+            (delta_a, delta_b)
+        };
+        let step_row = if flip_rows { step_a } else { -step_a };
+        let step_col = if flip_cols { -step_b } else { step_b };
+
+        let (nrows, ncols) = (grid.nrows(), grid.ncols());
+        let mut east = Vec::with_capacity(nrows * ncols);
+        let mut north = Vec::with_capacity(nrows * ncols);
+        for row in 0..nrows {
+            let step_col = if geodetic {
+                // never fails, in bounds of grid
+                let (lat, _) = self.cell_coord(row, 0).unwrap();
+                step_col * lat.cos()
+            } else {
+                step_col
+            };
+
+            for col in 0..ncols {
+                let at = |i: Option<usize>, j: Option<usize>| match (i, j) {
+                    (Some(i), Some(j)) => grid.get(i, j).copied().flatten(),
+                    _ => None,
+                };
+
+                east.push(derivative(
+                    at(Some(row), col.checked_sub(1)),
+                    grid[(row, col)],
+                    at(Some(row), Some(col + 1)),
+                    step_col,
+                ));
+                north.push(derivative(
+                    at(row.checked_sub(1), Some(col)),
+                    grid[(row, col)],
+                    at(Some(row + 1), Some(col)),
+                    step_row,
+                ));
+            }
+        }
+
+        let to_isg = |values| ISG {
+            comment: self.comment.clone(),
+            header: self.header.clone(),
+            data: Data::Grid(Grid::from_vec_unchecked(nrows, ncols, values)),
+        };
+        Some((to_isg(east), to_isg(north)))
+    }
+}
+
+/// Returns the derivative at `current` from the adjacent values of the spacing `step`.
+#[inline]
+fn derivative(
+    prev: Option<f64>,
+    current: Option<f64>,
+    next: Option<f64>,
+    step: f64,
+) -> Option<f64> {
+    match (prev, current?, next) {
+        (Some(prev), _, Some(next)) => Some((next - prev) / (2.0 * step)),
+        (None, current, Some(next)) => Some((next - current) / step),
+        (Some(prev), current, None) => Some((current - prev) / step),
+        (None, _, None) => None,
+    }
+}
@@ -0,0 +1,388 @@
+use std::convert::TryFrom;
+use std::ops::{Index, IndexMut};
+use std::sync::Arc;
+
+use crate::error::ValidationError;
+
+/// Grid data, a rectangular array of values in row-major order.
+///
+/// Values are stored in a single contiguous [`Vec`],
+/// [`None`] represents `nodata`.
+/// Values are shared by clones (copy-on-write),
+/// so cloning a grid (and [`ISG`](crate::ISG)) is cheap
+/// and values are copied only when a clone is modified.
+/// The value type is `f64` by default,
+/// `Grid<f32>` is available through [`Grid::to_f32`] to reduce memory usage.
+///
+/// ```
+/// use std::convert::TryFrom;
+///
+/// use libisg::Grid;
+///
+/// let grid = Grid::try_from(vec![
+///     vec![Some(1.0), None],
+///     vec![Some(3.0), Some(4.0)],
+/// ]).unwrap();
+///
+/// assert_eq!(grid.nrows(), 2);
+/// assert_eq!(grid.ncols(), 2);
+/// assert_eq!(grid.get(0, 0), Some(&Some(1.0)));
+/// assert_eq!(grid.get(0, 1), Some(&None));
+/// assert_eq!(grid.get(2, 0), None);
+/// assert_eq!(grid.row(1), Some([Some(3.0), Some(4.0)].as_slice()));
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct Grid<T = f64> {
+    values: Arc<Vec<Option<T>>>,
+    nrows: usize,
+    ncols: usize,
+}
+
+impl<T: Copy> Grid<T> {
+    /// Makes a grid from values in row-major order, which has `nrows * ncols` length.
+    #[inline]
+    pub(crate) fn from_vec_unchecked(nrows: usize, ncols: usize, values: Vec<Option<T>>) -> Self {
+        debug_assert_eq!(values.len(), nrows * ncols);
+        Self {
+            values: Arc::new(values),
+            nrows,
+            ncols,
+        }
+    }
+
+    /// Returns values as mutable, which are copied if shared with clones.
+    #[inline]
+    fn values_mut(&mut self) -> &mut Vec<Option<T>> {
+        Arc::make_mut(&mut self.values)
+    }
+
+    /// Returns the number of rows.
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    /// Returns the number of columns.
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    #[inline]
+    fn offset(&self, row: usize, col: usize) -> Option<usize> {
+        if row < self.nrows && col < self.ncols {
+            Some(row * self.ncols + col)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the value at (`row`, `col`),
+    /// [`None`] when out of bounds.
+    #[inline]
+    pub fn get(&self, row: usize, col: usize) -> Option<&Option<T>> {
+        self.offset(row, col).map(|i| &self.values[i])
+    }
+
+    /// Returns a mutable reference to the value at (`row`, `col`),
+    /// [`None`] when out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut Option<T>> {
+        match self.offset(row, col) {
+            Some(i) => Some(&mut self.values_mut()[i]),
+            None => None,
+        }
+    }
+
+    /// Returns a reference to the value at (`row`, `col`) without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Calling this with out-of-bounds index is undefined behavior.
+    #[inline]
+    pub unsafe fn get_unchecked(&self, row: usize, col: usize) -> &Option<T> {
+        self.values.get_unchecked(row * self.ncols + col)
+    }
+
+    /// Returns a mutable reference to the value at (`row`, `col`) without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Calling this with out-of-bounds index is undefined behavior.
+    #[inline]
+    pub unsafe fn get_unchecked_mut(&mut self, row: usize, col: usize) -> &mut Option<T> {
+        let ncols = self.ncols;
+        self.values_mut().get_unchecked_mut(row * ncols + col)
+    }
+
+    /// Returns the `i`-th row, [`None`] when out of bounds.
+    #[inline]
+    pub fn row(&self, i: usize) -> Option<&[Option<T>]> {
+        if i < self.nrows {
+            Some(&self.values[i * self.ncols..(i + 1) * self.ncols])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the `i`-th row as mutable, [`None`] when out of bounds.
+    #[inline]
+    pub fn row_mut(&mut self, i: usize) -> Option<&mut [Option<T>]> {
+        if i < self.nrows {
+            let ncols = self.ncols;
+            Some(&mut self.values_mut()[i * ncols..(i + 1) * ncols])
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator of rows.
+    ///
+    /// Notes, this yields nothing when the grid has no columns.
+    #[inline]
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = &[Option<T>]> + DoubleEndedIterator {
+        self.values.chunks(self.ncols.max(1))
+    }
+
+    /// Returns an iterator of mutable rows.
+    ///
+    /// Notes, this yields nothing when the grid has no columns.
+    #[inline]
+    pub fn rows_mut(
+        &mut self,
+    ) -> impl ExactSizeIterator<Item = &mut [Option<T>]> + DoubleEndedIterator {
+        let ncols = self.ncols.max(1);
+        self.values_mut().chunks_mut(ncols)
+    }
+
+    /// Returns all values in row-major order.
+    #[inline]
+    pub fn as_slice(&self) -> &[Option<T>] {
+        &self.values
+    }
+
+    /// Returns all values in row-major order as mutable.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [Option<T>] {
+        self.values_mut()
+    }
+
+    /// Converts into values in row-major order,
+    /// without copying unless values are shared with clones.
+    #[inline]
+    pub fn into_vec(self) -> Vec<Option<T>> {
+        Arc::try_unwrap(self.values).unwrap_or_else(|values| (*values).clone())
+    }
+
+    /// Reverses the order of rows.
+    pub(crate) fn flip_rows(&mut self) {
+        let (nrows, ncols) = (self.nrows, self.ncols);
+        for i in 0..nrows / 2 {
+            let (upper, lower) = self.values_mut().split_at_mut((nrows - i - 1) * ncols);
+            upper[i * ncols..(i + 1) * ncols].swap_with_slice(&mut lower[..ncols]);
+        }
+    }
+
+    /// Reverses the order of columns.
+    pub(crate) fn flip_cols(&mut self) {
+        for row in self.rows_mut() {
+            row.reverse();
+        }
+    }
+
+    /// Converts into nested [`Vec`]s of rows.
+    #[inline]
+    pub fn into_rows(self) -> Vec<Vec<Option<T>>> {
+        (0..self.nrows)
+            .map(|i| self.values[i * self.ncols..(i + 1) * self.ncols].to_vec())
+            .collect()
+    }
+}
+
+impl Grid {
+    /// Makes a grid of `nrows` × `ncols` filled by `value`.
+    pub fn filled(nrows: usize, ncols: usize, value: Option<f64>) -> Self {
+        Self {
+            values: Arc::new(vec![value; nrows * ncols]),
+            nrows,
+            ncols,
+        }
+    }
+
+    /// Makes a grid from values in row-major order.
+    ///
+    /// Returns [`None`] when `values.len()` is not `nrows * ncols`.
+    ///
+    /// ```
+    /// # use libisg::Grid;
+    /// let grid = Grid::from_vec(2, 3, vec![Some(1.0); 6]).unwrap();
+    /// assert_eq!(grid.row(1), Some([Some(1.0); 3].as_slice()));
+    ///
+    /// assert!(Grid::from_vec(2, 3, vec![Some(1.0); 5]).is_none());
+    /// ```
+    pub fn from_vec(nrows: usize, ncols: usize, values: Vec<Option<f64>>) -> Option<Self> {
+        if nrows.checked_mul(ncols) == Some(values.len()) {
+            Some(Self {
+                values: Arc::new(values),
+                nrows,
+                ncols,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Converts into values in row-major order, where `nodata` is [`f64::NAN`].
+    ///
+    /// ```
+    /// # use libisg::Grid;
+    /// let grid = Grid::from_vec(1, 2, vec![Some(1.0), None]).unwrap();
+    /// let values = grid.to_nan_grid();
+    /// assert_eq!(values[0], 1.0);
+    /// assert!(values[1].is_nan());
+    ///
+    /// assert_eq!(Grid::from_nan_grid(1, 2, values), Some(grid));
+    /// ```
+    pub fn to_nan_grid(&self) -> Vec<f64> {
+        self.values.iter().map(|v| v.unwrap_or(f64::NAN)).collect()
+    }
+
+    /// Makes a grid from values in row-major order, where NaN is `nodata`.
+    ///
+    /// Returns [`None`] when `values.len()` is not `nrows * ncols`.
+    pub fn from_nan_grid(nrows: usize, ncols: usize, values: Vec<f64>) -> Option<Self> {
+        let values = values
+            .into_iter()
+            .map(|v| if v.is_nan() { None } else { Some(v) })
+            .collect();
+        Self::from_vec(nrows, ncols, values)
+    }
+
+    /// Converts values into `f32`, which halves memory usage.
+    ///
+    /// Values are rounded to the nearest `f32`.
+    ///
+    /// ```
+    /// # use libisg::Grid;
+    /// let grid = Grid::from_vec(1, 2, vec![Some(30.1234), None]).unwrap();
+    /// let grid32 = grid.to_f32();
+    /// assert_eq!(grid32.get(0, 0), Some(&Some(30.1234_f32)));
+    /// assert_eq!(grid32.get(0, 1), Some(&None));
+    /// ```
+    pub fn to_f32(&self) -> Grid<f32> {
+        Grid {
+            values: Arc::new(self.values.iter().map(|v| v.map(|v| v as f32)).collect()),
+            nrows: self.nrows,
+            ncols: self.ncols,
+        }
+    }
+}
+
+impl Grid<f32> {
+    /// Converts values into `f64`, this is lossless.
+    pub fn to_f64(&self) -> Grid {
+        Grid {
+            values: Arc::new(self.values.iter().map(|v| v.map(f64::from)).collect()),
+            nrows: self.nrows,
+            ncols: self.ncols,
+        }
+    }
+}
+
+impl From<Grid<f32>> for Grid {
+    #[inline]
+    fn from(grid: Grid<f32>) -> Self {
+        grid.to_f64()
+    }
+}
+
+impl<T: Copy> Index<(usize, usize)> for Grid<T> {
+    type Output = Option<T>;
+
+    /// Returns the value at `(row, col)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics when out of bounds, see [`Grid::get`] for checked access.
+    #[inline]
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        match self.get(row, col) {
+            Some(value) => value,
+            None => out_of_bounds(row, col, self.nrows, self.ncols),
+        }
+    }
+}
+
+impl<T: Copy> IndexMut<(usize, usize)> for Grid<T> {
+    /// Returns the value at `(row, col)` as mutable.
+    ///
+    /// # Panics
+    ///
+    /// Panics when out of bounds, see [`Grid::get_mut`] for checked access.
+    #[inline]
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        let (nrows, ncols) = (self.nrows, self.ncols);
+        match self.get_mut(row, col) {
+            Some(value) => value,
+            None => out_of_bounds(row, col, nrows, ncols),
+        }
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn out_of_bounds(row: usize, col: usize, nrows: usize, ncols: usize) -> ! {
+    panic!(
+        "index out of bounds: the shape is ({}, {}) but the index is ({}, {})",
+        nrows, ncols, row, col
+    )
+}
+
+impl<T: Clone> Clone for Grid<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            values: self.values.clone(),
+            nrows: self.nrows,
+            ncols: self.ncols,
+        }
+    }
+
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        self.values.clone_from(&source.values);
+        self.nrows = source.nrows;
+        self.ncols = source.ncols;
+    }
+}
+
+impl<T> TryFrom<Vec<Vec<Option<T>>>> for Grid<T> {
+    type Error = ValidationError;
+
+    /// Makes a grid from rows, fails when rows have different lengths.
+    fn try_from(rows: Vec<Vec<Option<T>>>) -> Result<Self, Self::Error> {
+        let nrows = rows.len();
+        let ncols = rows.first().map_or(0, Vec::len);
+
+        let mut values = Vec::with_capacity(nrows * ncols);
+        for row in rows {
+            if row.len() != ncols {
+                return Err(ValidationError::ncols(ncols, Some(row.len())));
+            }
+            values.extend(row);
+        }
+
+        Ok(Self {
+            values: Arc::new(values),
+            nrows,
+            ncols,
+        })
+    }
+}
+
+impl<T: Copy> From<Grid<T>> for Vec<Vec<Option<T>>> {
+    #[inline]
+    fn from(grid: Grid<T>) -> Self {
+        grid.into_rows()
+    }
+}
@@ -0,0 +1,207 @@
+use crate::{Coord, Data, DataBounds, Header, ISG};
+
+/// Offset basis of 64-bit FNV-1a
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+/// Prime of 64-bit FNV-1a
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+impl ISG {
+    /// Returns a stable digest of the model, 64-bit FNV-1a over canonicalized header and data.
+    ///
+    /// The digest depends only on parsed values,
+    /// so it is independent of whitespace, number formatting and the `nodata` sentinel of the file,
+    /// and it is stable across platforms.
+    /// The comment is excluded.
+    /// It is not a cryptographic hash.
+    ///
+    /// ```
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// let minify = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.minify.isg").unwrap()).unwrap();
+    /// assert_eq!(isg.content_hash(), minify.content_hash());
+    ///
+    /// let other = isg.map_values(|v| v + 1e-4);
+    /// assert_ne!(isg.content_hash(), other.content_hash());
+    /// ```
+    pub fn content_hash(&self) -> u64 {
+        let mut digest = Digest::new();
+        digest.header(&self.header);
+        digest.data(&self.data);
+        digest.finish()
+    }
+}
+
+/// Writer of canonical encoding into 64-bit FNV-1a
+struct Digest(u64);
+
+impl Digest {
+    #[inline]
+    fn new() -> Self {
+        Self(FNV_OFFSET)
+    }
+
+    #[inline]
+    fn finish(self) -> u64 {
+        self.0
+    }
+
+    fn bytes(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    #[inline]
+    fn u64(&mut self, value: u64) {
+        self.bytes(&value.to_le_bytes())
+    }
+
+    /// Writes `0.0` for `-0.0` and the same bits for all NaNs
+    #[inline]
+    fn f64(&mut self, value: f64) {
+        let value = if value == 0.0 {
+            0.0
+        } else if value.is_nan() {
+            f64::NAN
+        } else {
+            value
+        };
+        self.u64(value.to_bits())
+    }
+
+    /// Writes the length prefix, so adjacent strings are not ambiguous
+    #[inline]
+    fn str(&mut self, value: &str) {
+        self.u64(value.len() as u64);
+        self.bytes(value.as_bytes());
+    }
+
+    #[inline]
+    fn option<T>(&mut self, value: Option<T>, f: impl FnOnce(&mut Self, T)) {
+        match value {
+            None => self.bytes(&[0]),
+            Some(value) => {
+                self.bytes(&[1]);
+                f(self, value);
+            }
+        }
+    }
+
+    fn coord(&mut self, coord: &Coord) {
+        match coord {
+            Coord::DMS {
+                negative,
+                degree,
+                minutes,
+                second,
+            } => {
+                self.bytes(&[if *negative { 2 } else { 0 }]);
+                self.bytes(&degree.to_le_bytes());
+                self.bytes(&[*minutes, *second]);
+            }
+            Coord::Dec(value) => {
+                self.bytes(&[1]);
+                self.f64(*value);
+            }
+        }
+    }
+
+    fn header(&mut self, header: &Header) {
+        // enums are written by their tokens of the ISG format
+        self.option(header.model_name.as_deref(), Self::str);
+        self.option(header.model_year.as_deref(), Self::str);
+        self.option(header.model_type.map(|v| v.to_string()), |d, v| d.str(&v));
+        self.option(header.data_type.map(|v| v.to_string()), |d, v| d.str(&v));
+        self.option(header.data_units.map(|v| v.to_string()), |d, v| d.str(&v));
+        self.str(&header.data_format.to_string());
+        self.option(header.data_ordering.map(|v| v.to_string()), |d, v| {
+            d.str(&v)
+        });
+        self.option(header.ref_ellipsoid.as_deref(), Self::str);
+        self.option(header.ref_frame.as_deref(), Self::str);
+        self.option(header.height_datum.as_deref(), Self::str);
+        self.option(header.tide_system.map(|v| v.to_string()), |d, v| d.str(&v));
+        self.str(&header.coord_type.to_string());
+        self.str(&header.coord_units.to_string());
+        self.option(header.map_projection.as_deref(), Self::str);
+        self.option(header.EPSG_code.as_deref(), Self::str);
+        self.data_bounds(&header.data_bounds);
+        self.u64(header.nrows as u64);
+        self.u64(header.ncols as u64);
+        self.option(header.creation_date.map(|v| v.to_string()), |d, v| {
+            d.str(&v)
+        });
+        self.str(&header.ISG_format);
+    }
+
+    fn data_bounds(&mut self, data_bounds: &DataBounds) {
+        let (tag, coords) = match data_bounds {
+            DataBounds::GridGeodetic {
+                lat_min,
+                lat_max,
+                lon_min,
+                lon_max,
+                delta_lat,
+                delta_lon,
+            } => (
+                0,
+                vec![lat_min, lat_max, lon_min, lon_max, delta_lat, delta_lon],
+            ),
+            DataBounds::GridProjected {
+                north_min,
+                north_max,
+                east_min,
+                east_max,
+                delta_north,
+                delta_east,
+            } => (
+                1,
+                vec![
+                    north_min,
+                    north_max,
+                    east_min,
+                    east_max,
+                    delta_north,
+                    delta_east,
+                ],
+            ),
+            DataBounds::SparseGeodetic {
+                lat_min,
+                lat_max,
+                lon_min,
+                lon_max,
+            } => (2, vec![lat_min, lat_max, lon_min, lon_max]),
+            DataBounds::SparseProjected {
+                north_min,
+                north_max,
+                east_min,
+                east_max,
+            } => (3, vec![north_min, north_max, east_min, east_max]),
+        };
+
+        self.bytes(&[tag]);
+        coords.into_iter().for_each(|coord| self.coord(coord));
+    }
+
+    fn data(&mut self, data: &Data) {
+        match data {
+            Data::Grid(grid) => {
+                self.bytes(&[0]);
+                self.u64(grid.nrows() as u64);
+                self.u64(grid.ncols() as u64);
+                for value in grid.as_slice() {
+                    self.option(*value, Self::f64);
+                }
+            }
+            Data::Sparse(data) => {
+                self.bytes(&[1]);
+                self.u64(data.len() as u64);
+                for (a, b, value) in data {
+                    self.coord(a);
+                    self.coord(b);
+                    self.f64(*value);
+                }
+            }
+        }
+    }
+}
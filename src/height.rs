@@ -0,0 +1,49 @@
+use crate::geometry::METERS_PER_FOOT;
+use crate::{DataUnits, ISG};
+
+impl ISG {
+    /// Returns the height above the model surface, `h - N`, of the point (`lat`, `lon`),
+    /// where `h_ell` is the ellipsoidal height in meters.
+    ///
+    /// The result is the orthometric height when `data type` is `geoid` (or missing),
+    /// and the normal height when `quasi-geoid`, in meters.
+    /// `N` is bilinearly interpolated, see [`ISG::interpolate`],
+    /// and converted from `data units` into meters.
+    ///
+    /// Returns [`None`] when `N` is not available at the point.
+    ///
+    /// ```
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// // N is 30.1234 m
+    /// let h = isg.to_orthometric(41.0 + 10.0 / 60.0, 119.0 + 50.0 / 60.0, 100.0).unwrap();
+    /// assert!((h - 69.8766).abs() < 1e-9);
+    /// ```
+    pub fn to_orthometric(&self, lat: f64, lon: f64, h_ell: f64) -> Option<f64> {
+        self.undulation(lat, lon).map(|n| h_ell - n)
+    }
+
+    /// Returns the ellipsoidal height, `H + N`, of the point (`lat`, `lon`),
+    /// where `height` is the height above the model surface in meters,
+    /// the inverse of [`ISG::to_orthometric`].
+    ///
+    /// Returns [`None`] when `N` is not available at the point.
+    ///
+    /// ```
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// let h = isg.to_ellipsoidal(41.0 + 10.0 / 60.0, 119.0 + 50.0 / 60.0, 69.8766).unwrap();
+    /// assert!((h - 100.0).abs() < 1e-9);
+    /// ```
+    pub fn to_ellipsoidal(&self, lat: f64, lon: f64, height: f64) -> Option<f64> {
+        self.undulation(lat, lon).map(|n| height + n)
+    }
+
+    /// Returns the value at the point in meters.
+    #[inline]
+    fn undulation(&self, lat: f64, lon: f64) -> Option<f64> {
+        let value = self.interpolate(lat, lon)?;
+        Some(match self.header.data_units {
+            Some(DataUnits::Feet) => value * METERS_PER_FOOT,
+            Some(DataUnits::Meters) | None => value,
+        })
+    }
+}
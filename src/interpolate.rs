@@ -0,0 +1,478 @@
+use crate::geometry::{distance2, snap, GridGeometry};
+use crate::ordering::grid_flips_or_default;
+use crate::{Coord, CoordType, Data, GridView, ISG};
+
+/// Number of points per parallel task of [`ISG::interpolate_many`]
+#[cfg(feature = "rayon")]
+const PAR_CHUNK_SIZE: usize = 4096;
+
+/// Interpolation kernel, which samples a grid at a fractional index.
+///
+/// This allows custom kernels with [`ISG::interpolate_with`],
+/// where the index is computed from the point and the ordering of the grid by [`libisg`](crate).
+/// The fractional index is `(row, col)` on `grid`, that is,
+/// the cell at (`i`, `j`) is at `(i as f64, j as f64)`,
+/// and it is not necessarily in bounds.
+///
+/// [`Method`] is an implementation of this.
+///
+/// ```
+/// use libisg::{GridView, Interpolator};
+///
+/// /// Mean of the defined cells among the four surrounding cells
+/// struct Mean;
+///
+/// impl Interpolator for Mean {
+///     fn sample(&self, grid: &GridView, frac_row: f64, frac_col: f64) -> Option<f64> {
+///         if !(frac_row >= 0.0 && frac_col >= 0.0) {
+///             return None;
+///         }
+///
+///         let (row, col) = (frac_row as usize, frac_col as usize);
+///         let values: Vec<f64> = [(0, 0), (0, 1), (1, 0), (1, 1)]
+///             .iter()
+///             .filter_map(|(i, j)| *grid.get(row + i, col + j)?)
+///             .collect();
+///
+///         if values.is_empty() {
+///             None
+///         } else {
+///             Some(values.iter().sum::<f64>() / values.len() as f64)
+///         }
+///     }
+/// }
+///
+/// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+/// // two of the four are nodata
+/// assert_eq!(isg.interpolate(40.6, 121.4), None);
+/// assert!(isg.interpolate_with(40.6, 121.4, Mean).is_some());
+/// ```
+pub trait Interpolator {
+    /// Returns the value at the fractional index (`frac_row`, `frac_col`) of `grid`,
+    /// [`None`] when it is not available, e.g. out of bounds or nodata.
+    fn sample(&self, grid: &GridView, frac_row: f64, frac_col: f64) -> Option<f64>;
+}
+
+impl<T: Interpolator + ?Sized> Interpolator for &T {
+    #[inline]
+    fn sample(&self, grid: &GridView, frac_row: f64, frac_col: f64) -> Option<f64> {
+        (**self).sample(grid, frac_row, frac_col)
+    }
+}
+
+/// Interpolation method
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Method {
+    /// Value of the nearest cell
+    Nearest,
+    /// Bilinear interpolation of the four surrounding cells
+    Bilinear,
+    /// Bicubic (cubic convolution) interpolation of the sixteen surrounding cells,
+    /// which falls back to [`Method::Bilinear`] near edges or nodata
+    Bicubic,
+}
+
+/// Result of [`ISG::sample`], which distinguishes points outside of the model from masked ones
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Sample {
+    /// Value at the point
+    Value(f64),
+    /// The point is outside of the grid, or the model is sparse
+    OutOfBounds,
+    /// The point is inside of the grid, but a cell which contributes to the value is nodata
+    Nodata,
+}
+
+impl Sample {
+    /// Returns the value, [`None`] unless [`Sample::Value`].
+    #[inline]
+    pub fn value(&self) -> Option<f64> {
+        match self {
+            Self::Value(value) => Some(*value),
+            Self::OutOfBounds | Self::Nodata => None,
+        }
+    }
+}
+
+impl From<Sample> for Option<f64> {
+    #[inline]
+    fn from(sample: Sample) -> Self {
+        sample.value()
+    }
+}
+
+/// Extrapolation policy of [`ISG::interpolate_with_extrapolation`],
+/// which applies to points within half a cell outside of the outermost cells
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Extrapolation {
+    /// No extrapolation, the value is [`None`]
+    None,
+    /// Value at the nearest point on the edge of the grid
+    Clamp,
+    /// Linear extrapolation from the edge of the grid and the next inner cell
+    Linear,
+}
+
+impl Default for Extrapolation {
+    #[inline]
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+impl ISG {
+    /// Returns the value at the point (`a`, `b`) by bilinear interpolation of the four surrounding cells.
+    ///
+    /// The point is `(lat, lon)` in decimal degrees (even if `coord units` is `dms`)
+    /// or `(north, east)`, see [`ISG::join_points`].
+    /// Values are taken at cell centers, and grid orderings other than `N-to-S, W-to-E`
+    /// are respected.
+    ///
+    /// Returns [`None`] when the point is outside of the grid,
+    /// any of the surrounding cells is nodata, or `self` is sparse.
+    ///
+    /// ```
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// // the midpoint of the upper-left two cells
+    /// let value = isg.interpolate(41.0 + 10.0 / 60.0, 120.0).unwrap();
+    /// assert!((value - (30.1234 + 31.2222) / 2.0).abs() < 1e-9);
+    ///
+    /// assert_eq!(isg.interpolate(0.0, 0.0), None);
+    /// ```
+    pub fn interpolate(&self, a: f64, b: f64) -> Option<f64> {
+        self.interpolate_with(a, b, Method::Bilinear)
+    }
+
+    /// Returns the value at the point (`a`, `b`) by `interpolator`,
+    /// see [`ISG::interpolate`] and [`Interpolator`].
+    ///
+    /// ```
+    /// # use libisg::Method;
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// let bicubic = isg.interpolate_with(40.75, 120.25, Method::Bicubic).unwrap();
+    /// let bilinear = isg.interpolate_with(40.75, 120.25, Method::Bilinear).unwrap();
+    /// assert!((bicubic - bilinear).abs() < 1.0);
+    /// ```
+    pub fn interpolate_with<I: Interpolator>(
+        &self,
+        a: f64,
+        b: f64,
+        interpolator: I,
+    ) -> Option<f64> {
+        let (grid, row, col) = self.frac_index(a, b)?;
+        interpolator.sample(&grid, row, col)
+    }
+
+    /// Returns the value at the point (`a`, `b`) by bilinear interpolation,
+    /// where the cause of a missing value is reported, see [`ISG::interpolate`].
+    ///
+    /// ```
+    /// # use libisg::Sample;
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// assert_eq!(isg.sample(41.0 + 10.0 / 60.0, 119.0 + 50.0 / 60.0), Sample::Value(30.1234));
+    /// // surrounded by nodata
+    /// assert_eq!(isg.sample(40.5, 121.5), Sample::Nodata);
+    /// assert_eq!(isg.sample(0.0, 0.0), Sample::OutOfBounds);
+    /// ```
+    pub fn sample(&self, a: f64, b: f64) -> Sample {
+        self.sample_with(a, b, Method::Bilinear)
+    }
+
+    /// Returns the value at the point (`a`, `b`) by `interpolator`,
+    /// where the cause of a missing value is reported, see [`ISG::interpolate_with`].
+    ///
+    /// The bounds are the same as [`ISG::cell_index`], that is, half a cell outside of the outermost cells,
+    /// and points between the outermost cells and the bounds are clamped as [`Extrapolation::Clamp`].
+    /// The result is [`Sample::OutOfBounds`] for points outside of the bounds,
+    /// and [`Sample::Nodata`] when `interpolator` returns [`None`].
+    pub fn sample_with<I: Interpolator>(&self, a: f64, b: f64, interpolator: I) -> Sample {
+        let (grid, row, col) = match self.frac_index(a, b) {
+            Some(index) => index,
+            None => return Sample::OutOfBounds,
+        };
+
+        let (last_row, last_col) = (grid.nrows() as f64 - 1.0, grid.ncols() as f64 - 1.0);
+        let (row, col) = match (clamp_half(row, last_row), clamp_half(col, last_col)) {
+            (Some(row), Some(col)) => (row, col),
+            _ => return Sample::OutOfBounds,
+        };
+
+        match interpolator.sample(&grid, row, col) {
+            Some(value) => Sample::Value(value),
+            None => Sample::Nodata,
+        }
+    }
+
+    /// Returns the value at the point (`a`, `b`) by `interpolator`,
+    /// where points within half a cell outside of the outermost cells are extrapolated by `extrapolation`,
+    /// see [`ISG::interpolate_with`].
+    ///
+    /// Points further outside are [`None`] regardless of `extrapolation`.
+    /// [`Extrapolation::Linear`] returns [`None`] when the next inner cell is not available.
+    ///
+    /// ```
+    /// # use libisg::{Extrapolation, Method};
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// // a quarter cell north of the upper-left cell
+    /// let (a, b) = (41.0 + 15.0 / 60.0, 119.0 + 50.0 / 60.0);
+    ///
+    /// assert_eq!(isg.interpolate_with_extrapolation(a, b, Method::Bilinear, Extrapolation::None), None);
+    /// assert_eq!(
+    ///     isg.interpolate_with_extrapolation(a, b, Method::Bilinear, Extrapolation::Clamp),
+    ///     Some(30.1234)
+    /// );
+    /// assert!(isg.interpolate_with_extrapolation(a, b, Method::Bilinear, Extrapolation::Linear).is_some());
+    /// ```
+    pub fn interpolate_with_extrapolation<I: Interpolator>(
+        &self,
+        a: f64,
+        b: f64,
+        interpolator: I,
+        extrapolation: Extrapolation,
+    ) -> Option<f64> {
+        let (grid, row, col) = self.frac_index(a, b)?;
+        let (last_row, last_col) = (grid.nrows() as f64 - 1.0, grid.ncols() as f64 - 1.0);
+
+        let inside = (0.0..=last_row).contains(&row) && (0.0..=last_col).contains(&col);
+        if inside || extrapolation == Extrapolation::None {
+            return interpolator.sample(&grid, row, col);
+        }
+
+        let (row0, col0) = (clamp_half(row, last_row)?, clamp_half(col, last_col)?);
+        let value = interpolator.sample(&grid, row0, col0)?;
+        if extrapolation == Extrapolation::Clamp {
+            return Some(value);
+        }
+
+        let (dr, dc) = (row - row0, col - col0);
+        let mut extrapolated = value;
+        // a single row (column) has no slope
+        if dr != 0.0 && last_row > 0.0 {
+            let inner = interpolator.sample(&grid, row0 - dr.signum(), col0)?;
+            extrapolated += dr.abs() * (value - inner);
+        }
+        if dc != 0.0 && last_col > 0.0 {
+            let inner = interpolator.sample(&grid, row0, col0 - dc.signum())?;
+            extrapolated += dc.abs() * (value - inner);
+        }
+
+        Some(extrapolated)
+    }
+
+    /// Returns the values at the `points` by bilinear interpolation, see [`ISG::interpolate`].
+    ///
+    /// This is equivalent to calling [`ISG::interpolate`] on each point,
+    /// but the geometry of the grid is resolved once.
+    /// Under the `rayon` feature, the points are processed in parallel chunks.
+    /// Returns all [`None`] when `self` is sparse.
+    ///
+    /// ```
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// let values = isg.interpolate_many(&[(41.0 + 10.0 / 60.0, 119.0 + 50.0 / 60.0), (0.0, 0.0)]);
+    /// assert_eq!(values, vec![Some(30.1234), None]);
+    /// ```
+    pub fn interpolate_many(&self, points: &[(f64, f64)]) -> Vec<Option<f64>> {
+        let (grid, locator) = match self.locator() {
+            Some(locator) => locator,
+            None => return vec![None; points.len()],
+        };
+
+        let interpolate = |&(a, b): &(f64, f64)| {
+            let (row, col) = locator.frac_index(a, b);
+            Method::Bilinear.sample(&grid, row, col)
+        };
+
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+
+            points
+                .par_chunks(PAR_CHUNK_SIZE)
+                .flat_map_iter(|chunk| chunk.iter().map(interpolate))
+                .collect()
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            points.iter().map(interpolate).collect()
+        }
+    }
+
+    /// Returns the closest defined cell (or sparse record) to the point (`a`, `b`),
+    /// `(a, b, value)`, see [`ISG::cells`].
+    ///
+    /// The point is `(lat, lon)` in decimal degrees or `(north, east)`, see [`ISG::join_points`].
+    /// For geodetic, distance is approximated by the equirectangular projection
+    /// and longitude is wrapped around the globe.
+    /// The point may be outside of the grid, and this scans all cells.
+    ///
+    /// Returns [`None`] when there is no defined cell.
+    ///
+    /// ```
+    /// # use libisg::Coord;
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// // (3, 5) is nodata
+    /// let (a, b, value) = isg.nearest(40.2, 121.8).unwrap();
+    /// assert_eq!((a, b), (Coord::with_dms(40, 50, 0), Coord::with_dms(121, 30, 0)));
+    /// assert_eq!(value, 46.6789);
+    /// ```
+    pub fn nearest(&self, a: f64, b: f64) -> Option<(Coord, Coord, f64)> {
+        let geodetic = matches!(self.header.coord_type, CoordType::Geodetic);
+
+        self.cells()
+            .filter_map(|(ca, cb, value)| value.map(|value| (ca, cb, value)))
+            .map(|cell| {
+                let d = distance2(a, b, cell.0.as_f64(), cell.1.as_f64(), geodetic);
+                (d, cell)
+            })
+            .fold(None, |acc: Option<(f64, _)>, (d, cell)| match acc {
+                Some((best, _)) if best <= d => acc,
+                _ => Some((d, cell)),
+            })
+            .map(|(_, cell)| cell)
+    }
+
+    /// Returns the grid and fractional index `(row, col)` of data of the point,
+    /// [`None`] when `self` is sparse.
+    #[inline]
+    pub(crate) fn frac_index(&self, a: f64, b: f64) -> Option<(GridView<'_>, f64, f64)> {
+        let (grid, locator) = self.locator()?;
+        let (row, col) = locator.frac_index(a, b);
+        Some((grid, row, col))
+    }
+
+    /// Returns the view of the whole grid and [`Locator`] of it, [`None`] when `self` is sparse.
+    pub(crate) fn locator(&self) -> Option<(GridView<'_>, Locator)> {
+        let grid = match &self.data {
+            Data::Grid(grid) => grid,
+            Data::Sparse(_) => return None,
+        };
+
+        let (flip_rows, flip_cols) = grid_flips_or_default(self.header.data_ordering);
+        let locator = Locator {
+            geometry: GridGeometry::new(&self.header)?,
+            flip_rows: flip_rows.then(|| grid.nrows() as f64 - 1.0),
+            flip_cols: flip_cols.then(|| grid.ncols() as f64 - 1.0),
+        };
+
+        Some((GridView::whole(&self.header, grid), locator))
+    }
+}
+
+/// Map from a point to fractional index `(row, col)` of data,
+/// which resolves the geometry and the ordering once for many points.
+#[derive(Debug, Clone)]
+pub(crate) struct Locator {
+    geometry: GridGeometry,
+    /// Last row index to flip, if any
+    flip_rows: Option<f64>,
+    /// Last column index to flip, if any
+    flip_cols: Option<f64>,
+}
+
+impl Locator {
+    #[inline]
+    pub(crate) fn frac_index(&self, a: f64, b: f64) -> (f64, f64) {
+        let (row, col) = self.geometry.frac_index(a, b);
+        let (row, col) = (snap(row), snap(col));
+        let row = self.flip_rows.map_or(row, |last| last - row);
+        let col = self.flip_cols.map_or(col, |last| last - col);
+        (row, col)
+    }
+}
+
+/// Clamps fractional index `x` into `[0, last]`,
+/// [`None`] when it is more than half a cell outside.
+#[inline]
+fn clamp_half(x: f64, last: f64) -> Option<f64> {
+    if (-0.5..=last + 0.5).contains(&x) && last >= 0.0 {
+        Some(x.max(0.0).min(last))
+    } else {
+        None
+    }
+}
+
+impl Interpolator for Method {
+    /// Returns [`None`] when the point is out of the grid
+    /// or a cell which contributes to the value is nodata.
+    #[inline]
+    fn sample(&self, grid: &GridView, frac_row: f64, frac_col: f64) -> Option<f64> {
+        match self {
+            Method::Nearest => nearest(grid, frac_row, frac_col),
+            Method::Bilinear => bilinear(grid, frac_row, frac_col),
+            Method::Bicubic => bicubic(grid, frac_row, frac_col),
+        }
+    }
+}
+
+#[inline]
+fn nearest(grid: &GridView, row: f64, col: f64) -> Option<f64> {
+    let (row, col) = ((row + 0.5).floor(), (col + 0.5).floor());
+    if !(row >= 0.0 && col >= 0.0) {
+        return None;
+    }
+
+    grid.get(row as usize, col as usize).copied().flatten()
+}
+
+fn bilinear(grid: &GridView, row: f64, col: f64) -> Option<f64> {
+    let (row0, col0) = (row.floor(), col.floor());
+    if !(row0 >= 0.0 && col0 >= 0.0) {
+        return None;
+    }
+
+    let (t, u) = (row - row0, col - col0);
+    let (row0, col0) = (row0 as usize, col0 as usize);
+
+    let mut value = 0.0;
+    for (i, wr) in [(0, 1.0 - t), (1, t)] {
+        for (j, wc) in [(0, 1.0 - u), (1, u)] {
+            let weight = wr * wc;
+            // cells of no weight are allowed to be nodata or out of bounds,
+            // e.g. the point is on the last row
+            if weight == 0.0 {
+                continue;
+            }
+            value += weight * (*grid.get(row0 + i, col0 + j)?)?;
+        }
+    }
+
+    Some(value)
+}
+
+fn bicubic(grid: &GridView, row: f64, col: f64) -> Option<f64> {
+    let (row0, col0) = (row.floor(), col.floor());
+    if !(row0 >= 1.0 && col0 >= 1.0) {
+        return bilinear(grid, row, col);
+    }
+
+    let (weights_row, weights_col) = (cubic_weights(row - row0), cubic_weights(col - col0));
+    let (row0, col0) = (row0 as usize - 1, col0 as usize - 1);
+
+    let mut value = 0.0;
+    for (i, wr) in weights_row.iter().enumerate() {
+        for (j, wc) in weights_col.iter().enumerate() {
+            let weight = wr * wc;
+            if weight == 0.0 {
+                continue;
+            }
+            match grid.get(row0 + i, col0 + j) {
+                Some(Some(v)) => value += weight * v,
+                _ => return bilinear(grid, row, col),
+            }
+        }
+    }
+
+    Some(value)
+}
+
+/// Returns weights of cubic convolution (Keys, `a = -0.5`) at offsets `-1`, `0`, `1` and `2`.
+#[inline]
+fn cubic_weights(t: f64) -> [f64; 4] {
+    let (t2, t3) = (t * t, t * t * t);
+    [
+        (-t3 + 2.0 * t2 - t) / 2.0,
+        (3.0 * t3 - 5.0 * t2 + 2.0) / 2.0,
+        (-3.0 * t3 + 4.0 * t2 + t) / 2.0,
+        (t3 - t2) / 2.0,
+    ]
+}
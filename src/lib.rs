@@ -4,38 +4,21 @@
 //! use std::fs;
 //!
 //! use libisg;
-//! use libisg::{Data, DataBounds, ISG};
 //!
 //!
 //! let s = fs::read_to_string("Example 1.isg").unwrap();
 //!
 //! let isg = libisg::from_str(&s).unwrap();
 //!
-//! // use data
-//! let (a_max, b_max, delta_a, delta_b) = match isg.header.data_bounds {
-//!     DataBounds::GridGeodetic { lat_max, lon_max, delta_lat, delta_lon, .. } => {
-//!         (lat_max, lon_max, delta_lat, delta_lon)
-//!     },
-//!     _ => unimplemented!("`Example 1.isg` is grid geodetic"),
-//! };
-//!
-//! match &isg.data {
-//!     Data::Grid(data) => {
-//!         for (nrow, row) in data.iter().enumerate() {
-//!             for (ncol, value) in row.iter().enumerate() {
-//!                 let a = a_max - delta_a * nrow;
-//!                 let b = b_max + delta_b * ncol;
-//!                 // do something
-//!             }
-//!         }
-//!     }
-//!     Data::Sparse(data) => {
-//!         for row in data {
-//!             let (a, b, value) = row;
-//!             // do something
-//!         }
+//! // (lat, lon, value) of each cell center, in the order of data
+//! for (a, b, value) in isg.cells() {
+//!     if let Some(value) = value {
+//!         // do something
 //!     }
 //! }
+//!
+//! // (lat, lon) of the cell center of `isg.data[(row, col)]`, respecting `data ordering`
+//! let (a, b) = isg.cell_coord(0, 0).unwrap();
 //! ```
 //!
 //! # Serialize/Deserialize
@@ -58,11 +41,16 @@
 //! assert_eq!(s, libisg::to_string(&isg));
 //! ```
 //!
+//! [`to_string_compact`] serializes without padding,
+//! it reduces file size but the output is still valid ISG format.
+//!
 //! ## serde
 //!
 //! [`ISG`] supports `serde` protocol.
 //!
 //! ```no_run
+//! # #[cfg(feature = "serde")]
+//! # {
 //! use std::fs;
 //! use serde_json;
 //! use libisg;
@@ -75,7 +63,8 @@
 //! let json = serde_json::to_string(&isg).unwrap();
 //!
 //! // deserialize
-//! assert_eq!(isg, serde_json::from_str(&json).unwrap());
+//! assert_eq!(isg, serde_json::from_str::<libisg::ISG>(&json).unwrap());
+//! # }
 //! ```
 //!
 //! # Notes
@@ -86,24 +75,109 @@
 // We don't support 1.01 format,
 // because it requires 18 digits decimal perception on data!
 
+use std::cmp::Ordering;
+use std::ops::{Index, IndexMut};
+
 #[cfg(feature = "serde")]
 use ::serde::{Deserialize, Serialize};
 
 #[doc(inline)]
-pub use display::to_string;
+pub use align::Misalignment;
+#[doc(inline)]
+pub use bounds::{Extent, ReconcilePolicy, Reconciliation, Registration};
+#[doc(inline)]
+pub use builder::{HeaderBuilder, IsgBuilder};
+#[doc(inline)]
+pub use comment::CommentMetadata;
+#[doc(inline)]
+pub use compare::{ComparisonReport, DifferenceStats, Discrepancy, FieldDiff, ResolutionPolicy};
+#[cfg(feature = "conformance")]
+#[doc(inline)]
+pub use conformance::{
+    conformance_cases, run_conformance, ConformanceCase, ConformanceFailure, ConformanceReport,
+    IsgCodec, LibIsg,
+};
+#[doc(inline)]
+pub use convert::SparseColumns;
+#[doc(inline)]
+pub use display::{
+    to_string, to_string_compact, to_string_with_options, CreationDateFormat, DmsFormat,
+    OptionalHeaders, WriteOptions,
+};
+#[doc(inline)]
+pub use error::{
+    ConversionError, CoordError, MismatchError, ParseError, ParseValueError, ParseWarning,
+    ValidationError,
+};
+#[doc(inline)]
+pub use fill::FillMethod;
+#[doc(inline)]
+pub use filter::{Outlier, Spike, SpikeAction};
+#[doc(inline)]
+pub use flags::GridFlags;
+#[doc(inline)]
+pub use geometry::{CellRef, CoordTrig};
+#[doc(inline)]
+pub use grid::Grid;
+#[doc(inline)]
+pub use interpolate::{Extrapolation, Interpolator, Method, Sample};
+#[doc(inline)]
+pub use merge::ConflictPolicy;
+#[doc(inline)]
+pub use parse::{from_str, from_str_lenient};
+#[doc(inline)]
+pub use region::Region;
+#[doc(inline)]
+pub use rle::RleGrid;
 #[doc(inline)]
-pub use error::{ParseError, ParseValueError, ValidationError};
+pub use seam::{check_seams, SeamReport, SeamSide};
 #[doc(inline)]
-pub use parse::from_str;
+pub use stats::Stats;
+#[cfg(feature = "testing")]
+#[doc(inline)]
+pub use testing::{CellDiff, IsgDiff};
+#[doc(inline)]
+pub use transform::{Convention, DmsRounding};
+#[doc(inline)]
+pub use view::GridView;
 
+mod align;
 mod arithm;
+mod bounds;
+mod builder;
+mod comment;
+mod compare;
+#[cfg(feature = "conformance")]
+mod conformance;
+mod convert;
 mod display;
 mod error;
+mod fill;
+mod filter;
+mod flags;
+mod geometry;
+mod gradient;
+mod grid;
+mod hash;
+mod height;
+mod interpolate;
+mod merge;
+mod ordering;
 mod parse;
+mod profile;
+mod region;
+mod resample;
+mod rle;
+mod seam;
 #[cfg(feature = "serde")]
 mod serde;
+mod stats;
+#[cfg(feature = "testing")]
+mod testing;
 mod token;
+mod transform;
 mod validation;
+mod view;
 
 /// ISG format.
 ///
@@ -173,21 +247,29 @@ pub struct Header {
 #[cfg_attr(feature = "serde", serde(untagged))]
 pub enum Data {
     /// Grid data
-    Grid(Vec<Vec<Option<f64>>>),
+    Grid(Grid),
     /// Sparse data
     Sparse(Vec<(Coord, Coord, f64)>),
 }
 
 impl Data {
     /// Makes [`Data::Grid`].
+    ///
+    /// # Panics
+    ///
+    /// Panics when rows have different lengths, see [`Grid::try_from`].
     pub fn new_grid(
         data: impl IntoIterator<Item = impl IntoIterator<Item = impl Into<Option<f64>>>>,
     ) -> Self {
-        Self::Grid(
-            data.into_iter()
-                .map(|row| row.into_iter().map(Into::into).collect())
-                .collect(),
-        )
+        let rows: Vec<Vec<_>> = data
+            .into_iter()
+            .map(|row| row.into_iter().map(Into::into).collect())
+            .collect();
+
+        match Grid::try_from(rows) {
+            Ok(grid) => Self::Grid(grid),
+            Err(e) => panic!("{}", e),
+        }
     }
 
     /// Makes [`Data::Sparse`].
@@ -201,7 +283,7 @@ impl Data {
     ///
     /// Panics when `self` is [`Data::Sparse`].
     #[inline]
-    pub fn grid_data(&self) -> &Vec<Vec<Option<f64>>> {
+    pub fn grid_data(&self) -> &Grid {
         match self {
             Data::Grid(data) => data,
             Data::Sparse(_) => panic!("self is `Data::Sparse`, expected `Data::Grid`"),
@@ -216,8 +298,209 @@ impl Data {
     #[inline]
     pub fn sparse_data(&self) -> &Vec<(Coord, Coord, f64)> {
         match self {
-            Data::Grid(_) => panic!(""),
-            Data::Sparse(_) => panic!("self is `Data::Grid`, expected `Data::Sparse`"),
+            Data::Grid(_) => panic!("self is `Data::Grid`, expected `Data::Sparse`"),
+            Data::Sparse(data) => data,
+        }
+    }
+
+    /// Returns data of [`Data::Grid`], [`None`] when `self` is [`Data::Sparse`].
+    #[inline]
+    pub fn as_grid(&self) -> Option<&Grid> {
+        match self {
+            Data::Grid(data) => Some(data),
+            Data::Sparse(_) => None,
+        }
+    }
+
+    /// Returns mutable data of [`Data::Grid`], [`None`] when `self` is [`Data::Sparse`].
+    #[inline]
+    pub fn as_grid_mut(&mut self) -> Option<&mut Grid> {
+        match self {
+            Data::Grid(data) => Some(data),
+            Data::Sparse(_) => None,
+        }
+    }
+
+    /// Returns data of [`Data::Sparse`], [`None`] when `self` is [`Data::Grid`].
+    #[inline]
+    pub fn as_sparse(&self) -> Option<&Vec<(Coord, Coord, f64)>> {
+        match self {
+            Data::Grid(_) => None,
+            Data::Sparse(data) => Some(data),
+        }
+    }
+
+    /// Returns mutable data of [`Data::Sparse`], [`None`] when `self` is [`Data::Grid`].
+    #[inline]
+    pub fn as_sparse_mut(&mut self) -> Option<&mut Vec<(Coord, Coord, f64)>> {
+        match self {
+            Data::Grid(_) => None,
+            Data::Sparse(data) => Some(data),
+        }
+    }
+
+    /// Returns `(nrows, ncols)` of data.
+    ///
+    /// For sparse, `nrows` is the number of records and `ncols` is `3`,
+    /// which correspond to `nrows` and `ncols` in header.
+    #[inline]
+    pub fn shape(&self) -> (usize, usize) {
+        match self {
+            Data::Grid(data) => (data.nrows(), data.ncols()),
+            Data::Sparse(data) => (data.len(), 3),
+        }
+    }
+
+    /// Returns the number of cells for grid, or records for sparse.
+    #[inline]
+    pub fn len(&self) -> usize {
+        match self {
+            Data::Grid(data) => data.nrows() * data.ncols(),
+            Data::Sparse(data) => data.len(),
+        }
+    }
+
+    /// Returns `true` if data has no cell or record.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the value of [`Data::Grid`] at (`row`, `col`),
+    /// [`None`] when out of bounds or `self` is [`Data::Sparse`].
+    #[inline]
+    pub fn get(&self, row: usize, col: usize) -> Option<&Option<f64>> {
+        self.as_grid().and_then(|data| data.get(row, col))
+    }
+
+    /// Returns a mutable reference to the value of [`Data::Grid`] at (`row`, `col`),
+    /// [`None`] when out of bounds or `self` is [`Data::Sparse`].
+    #[inline]
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut Option<f64>> {
+        self.as_grid_mut().and_then(|data| data.get_mut(row, col))
+    }
+
+    /// Sets value of [`Data::Grid`] at (`row`, `col`),
+    /// returns `false` if out of bounds or `self` is [`Data::Sparse`].
+    ///
+    /// Notes, NaN is rejected as it cannot be written as ISG format.
+    #[inline]
+    pub fn set(&mut self, row: usize, col: usize, value: f64) -> bool {
+        if value.is_nan() {
+            return false;
+        }
+
+        match self.get_mut(row, col) {
+            Some(v) => {
+                *v = Some(value);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Sets nodata to [`Data::Grid`] at (`row`, `col`),
+    /// returns `false` if out of bounds or `self` is [`Data::Sparse`].
+    #[inline]
+    pub fn set_nodata(&mut self, row: usize, col: usize) -> bool {
+        match self.get_mut(row, col) {
+            Some(v) => {
+                *v = None;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns an iterator of defined values, `nodata` is skipped.
+    ///
+    /// Grid is flattened in row-major order, and sparse yields values of records.
+    pub fn values(&self) -> impl Iterator<Item = f64> + '_ {
+        let (grid, sparse) = match self {
+            Data::Grid(data) => (Some(data), None),
+            Data::Sparse(data) => (None, Some(data)),
+        };
+
+        grid.into_iter()
+            .flat_map(|data| data.as_slice().iter().flatten().copied())
+            .chain(
+                sparse
+                    .into_iter()
+                    .flat_map(|data| data.iter().map(|(_, _, value)| *value)),
+            )
+    }
+
+    /// Returns an iterator of defined values with `(row, col, value)`, `nodata` is skipped.
+    ///
+    /// For sparse, `row` is the index of record and `col` is always `2`,
+    /// the column of value.
+    pub fn values_with_index(&self) -> impl Iterator<Item = (usize, usize, f64)> + '_ {
+        let (grid, sparse) = match self {
+            Data::Grid(data) => (Some(data), None),
+            Data::Sparse(data) => (None, Some(data)),
+        };
+
+        grid.into_iter()
+            .flat_map(|data| {
+                let ncols = data.ncols();
+                data.as_slice()
+                    .iter()
+                    .enumerate()
+                    .filter_map(move |(i, value)| value.map(|v| (i / ncols, i % ncols, v)))
+            })
+            .chain(sparse.into_iter().flat_map(|data| {
+                data.iter()
+                    .enumerate()
+                    .map(|(i, (_, _, value))| (i, 2, *value))
+            }))
+    }
+
+    /// Converts into data of [`Data::Grid`], returns `self` as [`Err`] when [`Data::Sparse`].
+    #[inline]
+    pub fn into_grid(self) -> Result<Grid, Data> {
+        match self {
+            Data::Grid(data) => Ok(data),
+            Data::Sparse(_) => Err(self),
+        }
+    }
+
+    /// Converts into data of [`Data::Sparse`], returns `self` as [`Err`] when [`Data::Grid`].
+    #[inline]
+    pub fn into_sparse(self) -> Result<Vec<(Coord, Coord, f64)>, Data> {
+        match self {
+            Data::Grid(_) => Err(self),
+            Data::Sparse(data) => Ok(data),
+        }
+    }
+}
+
+impl Index<(usize, usize)> for Data {
+    type Output = Option<f64>;
+
+    /// Returns the value of [`Data::Grid`] at `(row, col)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics when out of bounds or `self` is [`Data::Sparse`],
+    /// see [`Data::get`] for checked access.
+    #[inline]
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        &self.grid_data()[index]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Data {
+    /// Returns the value of [`Data::Grid`] at `(row, col)` as mutable.
+    ///
+    /// # Panics
+    ///
+    /// Panics when out of bounds or `self` is [`Data::Sparse`],
+    /// see [`Data::get_mut`] for checked access.
+    #[inline]
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        match self {
+            Data::Grid(data) => &mut data[index],
+            Data::Sparse(_) => panic!("self is `Data::Sparse`, expected `Data::Grid`"),
         }
     }
 }
@@ -282,13 +565,30 @@ pub enum DataFormat {
 }
 
 /// Value of `data ordering`
+///
+/// `S2NW2E`, `N2SE2W` and `S2NE2W` are not defined by ISG 2.0,
+/// use [`ISG::reorder`] to normalize to `N2SW2E`.
+///
+/// It is non-exhaustive, variants may be added without a major version.
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[non_exhaustive]
 pub enum DataOrdering {
+    /// `N-to-S, W-to-E`
     N2SW2E,
+    /// `lat, lon, N`
     LatLonN,
+    /// `east, north, N`
     EastNorthN,
+    /// `N`
     N,
+    /// `zeta`
     Zeta,
+    /// `S-to-N, W-to-E`
+    S2NW2E,
+    /// `N-to-S, E-to-W`
+    N2SE2W,
+    /// `S-to-N, E-to-W`
+    S2NE2W,
 }
 
 /// Value of `tide system`
@@ -370,8 +670,12 @@ impl CreationDate {
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum Coord {
     /// For `dms`.
+    ///
+    /// The sign is held by `negative` rather than `degree`,
+    /// so that a coordinate between -1° and 0°, e.g. `-0°20'00"`, is representable.
     DMS {
-        degree: i16,
+        negative: bool,
+        degree: u16,
         minutes: u8,
         second: u8,
     },
@@ -380,19 +684,244 @@ pub enum Coord {
 }
 
 impl Coord {
-    /// Make new [`Coord::DMS`].
+    /// Make new [`Coord::DMS`], the sign is taken from `degree`.
+    ///
+    /// Use [`Coord::with_signed_dms`] for a coordinate between -1° and 0°.
     #[inline]
     pub fn with_dms(degree: i16, minutes: u8, second: u8) -> Self {
         Self::DMS {
+            negative: degree.is_negative(),
+            degree: degree.unsigned_abs(),
+            minutes,
+            second,
+        }
+    }
+
+    /// Make new [`Coord::DMS`] of the sign `negative`.
+    ///
+    /// The sign of zero is dropped, `-0°00'00"` is made as `0°00'00"`.
+    ///
+    /// ```
+    /// # use libisg::Coord;
+    /// let coord = Coord::with_signed_dms(true, 0, 20, 0);
+    /// assert_eq!(coord.to_decimal_degrees(), -20.0 / 60.0);
+    /// assert_eq!(coord.to_string(), "-0°20'00\"");
+    ///
+    /// assert_eq!(Coord::with_signed_dms(true, 1, 0, 0), Coord::with_dms(-1, 0, 0));
+    /// assert_eq!(Coord::with_signed_dms(true, 0, 0, 0), Coord::with_dms(0, 0, 0));
+    /// ```
+    #[inline]
+    pub fn with_signed_dms(negative: bool, degree: u16, minutes: u8, second: u8) -> Self {
+        Self::DMS {
+            negative: negative && (degree, minutes, second) != (0, 0, 0),
             degree,
             minutes,
             second,
         }
     }
 
+    /// Make new [`Coord::DMS`], checking the values.
+    ///
+    /// `minutes` and `second` must be less than `60`,
+    /// and the coordinate must be in `[-360°, 360°]`,
+    /// which covers latitude and both conventions of longitude.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] when the values are out of the ranges.
+    ///
+    /// ```
+    /// # use libisg::Coord;
+    /// assert_eq!(Coord::try_with_dms(39, 50, 0), Ok(Coord::with_dms(39, 50, 0)));
+    /// assert!(Coord::try_with_dms(39, 60, 0).is_err());
+    /// assert!(Coord::try_with_dms(360, 0, 1).is_err());
+    /// ```
+    pub fn try_with_dms(degree: i16, minutes: u8, second: u8) -> Result<Self, CoordError> {
+        if minutes >= 60 {
+            return Err(CoordError::minutes(minutes));
+        }
+        if second >= 60 {
+            return Err(CoordError::second(second));
+        }
+        if degree.unsigned_abs() > 360
+            || (degree.unsigned_abs() == 360 && (minutes, second) != (0, 0))
+        {
+            return Err(CoordError::degree(degree));
+        }
+
+        Ok(Self::with_dms(degree, minutes, second))
+    }
+
     /// Make new [`Coord::Dec`].
     #[inline]
     pub fn with_dec(value: f64) -> Self {
         Self::Dec(value)
     }
+
+    /// Returns the value in decimal degrees.
+    ///
+    /// [`Coord::DMS`] is `degree + minutes / 60 + second / 3600` with the sign `negative`,
+    /// and [`Coord::Dec`] is returned as is (meters or feet for projected).
+    ///
+    /// ```
+    /// # use libisg::Coord;
+    /// assert_eq!(Coord::with_dms(39, 50, 0).to_decimal_degrees(), 39.0 + 50.0 / 60.0);
+    /// assert_eq!(Coord::with_dms(-1, 0, 36).to_decimal_degrees(), -1.01);
+    /// assert_eq!(Coord::with_signed_dms(true, 0, 0, 36).to_decimal_degrees(), -0.01);
+    /// assert_eq!(Coord::with_dec(120.5).to_decimal_degrees(), 120.5);
+    /// ```
+    #[inline]
+    pub fn to_decimal_degrees(&self) -> f64 {
+        self.as_f64()
+    }
+
+    /// Returns the value in radians, see [`CoordTrig`] for `sin` and `cos`.
+    ///
+    /// [`Coord::Dec`] is taken as decimal degrees,
+    /// which is meaningless for projected coordinates.
+    ///
+    /// ```
+    /// # use libisg::Coord;
+    /// assert_eq!(Coord::with_dms(90, 0, 0).to_radians(), std::f64::consts::FRAC_PI_2);
+    /// assert_eq!(Coord::with_dec(-180.0).to_radians(), -std::f64::consts::PI);
+    /// ```
+    #[inline]
+    pub fn to_radians(&self) -> f64 {
+        self.as_f64().to_radians()
+    }
+
+    /// Makes [`Coord`] of decimal degrees `value` for `coord_units`,
+    /// that is, [`Coord::DMS`] for `dms` and [`Coord::Dec`] for `deg`.
+    ///
+    /// DMS is rounded to the nearest second.
+    /// Returns [`None`] when `coord_units` is `meters` or `feet`,
+    /// or `value` is not representable in DMS, e.g. non-finite.
+    ///
+    /// ```
+    /// # use libisg::{Coord, CoordUnits};
+    /// assert_eq!(
+    ///     Coord::from_decimal_degrees(39.0 + 50.0 / 60.0, CoordUnits::DMS),
+    ///     Some(Coord::with_dms(39, 50, 0))
+    /// );
+    /// assert_eq!(Coord::from_decimal_degrees(39.5, CoordUnits::Deg), Some(Coord::with_dec(39.5)));
+    /// assert_eq!(Coord::from_decimal_degrees(39.5, CoordUnits::Meters), None);
+    /// ```
+    pub fn from_decimal_degrees(value: f64, coord_units: CoordUnits) -> Option<Self> {
+        match coord_units {
+            CoordUnits::DMS => Self::dms_from_f64_with(value, DmsRounding::Nearest),
+            CoordUnits::Deg => Some(Self::Dec(value)),
+            CoordUnits::Meters | CoordUnits::Feet => None,
+        }
+    }
+
+    /// Makes [`Coord::DMS`] of decimal degrees `value` by `rounding`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] when `value` is not representable in DMS by `rounding`,
+    /// e.g. non-finite.
+    ///
+    /// ```
+    /// # use libisg::{Coord, DmsRounding};
+    /// let value = 39.0 + 50.0 / 60.0 + 0.6 / 3600.0;
+    /// assert_eq!(
+    ///     Coord::dms_from_decimal_degrees(value, DmsRounding::Nearest),
+    ///     Ok(Coord::with_dms(39, 50, 1))
+    /// );
+    /// assert_eq!(
+    ///     Coord::dms_from_decimal_degrees(value, DmsRounding::Truncate),
+    ///     Ok(Coord::with_dms(39, 50, 0))
+    /// );
+    /// assert!(Coord::dms_from_decimal_degrees(value, DmsRounding::Exact).is_err());
+    ///
+    /// // float errors are not inexact
+    /// assert_eq!(
+    ///     Coord::dms_from_decimal_degrees(39.0 + 50.0 / 60.0, DmsRounding::Exact),
+    ///     Ok(Coord::with_dms(39, 50, 0))
+    /// );
+    /// ```
+    pub fn dms_from_decimal_degrees(value: f64, rounding: DmsRounding) -> Result<Self, CoordError> {
+        Self::dms_from_f64_with(value, rounding).ok_or_else(|| CoordError::unrepresentable(value))
+    }
+
+    /// Returns the total ordering between `self` and `other`, for sorting.
+    ///
+    /// Values of the same variant are ordered as [`PartialOrd`],
+    /// except [`Coord::Dec`] follows the IEEE 754 total order,
+    /// e.g. `-0.0 < 0.0` and NaN is the greatest (of positive sign).
+    /// Values of different variants are ordered by their decimal values,
+    /// then [`Coord::DMS`] precedes [`Coord::Dec`].
+    ///
+    /// ```
+    /// # use libisg::Coord;
+    /// let mut coords = vec![Coord::with_dec(f64::NAN), Coord::with_dec(1.5), Coord::with_dec(-2.0)];
+    /// coords.sort_by(Coord::total_cmp);
+    ///
+    /// assert_eq!(coords[..2], [Coord::with_dec(-2.0), Coord::with_dec(1.5)]);
+    /// ```
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        /// Key of IEEE 754 total order, same as `f64::total_cmp`
+        #[inline]
+        fn key(value: f64) -> i64 {
+            let bits = value.to_bits() as i64;
+            bits ^ (((bits >> 63) as u64) >> 1) as i64
+        }
+
+        match (self, other) {
+            (Self::DMS { .. }, Self::DMS { .. }) => self.dms_key().cmp(&other.dms_key()),
+            (Self::Dec(a), Self::Dec(b)) => key(*a).cmp(&key(*b)),
+            (Self::DMS { .. }, Self::Dec(_)) => key(self.as_f64())
+                .cmp(&key(other.as_f64()))
+                .then(Ordering::Less),
+            (Self::Dec(_), Self::DMS { .. }) => key(self.as_f64())
+                .cmp(&key(other.as_f64()))
+                .then(Ordering::Greater),
+        }
+    }
+
+    /// Returns key of [`Coord::DMS`] ordered by value, [`None`] for [`Coord::Dec`].
+    ///
+    /// Fields break ties of the same value,
+    /// which is possible when `minutes` or `second` is not less than `60`,
+    /// and the sign of zero, `-0°00'00"` is less than `0°00'00"` as [`PartialEq`] distinguishes.
+    #[inline]
+    fn dms_key(&self) -> Option<(i64, bool, u8, u8)> {
+        match self {
+            Self::DMS {
+                negative,
+                degree,
+                minutes,
+                second,
+            } => {
+                let seconds = *degree as i64 * 3600 + *minutes as i64 * 60 + *second as i64;
+                Some(if *negative {
+                    (-seconds, false, *minutes, *second)
+                } else {
+                    (seconds, true, *minutes, *second)
+                })
+            }
+            Self::Dec(_) => None,
+        }
+    }
+}
+
+/// Values of the same variant are compared by value,
+/// and values of different variants are not comparable,
+/// since [`Coord::Dec`] may be meters or feet.
+///
+/// ```
+/// # use libisg::Coord;
+/// assert!(Coord::with_dms(39, 50, 0) < Coord::with_dms(41, 10, 0));
+/// assert!(Coord::with_dms(-1, 0, 0) < Coord::with_dms(0, 30, 0));
+/// assert!(Coord::with_dec(120.0) <= Coord::with_dec(120.0));
+/// assert_eq!(Coord::with_dms(0, 30, 0).partial_cmp(&Coord::with_dec(0.5)), None);
+/// ```
+impl PartialOrd for Coord {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::DMS { .. }, Self::DMS { .. }) => self.dms_key().partial_cmp(&other.dms_key()),
+            (Self::Dec(a), Self::Dec(b)) => a.partial_cmp(b),
+            _ => None,
+        }
+    }
 }
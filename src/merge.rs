@@ -0,0 +1,273 @@
+use crate::error::MismatchError;
+use crate::geometry::{is_signed, shift_lon, wrap_lon, GridGeometry, TOLERANCE};
+use crate::ordering::grid_flips_or_default;
+use crate::{Coord, Data, DataBounds, Grid, ISG};
+
+/// Policy on overlapping cells of [`ISG::merge`]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ConflictPolicy {
+    /// Takes the value of the first model
+    First,
+    /// Takes the value of the last model
+    Last,
+    /// Takes the mean of the values
+    Mean,
+}
+
+impl ISG {
+    /// Merges grids into one model.
+    ///
+    /// Grids must share `coord units`, `data units` and deltas,
+    /// and grid nodes must be aligned each other.
+    /// Geodetic grids may cross the antimeridian.
+    /// `data ordering` of each grid is respected, and the result is in the ordering of the first model.
+    ///
+    /// `data bounds` of the result covers all grids,
+    /// and cells which are not covered by any grid are nodata.
+    /// On overlapping cells, a value is chosen by `policy`
+    /// (nodata does not conflict with a value).
+    /// The comment and the other header fields are copied from the first model.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] when `models` is empty or grids are not compatible.
+    ///
+    /// ```
+    /// # use libisg::{ConflictPolicy, ISG};
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// let west = isg.subgrid(0..4, 0..4).unwrap();
+    /// let east = isg.subgrid(0..4, 3..6).unwrap();
+    ///
+    /// let merged = ISG::merge(&[&west, &east], ConflictPolicy::First).unwrap();
+    /// assert_eq!(merged, isg);
+    /// ```
+    pub fn merge(models: &[&ISG], policy: ConflictPolicy) -> Result<ISG, MismatchError> {
+        let first = match models.first() {
+            Some(first) => *first,
+            None => return Err(MismatchError::empty()),
+        };
+
+        let mut data_bounds = first.header.data_bounds.clone();
+        for isg in models {
+            if !matches!(isg.data, Data::Grid(_)) {
+                return Err(MismatchError::not_grid());
+            }
+            if isg.header.coord_units != first.header.coord_units {
+                return Err(MismatchError::coord_units());
+            }
+            if isg.header.data_units != first.header.data_units {
+                return Err(MismatchError::data_units());
+            }
+            data_bounds = union_bounds(&data_bounds, &isg.header.data_bounds)?;
+        }
+
+        let mut header = first.header.clone();
+        header.data_bounds = data_bounds;
+        // never fails, data bounds is grid
+        let geometry = GridGeometry::new(&header).unwrap();
+
+        let mut placed = Vec::with_capacity(models.len());
+        let (mut nrows, mut ncols) = (0, 0);
+        for isg in models {
+            let grid = match &isg.data {
+                Data::Grid(grid) => grid,
+                Data::Sparse(_) => unreachable!(),
+            };
+            // never fails, data bounds is grid
+            let origin = GridGeometry::new(&isg.header).unwrap();
+
+            let (row, col) = geometry.frac_index(origin.a_max, origin.b_min);
+            let (row, col) = match (offset(row), offset(col)) {
+                (Some(row), Some(col)) => (row, col),
+                _ => return Err(MismatchError::misaligned()),
+            };
+
+            nrows = nrows.max(row + grid.nrows());
+            ncols = ncols.max(col + grid.ncols());
+            placed.push((
+                row,
+                col,
+                grid,
+                grid_flips_or_default(isg.header.data_ordering),
+            ));
+        }
+
+        let mut values = vec![None; nrows * ncols];
+        // used by `ConflictPolicy::Mean` only
+        let mut counts = match policy {
+            ConflictPolicy::Mean => vec![0_u32; values.len()],
+            ConflictPolicy::First | ConflictPolicy::Last => Vec::new(),
+        };
+        // placed in rows from `a max` and columns from `b min`
+        for (row, col, grid, (flip_rows, flip_cols)) in placed {
+            for i in 0..grid.nrows() {
+                // never fails, in bounds
+                let src = grid
+                    .row(if flip_rows { grid.nrows() - 1 - i } else { i })
+                    .unwrap();
+                let src: Box<dyn Iterator<Item = &Option<f64>>> = if flip_cols {
+                    Box::new(src.iter().rev())
+                } else {
+                    Box::new(src.iter())
+                };
+
+                let start = (row + i) * ncols + col;
+                for (k, v) in (start..).zip(src) {
+                    let v = match v {
+                        Some(v) => *v,
+                        None => continue,
+                    };
+
+                    match policy {
+                        ConflictPolicy::First => {
+                            values[k].get_or_insert(v);
+                        }
+                        ConflictPolicy::Last => values[k] = Some(v),
+                        ConflictPolicy::Mean => {
+                            values[k] = Some(values[k].unwrap_or(0.0) + v);
+                            counts[k] += 1;
+                        }
+                    }
+                }
+            }
+        }
+        for (v, n) in values.iter_mut().zip(&counts) {
+            if let Some(v) = v {
+                *v /= *n as f64;
+            }
+        }
+
+        header.nrows = nrows;
+        header.ncols = ncols;
+
+        // into the ordering of the first model
+        let mut grid = Grid::from_vec_unchecked(nrows, ncols, values);
+        let (flip_rows, flip_cols) = grid_flips_or_default(header.data_ordering);
+        if flip_rows {
+            grid.flip_rows();
+        }
+        if flip_cols {
+            grid.flip_cols();
+        }
+
+        Ok(ISG {
+            comment: first.comment.clone(),
+            header,
+            data: Data::Grid(grid),
+        })
+    }
+}
+
+/// Returns non-negative integer offset, [`None`] when it is off nodes.
+#[inline]
+fn offset(frac: f64) -> Option<usize> {
+    let rounded = frac.round();
+    if (frac - rounded).abs() <= TOLERANCE && rounded >= 0.0 {
+        Some(rounded as usize)
+    } else {
+        None
+    }
+}
+
+/// Returns `data bounds` which covers both.
+fn union_bounds(a: &DataBounds, b: &DataBounds) -> Result<DataBounds, MismatchError> {
+    #[inline]
+    fn min(a: &Coord, b: &Coord) -> Coord {
+        if b.as_f64() < a.as_f64() {
+            *b
+        } else {
+            *a
+        }
+    }
+
+    #[inline]
+    fn max(a: &Coord, b: &Coord) -> Coord {
+        if b.as_f64() > a.as_f64() {
+            *b
+        } else {
+            *a
+        }
+    }
+
+    match (a, b) {
+        (
+            DataBounds::GridGeodetic {
+                lat_min,
+                lat_max,
+                lon_min,
+                lon_max,
+                delta_lat,
+                delta_lon,
+            },
+            DataBounds::GridGeodetic {
+                lat_min: other_lat_min,
+                lat_max: other_lat_max,
+                lon_min: other_lon_min,
+                lon_max: other_lon_max,
+                delta_lat: other_delta_lat,
+                delta_lon: other_delta_lon,
+            },
+        ) => {
+            if delta_lat != other_delta_lat || delta_lon != other_delta_lon {
+                return Err(MismatchError::delta());
+            }
+
+            // lon is compared on the unwrapped ranges, for the antimeridian
+            let reference = lon_min.as_f64();
+            let unwrap = |lon_min: &Coord, lon_max: &Coord| {
+                let turns = -((lon_min.as_f64() - reference + 180.0) / 360.0).floor() as i32;
+                let min = shift_lon(lon_min, turns);
+                let max = if lon_max.as_f64() < lon_min.as_f64() {
+                    shift_lon(lon_max, turns + 1)
+                } else {
+                    shift_lon(lon_max, turns)
+                };
+                (min, max)
+            };
+            let signed = is_signed(lon_min, lon_max) && is_signed(other_lon_min, other_lon_max);
+            let (a_lon_min, a_lon_max) = unwrap(lon_min, lon_max);
+            let (b_lon_min, b_lon_max) = unwrap(other_lon_min, other_lon_max);
+
+            Ok(DataBounds::GridGeodetic {
+                lat_min: min(lat_min, other_lat_min),
+                lat_max: max(lat_max, other_lat_max),
+                lon_min: wrap_lon(min(&a_lon_min, &b_lon_min), signed),
+                lon_max: wrap_lon(max(&a_lon_max, &b_lon_max), signed),
+                delta_lat: *delta_lat,
+                delta_lon: *delta_lon,
+            })
+        }
+        (
+            DataBounds::GridProjected {
+                north_min,
+                north_max,
+                east_min,
+                east_max,
+                delta_north,
+                delta_east,
+            },
+            DataBounds::GridProjected {
+                north_min: other_north_min,
+                north_max: other_north_max,
+                east_min: other_east_min,
+                east_max: other_east_max,
+                delta_north: other_delta_north,
+                delta_east: other_delta_east,
+            },
+        ) => {
+            if delta_north != other_delta_north || delta_east != other_delta_east {
+                return Err(MismatchError::delta());
+            }
+
+            Ok(DataBounds::GridProjected {
+                north_min: min(north_min, other_north_min),
+                north_max: max(north_max, other_north_max),
+                east_min: min(east_min, other_east_min),
+                east_max: max(east_max, other_east_max),
+                delta_north: *delta_north,
+                delta_east: *delta_east,
+            })
+        }
+        _ => Err(MismatchError::data_bounds()),
+    }
+}
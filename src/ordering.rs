@@ -0,0 +1,181 @@
+use std::cmp::Ordering;
+
+use crate::{Coord, Data, DataOrdering, ISG};
+
+impl ISG {
+    /// Sorts sparse data by `data ordering`.
+    ///
+    /// - `N-to-S, W-to-E`: descending by the first column, then ascending by the second column
+    /// - `S-to-N, W-to-E`, `lat, lon, N` and `east, north, N`: ascending by the first column, then the second column
+    /// - `N-to-S, E-to-W`: descending by the first column, then descending by the second column
+    /// - `S-to-N, E-to-W`: ascending by the first column, then descending by the second column
+    ///
+    /// It does nothing when `self` is grid or `data ordering` is the others or [`None`].
+    /// The sort is stable.
+    pub fn sort_sparse_by_ordering(&mut self) {
+        let cmp = match sparse_cmp(self.header.data_ordering.as_ref()) {
+            None => return,
+            Some(cmp) => cmp,
+        };
+
+        if let Data::Sparse(data) = &mut self.data {
+            data.sort_by(|a, b| cmp((&a.0, &a.1), (&b.0, &b.1)));
+        }
+    }
+
+    /// Reorders data to `target` ordering and updates `data ordering`.
+    ///
+    /// Grid rows and columns are flipped, where [`None`] `data ordering`
+    /// is regarded as `N-to-S, W-to-E`.
+    /// The grid orderings (`N-to-S, W-to-E`, `S-to-N, W-to-E`, `N-to-S, E-to-W`
+    /// and `S-to-N, E-to-W`) are available as `target` of grid data.
+    ///
+    /// Sparse records are sorted by [`ISG::sort_sparse_by_ordering`],
+    /// and the coordinate columns are swapped when either of the orderings is `east, north, N`.
+    /// `lat, lon, N` and `east, north, N` are available as `target` of sparse data.
+    ///
+    /// Returns `false` and does nothing when `target` is not available.
+    ///
+    /// ```
+    /// # use libisg::DataOrdering;
+    /// # let mut isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// let expected = isg.clone();
+    ///
+    /// assert!(isg.reorder(DataOrdering::S2NE2W));
+    /// assert_eq!(isg.data[(0, 0)], expected.data[(3, 5)]);
+    ///
+    /// assert!(isg.reorder(DataOrdering::N2SW2E));
+    /// assert_eq!(isg, expected);
+    ///
+    /// assert!(!isg.reorder(DataOrdering::LatLonN));
+    /// ```
+    pub fn reorder(&mut self, target: DataOrdering) -> bool {
+        match &mut self.data {
+            Data::Grid(grid) => {
+                let (flip_rows, flip_cols) = match (
+                    grid_flips(target),
+                    grid_flips_or_default(self.header.data_ordering),
+                ) {
+                    (Some(target), current) => (target.0 != current.0, target.1 != current.1),
+                    (None, _) => return false,
+                };
+
+                if flip_rows {
+                    grid.flip_rows();
+                }
+                if flip_cols {
+                    grid.flip_cols();
+                }
+            }
+            Data::Sparse(data) => {
+                if !matches!(target, DataOrdering::LatLonN | DataOrdering::EastNorthN) {
+                    return false;
+                }
+
+                let is_east_north = |ordering| matches!(ordering, Some(DataOrdering::EastNorthN));
+                if is_east_north(self.header.data_ordering) != is_east_north(Some(target)) {
+                    for (a, b, _) in data.iter_mut() {
+                        std::mem::swap(a, b);
+                    }
+                }
+            }
+        }
+
+        self.header.data_ordering = Some(target);
+        self.sort_sparse_by_ordering();
+        true
+    }
+
+    /// Reverses the order of grid rows, and toggles N-to-S/S-to-N of `data ordering`.
+    ///
+    /// [`None`] or non-grid `data ordering` is regarded as `N-to-S, W-to-E`.
+    /// Returns `false` and does nothing when `self` is sparse.
+    ///
+    /// ```
+    /// # use libisg::DataOrdering;
+    /// # let mut isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// let expected = isg.clone();
+    ///
+    /// assert!(isg.flip_rows());
+    /// assert_eq!(isg.header.data_ordering, Some(DataOrdering::S2NW2E));
+    /// assert_eq!(isg.data[(0, 0)], expected.data[(3, 0)]);
+    /// ```
+    pub fn flip_rows(&mut self) -> bool {
+        match &mut self.data {
+            Data::Grid(grid) => grid.flip_rows(),
+            Data::Sparse(_) => return false,
+        }
+
+        let (rows, cols) = grid_flips_or_default(self.header.data_ordering);
+        self.header.data_ordering = Some(grid_ordering(!rows, cols));
+        true
+    }
+
+    /// Reverses the order of grid columns, and toggles W-to-E/E-to-W of `data ordering`,
+    /// see [`ISG::flip_rows`].
+    pub fn flip_cols(&mut self) -> bool {
+        match &mut self.data {
+            Data::Grid(grid) => grid.flip_cols(),
+            Data::Sparse(_) => return false,
+        }
+
+        let (rows, cols) = grid_flips_or_default(self.header.data_ordering);
+        self.header.data_ordering = Some(grid_ordering(rows, !cols));
+        true
+    }
+}
+
+/// Returns whether rows and columns are flipped from `N-to-S, W-to-E`,
+/// [`None`] when `data_ordering` is not a grid ordering
+#[inline]
+fn grid_flips(data_ordering: DataOrdering) -> Option<(bool, bool)> {
+    match data_ordering {
+        DataOrdering::N2SW2E => Some((false, false)),
+        DataOrdering::S2NW2E => Some((true, false)),
+        DataOrdering::N2SE2W => Some((false, true)),
+        DataOrdering::S2NE2W => Some((true, true)),
+        DataOrdering::LatLonN | DataOrdering::EastNorthN | DataOrdering::N | DataOrdering::Zeta => {
+            None
+        }
+    }
+}
+
+/// Returns the grid ordering of flips, the inverse of [`grid_flips`]
+#[inline]
+fn grid_ordering(flip_rows: bool, flip_cols: bool) -> DataOrdering {
+    match (flip_rows, flip_cols) {
+        (false, false) => DataOrdering::N2SW2E,
+        (true, false) => DataOrdering::S2NW2E,
+        (false, true) => DataOrdering::N2SE2W,
+        (true, true) => DataOrdering::S2NE2W,
+    }
+}
+
+/// Returns flips of rows and columns from `N-to-S, W-to-E`, no flip unless grid ordering
+#[inline]
+pub(crate) fn grid_flips_or_default(data_ordering: Option<DataOrdering>) -> (bool, bool) {
+    data_ordering.and_then(grid_flips).unwrap_or((false, false))
+}
+
+type SparseCmp = fn((&Coord, &Coord), (&Coord, &Coord)) -> Ordering;
+
+/// Returns comparator of sparse records by `data ordering`
+#[inline]
+pub(crate) fn sparse_cmp(data_ordering: Option<&DataOrdering>) -> Option<SparseCmp> {
+    #[inline]
+    fn cmp(a: &Coord, b: &Coord) -> Ordering {
+        a.as_f64()
+            .partial_cmp(&b.as_f64())
+            .unwrap_or(Ordering::Equal)
+    }
+
+    match data_ordering {
+        Some(DataOrdering::N2SW2E) => Some(|a, b| cmp(b.0, a.0).then_with(|| cmp(a.1, b.1))),
+        Some(DataOrdering::S2NW2E | DataOrdering::LatLonN | DataOrdering::EastNorthN) => {
+            Some(|a, b| cmp(a.0, b.0).then_with(|| cmp(a.1, b.1)))
+        }
+        Some(DataOrdering::N2SE2W) => Some(|a, b| cmp(b.0, a.0).then_with(|| cmp(b.1, a.1))),
+        Some(DataOrdering::S2NE2W) => Some(|a, b| cmp(a.0, b.0).then_with(|| cmp(b.1, a.1))),
+        Some(DataOrdering::N | DataOrdering::Zeta) | None => None,
+    }
+}
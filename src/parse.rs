@@ -63,6 +63,9 @@ impl FromStr for DataOrdering {
             "east, north, N" => Ok(Self::EastNorthN),
             "N" => Ok(Self::N),
             "zeta" => Ok(Self::Zeta),
+            "S-to-N, W-to-E" => Ok(Self::S2NW2E),
+            "N-to-S, E-to-W" => Ok(Self::N2SE2W),
+            "S-to-N, E-to-W" => Ok(Self::S2NE2W),
             _ => Err(Self::Err::new(s)),
         }
     }
@@ -116,21 +119,37 @@ impl FromStr for Coord {
 
         let (d, rest) = s.split_once('°').ok_or(Self::Err::new(s))?;
         let (m, rest) = rest.split_once('\'').ok_or(Self::Err::new(s))?;
-        let (s, rest) = rest.split_once('"').ok_or(Self::Err::new(s))?;
+        let (sec, rest) = rest.split_once('"').ok_or(Self::Err::new(s))?;
+
+        // hemisphere letter, e.g. `39°50'00"N` and `121°50'00"W`
+        let hemisphere = match rest {
+            "" => None,
+            "N" | "E" => Some(false),
+            "S" | "W" => Some(true),
+            _ => return Err(Self::Err::new(s)),
+        };
 
-        if !rest.is_empty() {
+        // the sign is parsed by hand, since `degree` of -0°20'00" is 0
+        let (negative, d) = match (d.strip_prefix('-'), d.strip_prefix('+')) {
+            (Some(d), _) => (Some(true), d),
+            (_, Some(d)) => (Some(false), d),
+            _ => (None, d),
+        };
+        let negative = match (negative, hemisphere) {
+            // both of sign and hemisphere are ambiguous
+            (Some(_), Some(_)) => return Err(Self::Err::new(s)),
+            (Some(negative), None) | (None, Some(negative)) => negative,
+            (None, None) => false,
+        };
+        if d.starts_with(['+', '-']) {
             return Err(Self::Err::new(s));
         }
 
         let degree = d.parse().map_err(|_| Self::Err::new(s))?;
         let minutes = m.parse().map_err(|_| Self::Err::new(s))?;
-        let second = s.parse().map_err(|_| Self::Err::new(s))?;
+        let second = sec.parse().map_err(|_| Self::Err::new(s))?;
 
-        Ok(Self::DMS {
-            degree,
-            minutes,
-            second,
-        })
+        Ok(Self::with_signed_dms(negative, degree, minutes, second))
     }
 }
 
@@ -138,14 +157,31 @@ impl FromStr for CreationDate {
     type Err = ParseValueError;
     #[inline]
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut split = s.split('/');
+        // Accepts `DD/MM/YYYY` and ISO `YYYY-MM-DD`
+        let (d, m, y) = if s.contains('-') {
+            let mut split = s.split('-');
 
-        let d = split.next().ok_or(Self::Err::new(s))?;
-        let m = split.next().ok_or(Self::Err::new(s))?;
-        let y = split.next().ok_or(Self::Err::new(s))?;
+            let y = split.next().ok_or(Self::Err::new(s))?;
+            let m = split.next().ok_or(Self::Err::new(s))?;
+            let d = split.next().ok_or(Self::Err::new(s))?;
 
-        if split.next().is_some() {
-            return Err(Self::Err::new(s));
+            if split.next().is_some() {
+                return Err(Self::Err::new(s));
+            };
+
+            (d, m, y)
+        } else {
+            let mut split = s.split('/');
+
+            let d = split.next().ok_or(Self::Err::new(s))?;
+            let m = split.next().ok_or(Self::Err::new(s))?;
+            let y = split.next().ok_or(Self::Err::new(s))?;
+
+            if split.next().is_some() {
+                return Err(Self::Err::new(s));
+            };
+
+            (d, m, y)
         };
 
         let year = y.parse().map_err(|_| Self::Err::new(s))?;
@@ -282,7 +318,10 @@ impl CoordUnits {
 
 impl<'a> HeaderStore<'a> {
     #[inline]
-    fn from_tokenizer(tokenizer: &mut Tokenizer<'a>) -> Result<Self, ParseError> {
+    fn from_tokenizer(
+        tokenizer: &mut Tokenizer<'a>,
+        mut warnings: Option<&mut Vec<ParseWarning>>,
+    ) -> Result<Self, ParseError> {
         let mut this = Self::default();
 
         macro_rules! set_value {
@@ -295,7 +334,19 @@ impl<'a> HeaderStore<'a> {
             }};
         }
 
-        while let Some((key, _, value)) = tokenizer.tokenize_header()? {
+        loop {
+            if let Some(warnings) = warnings.as_deref_mut() {
+                if let Some((line, lineno)) = tokenizer.tokenize_stray_header() {
+                    warnings.push(ParseWarning::stray_line(line, lineno));
+                    continue;
+                }
+            }
+
+            let (key, value) = match tokenizer.tokenize_header()? {
+                None => break,
+                Some((key, _, value)) => (key, value),
+            };
+
             match key
                 .value
                 .parse()
@@ -820,29 +871,47 @@ impl DataBounds {
 fn parse_data_grid(
     tokenizer: &mut Tokenizer,
     header: &Header,
-    lineno: usize,
+    mut lineno: usize,
+    mut warnings: Option<&mut Vec<ParseWarning>>,
 ) -> Result<Data, ParseError> {
     let mut rno = 0;
 
-    let mut data = Vec::with_capacity(header.nrows);
+    // `nrows` and `ncols` are not trusted, a datum takes at least 2 bytes with the separator
+    let mut data = Vec::with_capacity(
+        header
+            .nrows
+            .saturating_mul(header.ncols)
+            .min(tokenizer.input_len() / 2),
+    );
     while let Some(tokens) = tokenizer.tokenize_data() {
+        lineno = tokens.lineno();
+
+        if tokens.is_stray() {
+            match warnings.as_deref_mut() {
+                None => return Err(ParseError::stray_line(tokens.line(), lineno)),
+                Some(warnings) => {
+                    warnings.push(ParseWarning::stray_line(tokens.line(), lineno));
+                    continue;
+                }
+            }
+        }
+
         if rno >= header.nrows {
             return Err(ParseError::too_long_data(
                 DataDirection::Row,
                 header.nrows,
-                lineno + rno + 1,
+                lineno,
             ));
         }
 
         let mut cno = 0;
 
-        let mut row = Vec::with_capacity(header.ncols);
         for token in tokens {
             if cno >= header.ncols {
                 return Err(ParseError::too_long_data(
                     DataDirection::Column,
                     header.ncols,
-                    lineno + rno + 1,
+                    lineno,
                 ));
             }
 
@@ -850,10 +919,10 @@ fn parse_data_grid(
                 .parse()
                 .map_err(|_| ParseError::invalid_data(&token))?;
 
-            if header.nodata.as_ref().map_or(false, |m| m == &a) {
-                row.push(None)
+            if header.nodata.as_ref() == Some(&a) {
+                data.push(None)
             } else {
-                row.push(Some(a))
+                data.push(Some(a))
             }
 
             cno += 1;
@@ -863,13 +932,10 @@ fn parse_data_grid(
             return Err(ParseError::too_short_data(
                 DataDirection::Column,
                 header.ncols,
-                lineno + rno + 1,
+                lineno,
             ));
         }
 
-        row.shrink_to_fit();
-        data.push(row);
-
         rno += 1;
     }
 
@@ -877,19 +943,24 @@ fn parse_data_grid(
         return Err(ParseError::too_short_data(
             DataDirection::Row,
             header.nrows,
-            lineno + rno + 1,
+            lineno + 1,
         ));
     }
 
     data.shrink_to_fit();
-    Ok(Data::Grid(data))
+    Ok(Data::Grid(Grid::from_vec_unchecked(
+        header.nrows,
+        header.ncols,
+        data,
+    )))
 }
 
 #[inline]
 fn parse_data_sparse(
     tokenizer: &mut Tokenizer,
     header: &Header,
-    lineno: usize,
+    mut lineno: usize,
+    mut warnings: Option<&mut Vec<ParseWarning>>,
 ) -> Result<Data, ParseError> {
     let is_valid_angle = match &header.coord_units {
         CoordUnits::DMS => |a: &Coord| matches!(a, Coord::DMS { .. }),
@@ -900,13 +971,26 @@ fn parse_data_sparse(
 
     let mut rno = 0;
 
-    let mut data = Vec::with_capacity(header.nrows);
+    // `nrows` is not trusted, a record takes at least 6 bytes with the separators
+    let mut data = Vec::with_capacity(header.nrows.min(tokenizer.input_len() / 6));
     while let Some(mut tokens) = tokenizer.tokenize_data() {
+        lineno = tokens.lineno();
+
+        if tokens.is_stray() {
+            match warnings.as_deref_mut() {
+                None => return Err(ParseError::stray_line(tokens.line(), lineno)),
+                Some(warnings) => {
+                    warnings.push(ParseWarning::stray_line(tokens.line(), lineno));
+                    continue;
+                }
+            }
+        }
+
         if rno >= header.nrows {
             return Err(ParseError::too_long_data(
                 DataDirection::Row,
                 header.nrows,
-                lineno + rno + 1,
+                lineno,
             ));
         }
 
@@ -914,7 +998,7 @@ fn parse_data_sparse(
             None => Err(ParseError::too_short_data(
                 DataDirection::Column,
                 header.ncols,
-                lineno + rno + 1,
+                lineno,
             )),
             Some(token) => match token.parse() {
                 Ok(r) if is_valid_angle(&r) => Ok(r),
@@ -926,7 +1010,7 @@ fn parse_data_sparse(
             None => Err(ParseError::too_short_data(
                 DataDirection::Column,
                 header.ncols,
-                lineno + rno + 1,
+                lineno,
             )),
             Some(token) => match token.parse() {
                 Ok(r) if is_valid_angle(&r) => Ok(r),
@@ -938,7 +1022,7 @@ fn parse_data_sparse(
             None => Err(ParseError::too_short_data(
                 DataDirection::Column,
                 header.ncols,
-                lineno + rno + 1,
+                lineno,
             )),
             Some(token) => token.parse().map_err(|_| ParseError::invalid_data(&token)),
         }?;
@@ -947,7 +1031,7 @@ fn parse_data_sparse(
             return Err(ParseError::too_long_data(
                 DataDirection::Column,
                 header.ncols,
-                lineno + rno + 1,
+                lineno,
             ));
         }
 
@@ -960,7 +1044,7 @@ fn parse_data_sparse(
         return Err(ParseError::too_short_data(
             DataDirection::Row,
             header.nrows,
-            lineno + rno + 1,
+            lineno + 1,
         ));
     }
 
@@ -969,20 +1053,47 @@ fn parse_data_sparse(
 }
 
 /// Deserialize ISG-format.
+///
+/// This fails on a non-data line in the data section, such as blank line,
+/// `begin_of_head`, `end_of_head` and header line (e.g. `model name : EXAMPLE`),
+/// and on a free-text line in the header section.
+/// Use [`from_str_lenient`] to skip them.
+///
+/// DMS coordinates may have hemisphere letter instead of sign,
+/// e.g. `39°50'00"N` and `121°50'00"W`.
 #[inline]
 pub fn from_str(s: &str) -> Result<ISG, ParseError> {
+    parse(s, None)
+}
+
+/// Deserialize ISG-format, skipping stray lines in the header and data sections.
+///
+/// Blank line, `begin_of_head`, `end_of_head` and header line (e.g. `model name : EXAMPLE`)
+/// in the data section are skipped and reported as [`ParseWarning`],
+/// and so are free-text lines in the header section, that is, lines without `=` but header lines.
+#[inline]
+pub fn from_str_lenient(s: &str) -> Result<(ISG, Vec<ParseWarning>), ParseError> {
+    let mut warnings = Vec::new();
+    let isg = parse(s, Some(&mut warnings))?;
+    Ok((isg, warnings))
+}
+
+#[inline]
+fn parse(s: &str, mut warnings: Option<&mut Vec<ParseWarning>>) -> Result<ISG, ParseError> {
     let mut tokenizer = Tokenizer::new(s);
 
     let comment = tokenizer.tokenize_comment()?.value.to_string();
     let _ = tokenizer.tokenize_begin_of_header()?;
 
-    let header = HeaderStore::from_tokenizer(&mut tokenizer)?.header()?;
+    let header = HeaderStore::from_tokenizer(&mut tokenizer, warnings.as_deref_mut())?.header()?;
 
     let end_of_head = tokenizer.tokenize_end_of_header()?;
 
     let data = match header.data_format {
-        DataFormat::Grid => parse_data_grid(&mut tokenizer, &header, end_of_head.lineno),
-        DataFormat::Sparse => parse_data_sparse(&mut tokenizer, &header, end_of_head.lineno),
+        DataFormat::Grid => parse_data_grid(&mut tokenizer, &header, end_of_head.lineno, warnings),
+        DataFormat::Sparse => {
+            parse_data_sparse(&mut tokenizer, &header, end_of_head.lineno, warnings)
+        }
     }?;
 
     Ok(ISG {
@@ -992,6 +1103,18 @@ pub fn from_str(s: &str) -> Result<ISG, ParseError> {
     })
 }
 
+impl FromStr for Header {
+    type Err = ParseError;
+
+    /// Deserialize header section,
+    /// that is lines between `begin_of_head` and `end_of_head` (exclusive).
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokenizer = Tokenizer::with_header_only(s);
+        HeaderStore::from_tokenizer(&mut tokenizer, None)?.header()
+    }
+}
+
 impl FromStr for ISG {
     type Err = ParseError;
 
@@ -0,0 +1,139 @@
+use crate::{CoordType, ISG};
+
+/// Mean radius of the earth in meters (IUGG, `R1` of GRS80)
+pub(crate) const EARTH_RADIUS: f64 = 6_371_008.8;
+
+impl ISG {
+    /// Samples values along the straight line from `start` to `end` in coordinate space,
+    /// returns pairs of the distance from `start` and the value.
+    ///
+    /// Points are `(lat, lon)` in decimal degrees or `(north, east)`, see [`ISG::interpolate`],
+    /// and `n_samples` points are equally spaced including both ends.
+    /// The distance is also in coordinate space, that is,
+    /// in decimal degrees for geodetic and in `coord units` for projected.
+    /// Values are bilinearly interpolated, see [`ISG::interpolate_many`],
+    /// and [`None`] when not available.
+    ///
+    /// ```
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// let profile = isg.profile((41.0, 120.0), (41.0, 121.0), 3);
+    ///
+    /// assert_eq!(profile.len(), 3);
+    /// assert_eq!(profile[0], (0.0, isg.interpolate(41.0, 120.0)));
+    /// assert_eq!(profile[2], (1.0, isg.interpolate(41.0, 121.0)));
+    /// ```
+    pub fn profile(
+        &self,
+        start: (f64, f64),
+        end: (f64, f64),
+        n_samples: usize,
+    ) -> Vec<(f64, Option<f64>)> {
+        let (da, db) = (end.0 - start.0, end.1 - start.1);
+        let length = da.hypot(db);
+
+        let ts = steps(n_samples);
+        let points: Vec<_> = ts
+            .iter()
+            .map(|t| (start.0 + t * da, start.1 + t * db))
+            .collect();
+
+        ts.iter()
+            .map(|t| t * length)
+            .zip(self.interpolate_many(&points))
+            .collect()
+    }
+
+    /// Samples values along the great circle from `start` to `end` on the sphere,
+    /// returns pairs of the distance from `start` in meters and the value.
+    ///
+    /// Points are `(lat, lon)` in decimal degrees,
+    /// and `n_samples` points are equally spaced along the shorter arc including both ends.
+    /// The sphere has the mean radius of the earth, 6,371,008.8 m,
+    /// which is accurate enough to sample geoid models even along long transects,
+    /// see [`ISG::profile`] for the straight line in coordinate space.
+    /// Values are bilinearly interpolated, and [`None`] when not available.
+    ///
+    /// Returns [`None`] when `self` is projected or `start` and `end` are antipodal.
+    ///
+    /// ```
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// let profile = isg.great_circle_profile((41.0, 120.0), (40.5, 121.0), 11).unwrap();
+    ///
+    /// assert_eq!(profile.len(), 11);
+    /// assert_eq!(profile[0], (0.0, isg.interpolate(41.0, 120.0)));
+    /// // about 101 km
+    /// assert!((profile[10].0 - 101_000.0).abs() < 1_000.0);
+    /// ```
+    pub fn great_circle_profile(
+        &self,
+        start: (f64, f64),
+        end: (f64, f64),
+        n_samples: usize,
+    ) -> Option<Vec<(f64, Option<f64>)>> {
+        if !matches!(self.header.coord_type, CoordType::Geodetic) {
+            return None;
+        }
+
+        let (p, q) = (unit_vector(start), unit_vector(end));
+        let cross = [
+            p[1] * q[2] - p[2] * q[1],
+            p[2] * q[0] - p[0] * q[2],
+            p[0] * q[1] - p[1] * q[0],
+        ];
+        let sin = cross.iter().map(|c| c * c).sum::<f64>().sqrt();
+        let cos = p.iter().zip(&q).map(|(p, q)| p * q).sum::<f64>();
+        // central angle
+        let angle = sin.atan2(cos);
+
+        let ts = steps(n_samples);
+        let points = if angle == 0.0 {
+            vec![start; ts.len()]
+        } else if sin < 1e-12 {
+            // antipodal, the great circle is not unique
+            return None;
+        } else {
+            ts.iter()
+                .map(|&t| {
+                    // both ends are exact
+                    if t == 0.0 {
+                        return start;
+                    } else if t == 1.0 {
+                        return end;
+                    }
+
+                    // spherical linear interpolation
+                    let (wp, wq) = (((1.0 - t) * angle).sin() / sin, (t * angle).sin() / sin);
+                    let v = [0, 1, 2].map(|i| wp * p[i] + wq * q[i]);
+                    (
+                        v[2].atan2(v[0].hypot(v[1])).to_degrees(),
+                        v[1].atan2(v[0]).to_degrees(),
+                    )
+                })
+                .collect()
+        };
+
+        Some(
+            ts.iter()
+                .map(|t| t * angle * EARTH_RADIUS)
+                .zip(self.interpolate_many(&points))
+                .collect(),
+        )
+    }
+}
+
+/// Returns the unit vector of the point `(lat, lon)` in decimal degrees.
+#[inline]
+fn unit_vector((lat, lon): (f64, f64)) -> [f64; 3] {
+    let (lat, lon) = (lat.to_radians(), lon.to_radians());
+    [lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin()]
+}
+
+/// Returns `n` fractions equally spaced on `[0, 1]` including both ends.
+#[inline]
+fn steps(n: usize) -> Vec<f64> {
+    match n {
+        0 => Vec::new(),
+        1 => vec![0.0],
+        _ => (0..n).map(|i| i as f64 / (n - 1) as f64).collect(),
+    }
+}
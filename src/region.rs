@@ -0,0 +1,119 @@
+use crate::stats::Accumulator;
+use crate::{Data, Stats, ISG};
+
+/// Region in coordinate space, see [`ISG::zonal_stats`].
+///
+/// Points are `(a, b)`, that is, `(lat, lon)` in decimal degrees or `(north, east)`,
+/// as [`ISG::cells`] yields.
+/// Implement it for polygons or other shapes of the application.
+///
+/// ```
+/// use libisg::Region;
+///
+/// struct North;
+///
+/// impl Region for North {
+///     fn contains(&self, a: f64, _: f64) -> bool {
+///         a > 40.9
+///     }
+/// }
+///
+/// assert!(North.contains(41.0, 120.0));
+/// assert!(!North.contains(40.0, 120.0));
+/// ```
+pub trait Region {
+    /// Returns `true` if the point (`a`, `b`) is inside of the region.
+    fn contains(&self, a: f64, b: f64) -> bool;
+}
+
+impl<T: Region + ?Sized> Region for &T {
+    #[inline]
+    fn contains(&self, a: f64, b: f64) -> bool {
+        (**self).contains(a, b)
+    }
+}
+
+impl ISG {
+    /// Returns statistics of defined cells (or records) of which centers are inside of `region`,
+    /// [`None`] when there is no such cell.
+    ///
+    /// ```
+    /// # use libisg::Region;
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// // the upper-left 2 × 2 cells
+    /// struct UpperLeft;
+    /// impl Region for UpperLeft {
+    ///     fn contains(&self, a: f64, b: f64) -> bool {
+    ///         (40.7..=41.3).contains(&a) && (119.7..=120.3).contains(&b)
+    ///     }
+    /// }
+    ///
+    /// let stats = isg.zonal_stats(UpperLeft).unwrap();
+    ///
+    /// assert_eq!(stats.count, 4);
+    /// assert_eq!(stats.max, 42.2345);
+    /// ```
+    pub fn zonal_stats<R: Region>(&self, region: R) -> Option<Stats> {
+        let mut acc = Accumulator::default();
+        for (a, b, value) in self.cells() {
+            if let Some(value) = value {
+                if region.contains(a.as_f64(), b.as_f64()) {
+                    acc.push(value);
+                }
+            }
+        }
+        acc.finish()
+    }
+
+    /// Returns a new [`ISG`] of which cells outside of `region` are set to nodata,
+    /// or cells inside when `keep_inside` is `false`.
+    ///
+    /// Cells are tested by their centers, and records outside are removed for sparse data,
+    /// then `nrows` and the bounds of header are updated to the remaining records
+    /// (the bounds are kept when no record remains).
+    /// Otherwise header and comment are preserved.
+    ///
+    /// ```
+    /// # use libisg::Region;
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// // the upper-left 2 × 2 cells
+    /// struct UpperLeft;
+    /// impl Region for UpperLeft {
+    ///     fn contains(&self, a: f64, b: f64) -> bool {
+    ///         (40.7..=41.3).contains(&a) && (119.7..=120.3).contains(&b)
+    ///     }
+    /// }
+    ///
+    /// let clipped = isg.mask(UpperLeft, true);
+    /// assert_eq!(clipped.data[(0, 0)], Some(30.1234));
+    /// assert_eq!(clipped.data[(0, 2)], None);
+    /// assert_eq!(clipped.nodata_count(), 20);
+    ///
+    /// let masked = isg.mask(UpperLeft, false);
+    /// assert_eq!(masked.data[(0, 0)], None);
+    /// assert_eq!(masked.data[(0, 2)], Some(32.3456));
+    /// ```
+    pub fn mask<R: Region>(&self, region: R, keep_inside: bool) -> ISG {
+        let mut keep = self
+            .cells()
+            .map(|(a, b, _)| region.contains(a.as_f64(), b.as_f64()) == keep_inside)
+            .collect::<Vec<_>>()
+            .into_iter();
+
+        let mut isg = self.clone();
+        match &mut isg.data {
+            Data::Grid(data) => data
+                .as_mut_slice()
+                .iter_mut()
+                .zip(keep)
+                .filter(|(_, keep)| !keep)
+                .for_each(|(value, _)| *value = None),
+            Data::Sparse(data) => {
+                data.retain(|_| keep.next().unwrap_or(true));
+                isg.header.nrows = data.len();
+                isg.recompute_bounds();
+            }
+        }
+        isg
+    }
+}
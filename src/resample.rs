@@ -0,0 +1,213 @@
+use crate::geometry::{is_signed, wrap_lon};
+use crate::interpolate::{Interpolator, Method};
+use crate::ordering::grid_flips_or_default;
+use crate::{Coord, Data, DataBounds, Grid, GridView, ISG};
+
+impl ISG {
+    /// Returns a grid which keeps every `factor_rows`-th row and `factor_cols`-th column,
+    /// starting from the first cell of data.
+    ///
+    /// Deltas are multiplied by the factors, and `nrows`, `ncols` and `data bounds` are updated,
+    /// where bounds of the first row and column are unchanged,
+    /// e.g. `lat max` and `lon min` for `N-to-S, W-to-E`.
+    /// Returns [`None`] when `self` is sparse.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `factor_rows` or `factor_cols` is `0`.
+    ///
+    /// ```
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// let overview = isg.decimate(2, 3).unwrap();
+    ///
+    /// assert_eq!((overview.header.nrows, overview.header.ncols), (2, 2));
+    /// assert_eq!(overview.data[(1, 1)], isg.data[(2, 3)]);
+    /// ```
+    pub fn decimate(&self, factor_rows: usize, factor_cols: usize) -> Option<ISG> {
+        assert!(
+            factor_rows != 0 && factor_cols != 0,
+            "factor must be non-zero"
+        );
+
+        let grid = match &self.data {
+            Data::Grid(grid) => grid,
+            Data::Sparse(_) => return None,
+        };
+
+        let (nrows, ncols) = (grid.nrows(), grid.ncols());
+        let (new_nrows, new_ncols) = (
+            (nrows + factor_rows - 1) / factor_rows,
+            (ncols + factor_cols - 1) / factor_cols,
+        );
+        // margins between the last kept cells and the original bounds
+        let (margin_rows, margin_cols) = (
+            nrows.saturating_sub(1) - new_nrows.saturating_sub(1) * factor_rows,
+            ncols.saturating_sub(1) - new_ncols.saturating_sub(1) * factor_cols,
+        );
+        // margins on (`a min`, `a max`) and (`b min`, `b max`), the last cells are at the end of data
+        let (flip_rows, flip_cols) = grid_flips_or_default(self.header.data_ordering);
+        let (margin_a_min, margin_a_max) = if flip_rows {
+            (0, margin_rows)
+        } else {
+            (margin_rows, 0)
+        };
+        let (margin_b_min, margin_b_max) = if flip_cols {
+            (margin_cols, 0)
+        } else {
+            (0, margin_cols)
+        };
+
+        let data_bounds = match &self.header.data_bounds {
+            DataBounds::GridGeodetic {
+                lat_min,
+                lat_max,
+                lon_min,
+                lon_max,
+                delta_lat,
+                delta_lon,
+            } => DataBounds::GridGeodetic {
+                lat_min: lat_min + delta_lat * margin_a_min,
+                lat_max: lat_max - delta_lat * margin_a_max,
+                lon_min: wrap_lon(
+                    lon_min + delta_lon * margin_b_min,
+                    is_signed(lon_min, lon_max),
+                ),
+                lon_max: wrap_lon(
+                    lon_max - delta_lon * margin_b_max,
+                    is_signed(lon_min, lon_max),
+                ),
+                delta_lat: delta_lat * factor_rows,
+                delta_lon: delta_lon * factor_cols,
+            },
+            DataBounds::GridProjected {
+                north_min,
+                north_max,
+                east_min,
+                east_max,
+                delta_north,
+                delta_east,
+            } => DataBounds::GridProjected {
+                north_min: north_min + delta_north * margin_a_min,
+                north_max: north_max - delta_north * margin_a_max,
+                east_min: east_min + delta_east * margin_b_min,
+                east_max: east_max - delta_east * margin_b_max,
+                delta_north: delta_north * factor_rows,
+                delta_east: delta_east * factor_cols,
+            },
+            DataBounds::SparseGeodetic { .. } | DataBounds::SparseProjected { .. } => return None,
+        };
+
+        let values = grid
+            .rows()
+            .step_by(factor_rows)
+            .flat_map(|row| row.iter().step_by(factor_cols).copied())
+            .collect();
+
+        let mut header = self.header.clone();
+        header.data_bounds = data_bounds;
+        header.nrows = new_nrows;
+        header.ncols = new_ncols;
+
+        Some(ISG {
+            comment: self.comment.clone(),
+            header,
+            data: Data::Grid(Grid::from_vec_unchecked(new_nrows, new_ncols, values)),
+        })
+    }
+
+    /// Returns a grid refined by `factor` by interpolation of `method`,
+    /// e.g. `factor` `2` halves deltas.
+    ///
+    /// Nodes of `self` are kept, so `nrows` becomes `(nrows - 1) * factor + 1`
+    /// (`ncols` as well), and `data bounds` except deltas are unchanged.
+    /// Interpolated cells are nodata when any of contributing cells is nodata.
+    ///
+    /// Returns [`None`] when `self` is sparse or deltas in `dms` are not divisible by `factor`
+    /// into whole seconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `factor` is `0`.
+    ///
+    /// ```
+    /// # use libisg::Method;
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// let fine = isg.upsample(2, Method::Bilinear).unwrap();
+    ///
+    /// assert_eq!((fine.header.nrows, fine.header.ncols), (7, 11));
+    /// assert_eq!(fine.data[(2, 2)], isg.data[(1, 1)]);
+    /// assert_eq!(fine.data[(0, 1)], Some((30.1234 + 31.2222) / 2.0));
+    /// ```
+    pub fn upsample(&self, factor: usize, method: Method) -> Option<ISG> {
+        assert!(factor != 0, "factor must be non-zero");
+
+        let grid = match &self.data {
+            Data::Grid(grid) => grid,
+            Data::Sparse(_) => return None,
+        };
+
+        let mut data_bounds = self.header.data_bounds.clone();
+        match &mut data_bounds {
+            DataBounds::GridGeodetic {
+                delta_lat,
+                delta_lon,
+                ..
+            } => {
+                *delta_lat = div_delta(delta_lat, factor)?;
+                *delta_lon = div_delta(delta_lon, factor)?;
+            }
+            DataBounds::GridProjected {
+                delta_north,
+                delta_east,
+                ..
+            } => {
+                *delta_north = div_delta(delta_north, factor)?;
+                *delta_east = div_delta(delta_east, factor)?;
+            }
+            DataBounds::SparseGeodetic { .. } | DataBounds::SparseProjected { .. } => return None,
+        }
+
+        let (nrows, ncols) = (
+            grid.nrows().saturating_sub(1) * factor + grid.nrows().min(1),
+            grid.ncols().saturating_sub(1) * factor + grid.ncols().min(1),
+        );
+
+        let view = GridView::whole(&self.header, grid);
+        let scale = factor as f64;
+        let mut values = Vec::with_capacity(nrows * ncols);
+        for row in 0..nrows {
+            for col in 0..ncols {
+                values.push(method.sample(&view, row as f64 / scale, col as f64 / scale));
+            }
+        }
+
+        let mut header = self.header.clone();
+        header.data_bounds = data_bounds;
+        header.nrows = nrows;
+        header.ncols = ncols;
+
+        Some(ISG {
+            comment: self.comment.clone(),
+            header,
+            data: Data::Grid(Grid::from_vec_unchecked(nrows, ncols, values)),
+        })
+    }
+}
+
+/// Returns `delta / factor`, [`None`] when [`Coord::DMS`] is not divisible into whole seconds.
+#[inline]
+fn div_delta(delta: &Coord, factor: usize) -> Option<Coord> {
+    match delta {
+        Coord::DMS { .. } => {
+            let seconds = (delta.as_f64() * 3600.0).round() as u64;
+            if seconds % factor as u64 == 0 {
+                Some(Coord::dms_from_f64(
+                    (seconds / factor as u64) as f64 / 3600.0,
+                ))
+            } else {
+                None
+            }
+        }
+        Coord::Dec(value) => Some(Coord::Dec(value / factor as f64)),
+    }
+}
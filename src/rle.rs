@@ -0,0 +1,139 @@
+use crate::Grid;
+
+/// Grid data of which `nodata` is run-length encoded, see [`Grid::to_rle`].
+///
+/// Only defined values are stored, in row-major order,
+/// and a run of consecutive `nodata` (in row-major order) costs a constant size,
+/// so it is compact when `nodata` forms long stretches, e.g. ocean masks of land-only models.
+///
+/// ```
+/// use std::convert::TryFrom;
+///
+/// use libisg::Grid;
+///
+/// let grid = Grid::try_from(vec![
+///     vec![Some(1.0), None, None],
+///     vec![None, None, Some(6.0)],
+/// ]).unwrap();
+/// let rle = grid.to_rle();
+///
+/// assert_eq!(rle.get(0, 0), Some(Some(1.0)));
+/// assert_eq!(rle.get(1, 1), Some(None));
+/// assert_eq!(rle.get(2, 0), None);
+/// assert_eq!(rle.nodata_count(), 4);
+/// assert_eq!(rle.to_grid(), grid);
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct RleGrid<T = f64> {
+    values: Vec<T>,
+    runs: Vec<Run>,
+    nrows: usize,
+    ncols: usize,
+}
+
+/// Run of `nodata`
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+struct Run {
+    /// Offset of the first cell of the run
+    start: usize,
+    /// Number of cells of the run
+    len: usize,
+    /// Number of `nodata` cells before the run
+    before: usize,
+}
+
+impl<T: Copy> Grid<T> {
+    /// Converts into [`RleGrid`], which run-length encodes `nodata`.
+    pub fn to_rle(&self) -> RleGrid<T> {
+        let mut values = Vec::new();
+        let mut runs: Vec<Run> = Vec::new();
+        let mut nodata = 0;
+
+        for (i, value) in self.as_slice().iter().enumerate() {
+            match value {
+                Some(value) => values.push(*value),
+                None => {
+                    match runs.last_mut() {
+                        Some(run) if run.start + run.len == i => run.len += 1,
+                        _ => runs.push(Run {
+                            start: i,
+                            len: 1,
+                            before: nodata,
+                        }),
+                    }
+                    nodata += 1;
+                }
+            }
+        }
+
+        values.shrink_to_fit();
+        runs.shrink_to_fit();
+
+        RleGrid {
+            values,
+            runs,
+            nrows: self.nrows(),
+            ncols: self.ncols(),
+        }
+    }
+}
+
+impl<T: Copy> RleGrid<T> {
+    /// Returns the number of rows.
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    /// Returns the number of columns.
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    /// Returns the number of `nodata` cells.
+    #[inline]
+    pub fn nodata_count(&self) -> usize {
+        self.nrows * self.ncols - self.values.len()
+    }
+
+    /// Returns the value at (`row`, `col`), [`None`] when out of bounds.
+    pub fn get(&self, row: usize, col: usize) -> Option<Option<T>> {
+        if row >= self.nrows || col >= self.ncols {
+            return None;
+        }
+
+        let i = row * self.ncols + col;
+        // runs which start at or before `i`
+        let k = self.runs.partition_point(|run| run.start <= i);
+        let nodata = match k.checked_sub(1).map(|k| &self.runs[k]) {
+            Some(run) if i < run.start + run.len => return Some(None),
+            Some(run) => run.before + run.len,
+            None => 0,
+        };
+
+        Some(Some(self.values[i - nodata]))
+    }
+
+    /// Returns an iterator of values in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = Option<T>> + '_ {
+        let mut values = self.values.iter();
+        let mut runs = self.runs.iter().peekable();
+
+        (0..self.nrows * self.ncols).map(move |i| match runs.peek() {
+            Some(run) if run.start <= i => {
+                if i + 1 == run.start + run.len {
+                    runs.next();
+                }
+                None
+            }
+            // defined cell, values are consumed in row-major order
+            _ => values.next().copied(),
+        })
+    }
+
+    /// Converts into [`Grid`].
+    pub fn to_grid(&self) -> Grid<T> {
+        Grid::from_vec_unchecked(self.nrows, self.ncols, self.iter().collect())
+    }
+}
@@ -0,0 +1,172 @@
+use crate::geometry::{flip_index, GridGeometry};
+use crate::ordering::grid_flips_or_default;
+use crate::{Data, Grid, ISG};
+
+/// Side of the neighbor tile, see [`check_seams`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum SeamSide {
+    North,
+    South,
+    East,
+    West,
+}
+
+/// Discontinuity statistics on the seam of two tiles, see [`check_seams`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct SeamReport {
+    /// Side of `b` seen from `a`
+    pub side: SeamSide,
+    /// `true` if two tiles share the boundary, `false` if they are abutting
+    pub shared: bool,
+    /// Number of compared cell pairs, nodata cells are excluded
+    pub count: usize,
+    /// Max of absolute difference
+    pub max_abs_diff: f64,
+    /// Mean of absolute difference
+    pub mean_abs_diff: f64,
+    /// Root mean square of difference
+    pub rms: f64,
+    /// Pairs of which absolute difference exceeds the tolerance,
+    /// `(index, difference)` where `index` is the position on the edge of `a`
+    /// (row of data for east/west, column of data for north/south) and difference is `b - a`
+    pub exceeded: Vec<(usize, f64)>,
+}
+
+/// Compares the boundary rows/columns of two adjacent grid tiles.
+///
+/// Tiles must have the same deltas and either share the boundary
+/// (the edge rows/columns are on the same coordinate)
+/// or be abutting (the edge rows/columns are one delta apart).
+/// The edges are compared at the same coordinate along the seam,
+/// and `data ordering` of each tile is respected.
+///
+/// Returns [`None`] when `a` or `b` is not grid, deltas differ
+/// or tiles are not adjacent.
+pub fn check_seams(a: &ISG, b: &ISG, tolerance: f64) -> Option<SeamReport> {
+    let (a_data, b_data) = match (&a.data, &b.data) {
+        (Data::Grid(a), Data::Grid(b)) => (a, b),
+        _ => return None,
+    };
+
+    let ga = GridGeometry::new(&a.header)?;
+    let gb = GridGeometry::new(&b.header)?;
+    if ga.nrows == 0 || ga.ncols == 0 || gb.nrows == 0 || gb.ncols == 0 {
+        return None;
+    }
+
+    let eps = 1e-3 * ga.delta_a.abs().min(ga.delta_b.abs());
+    let close = |x: f64, y: f64| (x - y).abs() <= eps;
+    if !close(ga.delta_a, gb.delta_a) || !close(ga.delta_b, gb.delta_b) {
+        return None;
+    }
+
+    let a_min = |g: &GridGeometry| g.a_max - g.delta_a * (g.nrows - 1) as f64;
+    let b_max = |g: &GridGeometry| g.b_min + g.delta_b * (g.ncols - 1) as f64;
+
+    // find side and whether the boundary is shared
+    let adjacent = |edge_a: f64, edge_b: f64, delta: f64| {
+        if close(edge_a, edge_b) {
+            Some(true)
+        } else if close(edge_a + delta, edge_b) {
+            Some(false)
+        } else {
+            None
+        }
+    };
+
+    let (side, shared) = adjacent(b_max(&ga), gb.b_min, ga.delta_b)
+        .map(|shared| (SeamSide::East, shared))
+        .or_else(|| {
+            adjacent(b_max(&gb), ga.b_min, ga.delta_b).map(|shared| (SeamSide::West, shared))
+        })
+        .or_else(|| {
+            adjacent(ga.a_max, a_min(&gb), ga.delta_a).map(|shared| (SeamSide::North, shared))
+        })
+        .or_else(|| {
+            adjacent(gb.a_max, a_min(&ga), ga.delta_a).map(|shared| (SeamSide::South, shared))
+        })?;
+
+    // index on `b` if fractional index `idx` is on a row/column
+    let matched = |idx: f64, delta: f64, len: usize| {
+        let rounded = idx.round();
+        if (idx - rounded).abs() * delta.abs() <= eps && 0.0 <= rounded && rounded < len as f64 {
+            Some(rounded as usize)
+        } else {
+            None
+        }
+    };
+
+    // rows from `a max` and columns from `b min` into index of data, respecting `data ordering`
+    let (flips_a, flips_b) = (
+        grid_flips_or_default(a.header.data_ordering),
+        grid_flips_or_default(b.header.data_ordering),
+    );
+    let index =
+        |data: &Grid, flips, row, col| flip_index((row, col), (data.nrows(), data.ncols()), flips);
+    let value = |data: &Grid, index: Option<(usize, usize)>| {
+        index.and_then(|(row, col)| data.get(row, col).copied().flatten())
+    };
+
+    let mut diffs = Vec::new();
+    match side {
+        SeamSide::East | SeamSide::West => {
+            let (col_a, col_b) = match side {
+                SeamSide::East => (ga.ncols - 1, 0),
+                _ => (0, gb.ncols - 1),
+            };
+            for row_a in 0..ga.nrows {
+                let coord = ga.a_max - ga.delta_a * row_a as f64;
+                if let Some(row_b) = matched((gb.a_max - coord) / gb.delta_a, gb.delta_a, gb.nrows)
+                {
+                    let index_a = index(a_data, flips_a, row_a, col_a);
+                    let index_b = index(b_data, flips_b, row_b, col_b);
+                    if let (Some(x), Some(y)) = (value(a_data, index_a), value(b_data, index_b)) {
+                        // never fails, `x` is found
+                        diffs.push((index_a.unwrap().0, y - x));
+                    }
+                }
+            }
+        }
+        SeamSide::North | SeamSide::South => {
+            let (row_a, row_b) = match side {
+                SeamSide::North => (0, gb.nrows - 1),
+                _ => (ga.nrows - 1, 0),
+            };
+            for col_a in 0..ga.ncols {
+                let coord = ga.b_min + ga.delta_b * col_a as f64;
+                if let Some(col_b) = matched((coord - gb.b_min) / gb.delta_b, gb.delta_b, gb.ncols)
+                {
+                    let index_a = index(a_data, flips_a, row_a, col_a);
+                    let index_b = index(b_data, flips_b, row_b, col_b);
+                    if let (Some(x), Some(y)) = (value(a_data, index_a), value(b_data, index_b)) {
+                        // never fails, `x` is found
+                        diffs.push((index_a.unwrap().1, y - x));
+                    }
+                }
+            }
+        }
+    }
+
+    let count = diffs.len();
+    let (max_abs_diff, mean_abs_diff, rms) = if count == 0 {
+        (0.0, 0.0, 0.0)
+    } else {
+        let max = diffs.iter().fold(0.0_f64, |acc, (_, d)| acc.max(d.abs()));
+        let mean = diffs.iter().map(|(_, d)| d.abs()).sum::<f64>() / count as f64;
+        let rms = (diffs.iter().map(|(_, d)| d * d).sum::<f64>() / count as f64).sqrt();
+        (max, mean, rms)
+    };
+
+    Some(SeamReport {
+        side,
+        shared,
+        count,
+        max_abs_diff,
+        mean_abs_diff,
+        rms,
+        exceeded: diffs
+            .into_iter()
+            .filter(|(_, d)| d.abs() > tolerance)
+            .collect(),
+    })
+}
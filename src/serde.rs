@@ -1,7 +1,7 @@
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::{
-    Coord, CoordType, CoordUnits, DataFormat, DataOrdering, DataType, DataUnits, ModelType,
+    Coord, CoordType, CoordUnits, DataFormat, DataOrdering, DataType, DataUnits, Grid, ModelType,
     TideSystem,
 };
 
@@ -13,19 +13,39 @@ impl Serialize for Coord {
         // untagged
         match self {
             Coord::DMS {
+                negative,
                 degree,
                 minutes,
                 second,
             } => {
                 use serde::ser::SerializeStruct;
 
-                let mut s = serializer.serialize_struct("Coord", 3)?;
-
-                s.serialize_field("degree", degree)?;
-                s.serialize_field("minutes", minutes)?;
-                s.serialize_field("second", second)?;
+                // the sign is folded into `degree` when it can hold the sign,
+                // otherwise `negative` is written, e.g. -0°20'00"
+                let signed = match i16::try_from(*degree) {
+                    Ok(degree) if degree != 0 || !negative => {
+                        Some(if *negative { -degree } else { degree })
+                    }
+                    _ => None,
+                };
 
-                s.end()
+                match signed {
+                    Some(degree) => {
+                        let mut s = serializer.serialize_struct("Coord", 3)?;
+                        s.serialize_field("degree", &degree)?;
+                        s.serialize_field("minutes", minutes)?;
+                        s.serialize_field("second", second)?;
+                        s.end()
+                    }
+                    None => {
+                        let mut s = serializer.serialize_struct("Coord", 4)?;
+                        s.serialize_field("negative", negative)?;
+                        s.serialize_field("degree", degree)?;
+                        s.serialize_field("minutes", minutes)?;
+                        s.serialize_field("second", second)?;
+                        s.end()
+                    }
+                }
             }
             Coord::Dec(value) => serializer.serialize_f64(*value),
         }
@@ -38,6 +58,7 @@ impl<'de> Deserialize<'de> for Coord {
         D: Deserializer<'de>,
     {
         enum Field {
+            Negative,
             Degree,
             Minutes,
             Second,
@@ -52,7 +73,7 @@ impl<'de> Deserialize<'de> for Coord {
                     type Value = Field;
 
                     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-                        formatter.write_str("`degree`, `minutes` or `second`")
+                        formatter.write_str("`negative`, `degree`, `minutes` or `second`")
                     }
 
                     fn visit_str<E>(self, value: &str) -> Result<Field, E>
@@ -60,6 +81,7 @@ impl<'de> Deserialize<'de> for Coord {
                         E: de::Error,
                     {
                         match value {
+                            "negative" => Ok(Field::Negative),
                             "degree" => Ok(Field::Degree),
                             "minutes" => Ok(Field::Minutes),
                             "second" => Ok(Field::Second),
@@ -91,11 +113,18 @@ impl<'de> Deserialize<'de> for Coord {
             where
                 V: de::MapAccess<'de>,
             {
-                let mut degree = None;
+                let mut negative = None;
+                let mut degree: Option<i32> = None;
                 let mut minutes = None;
                 let mut second = None;
                 while let Some(key) = map.next_key()? {
                     match key {
+                        Field::Negative => {
+                            if negative.is_some() {
+                                return Err(de::Error::duplicate_field("negative"));
+                            }
+                            negative = Some(map.next_value()?);
+                        }
                         Field::Degree => {
                             if degree.is_some() {
                                 return Err(de::Error::duplicate_field("degree"));
@@ -120,19 +149,47 @@ impl<'de> Deserialize<'de> for Coord {
                 let degree = degree.ok_or_else(|| de::Error::missing_field("degree"))?;
                 let minutes = minutes.ok_or_else(|| de::Error::missing_field("minutes"))?;
                 let second = second.ok_or_else(|| de::Error::missing_field("second"))?;
-                Ok(Self::Value::DMS {
-                    degree,
-                    minutes,
-                    second,
-                })
+
+                // `negative` is optional, and negative `degree` is also negative
+                let negative = negative.unwrap_or(false) || degree.is_negative();
+                let degree = u16::try_from(degree.unsigned_abs()).map_err(|_| {
+                    de::Error::invalid_value(
+                        de::Unexpected::Signed(degree as i64),
+                        &"degree in [-65535, 65535]",
+                    )
+                })?;
+                Ok(Self::Value::with_signed_dms(
+                    negative, degree, minutes, second,
+                ))
             }
         }
 
-        const FIELDS: &[&str] = &["degree", "minutes", "second"];
+        const FIELDS: &[&str] = &["negative", "degree", "minutes", "second"];
         deserializer.deserialize_any(CoordVisitor)
     }
 }
 
+impl<T: Copy + Serialize> Serialize for Grid<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // as nested seq of rows
+        serializer.collect_seq(self.rows())
+    }
+}
+
+impl<'de, T: Copy + Deserialize<'de>> Deserialize<'de> for Grid<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use std::convert::TryFrom;
+        let rows = Vec::<Vec<Option<T>>>::deserialize(deserializer)?;
+        Grid::try_from(rows).map_err(de::Error::custom)
+    }
+}
+
 macro_rules! impl_ser {
     ($name:ident, $( $variant:ident => ($index:literal, $string:literal) ),+ ) => {
         impl Serialize for $name {
@@ -203,7 +260,10 @@ impl_ser!(
     LatLonN => (1, "lat, lon, N"),
     EastNorthN => (2, "east, north, N"),
     N => (3, "N"),
-    Zeta => (4, "zeta")
+    Zeta => (4, "zeta"),
+    S2NW2E => (5, "S-to-N, W-to-E"),
+    N2SE2W => (6, "N-to-S, E-to-W"),
+    S2NE2W => (7, "S-to-N, E-to-W")
 );
 
 impl_de!(DataOrdering);
@@ -243,11 +303,7 @@ mod test {
 
     #[test]
     fn serde_angle() {
-        let angle = Coord::DMS {
-            degree: 1,
-            minutes: 2,
-            second: 3,
-        };
+        let angle = Coord::with_dms(1, 2, 3);
 
         assert_tokens(
             &angle,
@@ -266,6 +322,46 @@ mod test {
             ],
         );
 
+        let angle = Coord::with_dms(-1, 2, 3);
+
+        assert_tokens(
+            &angle,
+            &[
+                Token::Struct {
+                    name: "Coord",
+                    len: 3,
+                },
+                Token::Str("degree"),
+                Token::I16(-1),
+                Token::Str("minutes"),
+                Token::U8(2),
+                Token::Str("second"),
+                Token::U8(3),
+                Token::StructEnd,
+            ],
+        );
+
+        let angle = Coord::with_signed_dms(true, 0, 2, 3);
+
+        assert_tokens(
+            &angle,
+            &[
+                Token::Struct {
+                    name: "Coord",
+                    len: 4,
+                },
+                Token::Str("negative"),
+                Token::Bool(true),
+                Token::Str("degree"),
+                Token::U16(0),
+                Token::Str("minutes"),
+                Token::U8(2),
+                Token::Str("second"),
+                Token::U8(3),
+                Token::StructEnd,
+            ],
+        );
+
         let angle = Coord::Dec(1.0);
 
         assert_tokens(&angle, &[Token::F64(1.0)]);
@@ -387,6 +483,27 @@ mod test {
                 variant: "zeta",
             }],
         );
+        assert_tokens(
+            &DataOrdering::S2NW2E,
+            &[Token::UnitVariant {
+                name: "DataOrdering",
+                variant: "S-to-N, W-to-E",
+            }],
+        );
+        assert_tokens(
+            &DataOrdering::N2SE2W,
+            &[Token::UnitVariant {
+                name: "DataOrdering",
+                variant: "N-to-S, E-to-W",
+            }],
+        );
+        assert_tokens(
+            &DataOrdering::S2NE2W,
+            &[Token::UnitVariant {
+                name: "DataOrdering",
+                variant: "S-to-N, E-to-W",
+            }],
+        );
     }
 
     #[test]
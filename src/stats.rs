@@ -0,0 +1,137 @@
+use crate::{CoordTrig, CoordType, ISG};
+
+/// Summary statistics of values, see [`ISG::stats`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Stats {
+    /// Number of values
+    pub count: usize,
+    /// Arithmetic mean
+    pub mean: f64,
+    /// Minimum value
+    pub min: f64,
+    /// Maximum value
+    pub max: f64,
+    /// Population standard deviation
+    pub std: f64,
+}
+
+/// Accumulates [`Stats`] in one pass (weighted Welford's algorithm).
+#[derive(Debug, Default)]
+pub(crate) struct Accumulator {
+    count: usize,
+    weight: f64,
+    mean: f64,
+    m2: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Accumulator {
+    #[inline]
+    pub(crate) fn push(&mut self, value: f64) {
+        self.push_weighted(value, 1.0)
+    }
+
+    /// Pushes `value` of non-negative `weight`.
+    pub(crate) fn push_weighted(&mut self, value: f64, weight: f64) {
+        if self.count == 0 {
+            self.min = value;
+            self.max = value;
+        } else {
+            self.min = self.min.min(value);
+            self.max = self.max.max(value);
+        }
+
+        self.count += 1;
+        if weight > 0.0 {
+            self.weight += weight;
+            let delta = value - self.mean;
+            self.mean += delta * weight / self.weight;
+            self.m2 += weight * delta * (value - self.mean);
+        }
+    }
+
+    /// Returns [`None`] when no value of positive weight is pushed.
+    pub(crate) fn finish(self) -> Option<Stats> {
+        (self.weight > 0.0).then(|| Stats {
+            count: self.count,
+            mean: self.mean,
+            min: self.min,
+            max: self.max,
+            std: (self.m2 / self.weight).sqrt(),
+        })
+    }
+}
+
+impl ISG {
+    /// Returns the number of nodata cells, always `0` for sparse.
+    #[inline]
+    pub fn nodata_count(&self) -> usize {
+        self.data.len() - self.data.values().count()
+    }
+
+    /// Returns the fraction of defined cells, from `0.0` to `1.0`.
+    ///
+    /// Returns `0.0` when data is empty.
+    ///
+    /// ```
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// assert_eq!(isg.nodata_count(), 4);
+    /// assert_eq!(isg.coverage(), 20.0 / 24.0);
+    /// ```
+    pub fn coverage(&self) -> f64 {
+        match self.data.len() {
+            0 => 0.0,
+            len => (len - self.nodata_count()) as f64 / len as f64,
+        }
+    }
+
+    /// Returns statistics of defined values, [`None`] when there is no defined value.
+    ///
+    /// Nodata cells are excluded, and `std` is the population standard deviation.
+    ///
+    /// ```
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// let stats = isg.stats().unwrap();
+    /// assert_eq!(stats.count, 20);
+    /// assert_eq!(stats.min, 30.1234);
+    /// assert_eq!(stats.max, 64.6666);
+    /// ```
+    pub fn stats(&self) -> Option<Stats> {
+        let mut acc = Accumulator::default();
+        self.data.values().for_each(|value| acc.push(value));
+        acc.finish()
+    }
+
+    /// Returns area-weighted statistics of defined values,
+    /// [`None`] when there is no defined value of positive weight.
+    ///
+    /// Each cell (or record) is weighted by the cosine of its latitude for geodetic,
+    /// which is proportional to the area of a cell, so rows near the poles are not over-weighted.
+    /// Cells of projected grids are equal in area, hence the result equals [`ISG::stats`].
+    ///
+    /// `mean` and `std` are weighted, while `count`, `min` and `max` are of all defined values.
+    ///
+    /// ```
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// let stats = isg.stats().unwrap();
+    /// let weighted = isg.weighted_stats().unwrap();
+    ///
+    /// // values increase toward the south, where cells are larger
+    /// assert!(weighted.mean > stats.mean);
+    /// assert_eq!(weighted.count, stats.count);
+    /// ```
+    pub fn weighted_stats(&self) -> Option<Stats> {
+        if !matches!(self.header.coord_type, CoordType::Geodetic) {
+            return self.stats();
+        }
+
+        let mut acc = Accumulator::default();
+        for (a, _, value) in self.cells() {
+            if let Some(value) = value {
+                acc.push_weighted(value, a.cos().max(0.0));
+            }
+        }
+        acc.finish()
+    }
+}
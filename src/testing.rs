@@ -0,0 +1,150 @@
+//! Test helpers.
+
+use std::fmt::{Debug, Display, Formatter};
+
+use crate::compare::{header_diff, variant};
+use crate::{Data, FieldDiff, ISG};
+
+/// Asserts that two [`ISG`]s are equal.
+///
+/// On failure, this panics with [`IsgDiff`] report,
+/// which lists differences of header fields and data cells,
+/// instead of dumping both values.
+///
+/// ```
+/// # use libisg::assert_isg_eq;
+/// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+/// assert_isg_eq!(isg, isg.clone());
+/// ```
+#[macro_export]
+macro_rules! assert_isg_eq {
+    ($left:expr, $right:expr $(,)?) => {{
+        let diff = $crate::IsgDiff::compare(&$left, &$right);
+        if !diff.is_empty() {
+            panic!("assertion `left == right` failed\n{}", diff);
+        }
+    }};
+}
+
+/// Structural difference of two [`ISG`]s.
+#[derive(Debug, Default, Eq, PartialEq, Clone)]
+pub struct IsgDiff {
+    /// `true` if comments differ
+    pub comment: bool,
+    /// Differences of header fields
+    pub header: Vec<FieldDiff>,
+    /// Differences of data cells
+    pub data: Vec<CellDiff>,
+}
+
+/// Difference of a data cell, see [`IsgDiff`].
+///
+/// For sparse data, `row` is the index of record
+/// and `col` is the column of record (from `0` to `2`).
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct CellDiff {
+    pub row: usize,
+    pub col: usize,
+    /// Left value (debug format), [`None`] if the cell does not exist
+    pub left: Option<String>,
+    /// Right value (debug format), [`None`] if the cell does not exist
+    pub right: Option<String>,
+}
+
+impl IsgDiff {
+    /// Compares two [`ISG`]s.
+    pub fn compare(left: &ISG, right: &ISG) -> Self {
+        let mut diff = Self {
+            comment: left.comment != right.comment,
+            header: header_diff(&left.header, &right.header),
+            data: Vec::new(),
+        };
+
+        match (&left.data, &right.data) {
+            (Data::Grid(a), Data::Grid(b)) => {
+                for row in 0..a.nrows().max(b.nrows()) {
+                    for col in 0..a.ncols().max(b.ncols()) {
+                        push_cell(&mut diff.data, row, col, a.get(row, col), b.get(row, col));
+                    }
+                }
+            }
+            (Data::Sparse(a), Data::Sparse(b)) => {
+                for row in 0..a.len().max(b.len()) {
+                    let (ra, rb) = (a.get(row), b.get(row));
+                    push_cell(&mut diff.data, row, 0, ra.map(|r| &r.0), rb.map(|r| &r.0));
+                    push_cell(&mut diff.data, row, 1, ra.map(|r| &r.1), rb.map(|r| &r.1));
+                    push_cell(&mut diff.data, row, 2, ra.map(|r| &r.2), rb.map(|r| &r.2));
+                }
+            }
+            (Data::Grid(_), Data::Sparse(_)) | (Data::Sparse(_), Data::Grid(_)) => {
+                diff.header.push(FieldDiff {
+                    field: "data",
+                    left: variant(&left.data).into(),
+                    right: variant(&right.data).into(),
+                });
+            }
+        }
+
+        diff
+    }
+
+    /// Returns `true` if no difference.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        !self.comment && self.header.is_empty() && self.data.is_empty()
+    }
+}
+
+#[inline]
+fn push_cell<T: PartialEq + Debug>(
+    diffs: &mut Vec<CellDiff>,
+    row: usize,
+    col: usize,
+    left: Option<&T>,
+    right: Option<&T>,
+) {
+    if left != right {
+        diffs.push(CellDiff {
+            row,
+            col,
+            left: left.map(|v| format!("{:?}", v)),
+            right: right.map(|v| format!("{:?}", v)),
+        });
+    }
+}
+
+/// Number of cells shown by [`Display`] impl of [`IsgDiff`]
+const MAX_CELLS: usize = 20;
+
+impl Display for IsgDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.comment {
+            writeln!(f, "comment differs")?;
+        }
+
+        for field in &self.header {
+            writeln!(
+                f,
+                "header `{}`: left: {}, right: {}",
+                field.field, field.left, field.right
+            )?;
+        }
+
+        for cell in self.data.iter().take(MAX_CELLS) {
+            writeln!(
+                f,
+                "data ({}, {}): left: {}, right: {}",
+                cell.row,
+                cell.col,
+                cell.left.as_deref().unwrap_or("(missing)"),
+                cell.right.as_deref().unwrap_or("(missing)"),
+            )?;
+        }
+
+        if self.data.len() > MAX_CELLS {
+            writeln!(f, "... and {} more cell(s)", self.data.len() - MAX_CELLS)?;
+        }
+
+        Ok(())
+    }
+}
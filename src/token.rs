@@ -4,6 +4,7 @@ use std::ops::Range;
 use std::str::{FromStr, Lines};
 
 use crate::error::ParseError;
+use crate::parse::HeaderField;
 
 const BEGIN_OF_HEAD: &str = "begin_of_head";
 const END_OF_HEADER: &str = "end_of_head";
@@ -66,6 +67,8 @@ pub(crate) struct Tokenizer<'a> {
     str: &'a str,
     lines: Peekable<Enumerate<Lines<'a>>>,
     lineno: usize,
+    /// `true` if the input is header lines only
+    header_only: bool,
 }
 
 #[derive(Debug)]
@@ -75,6 +78,33 @@ pub(crate) struct DataColumnIterator<'a> {
     pos: usize,
 }
 
+impl<'a> DataColumnIterator<'a> {
+    /// Returns the entire line
+    #[inline]
+    pub(crate) fn line(&self) -> &'a str {
+        self.line
+    }
+
+    #[inline]
+    pub(crate) fn lineno(&self) -> usize {
+        self.lineno
+    }
+
+    /// Returns `true` if the line is not data but recognized as
+    /// blank, `begin_of_head`, `end_of_head` or header line
+    pub(crate) fn is_stray(&self) -> bool {
+        let line = self.line.trim();
+        if line.is_empty() || line.starts_with(BEGIN_OF_HEAD) || line.starts_with(END_OF_HEADER) {
+            return true;
+        }
+
+        match line.find([':', '=']) {
+            None => false,
+            Some(pos) => line[..pos].trim().parse::<HeaderField>().is_ok(),
+        }
+    }
+}
+
 impl<'a> Iterator for DataColumnIterator<'a> {
     type Item = Token<'a>;
 
@@ -85,7 +115,7 @@ impl<'a> Iterator for DataColumnIterator<'a> {
 
         let mut found = false;
         let slice = &self.line[self.pos..];
-        for (columns, c) in slice.chars().enumerate() {
+        for (columns, c) in slice.char_indices() {
             match c {
                 ' ' => {
                     if found {
@@ -134,6 +164,22 @@ impl<'a> Tokenizer<'a> {
             str: s,
             lines: s.lines().enumerate().peekable(),
             lineno: 1,
+            header_only: false,
+        }
+    }
+
+    /// Returns the length of the whole input in bytes
+    #[inline]
+    pub(crate) fn input_len(&self) -> usize {
+        self.str.len()
+    }
+
+    /// Tokenizer for lines between `begin_of_head` and `end_of_head`
+    #[inline]
+    pub(crate) fn with_header_only(s: &'a str) -> Self {
+        Self {
+            header_only: true,
+            ..Self::new(s)
         }
     }
 
@@ -195,6 +241,7 @@ impl<'a> Tokenizer<'a> {
         // Not consume lines,
         // because we need to not consume the `end_of_head` line
         match self.lines.peek() {
+            None if self.header_only => Ok(None),
             None => Err(ParseError::missing_eoh()),
             // Returns `Ok(None)` when header ends
             Some((_, line)) if line.starts_with(END_OF_HEADER) => Ok(None),
@@ -260,6 +307,26 @@ impl<'a> Tokenizer<'a> {
         }
     }
 
+    /// Consumes the next line and returns it with line number
+    /// when it is a free-text line in the header section,
+    /// that is, a line without `=` which is not a header line (e.g. `model name : EXAMPLE`)
+    #[inline]
+    pub(crate) fn tokenize_stray_header(&mut self) -> Option<(&'a str, usize)> {
+        match self.lines.peek() {
+            Some(&(_, line)) if !line.starts_with(END_OF_HEADER) && !line.contains('=') => {
+                let is_header = line.find(':').map_or(false, |pos| {
+                    line[..pos].trim().parse::<HeaderField>().is_ok()
+                });
+                if is_header {
+                    return None;
+                }
+
+                self.lines.next().map(|(lineno, line)| (line, lineno + 1))
+            }
+            _ => None,
+        }
+    }
+
     #[inline]
     pub(crate) fn tokenize_end_of_header(&mut self) -> Result<Token<'a>, ParseError> {
         match self.lines.next() {
@@ -281,7 +348,7 @@ impl<'a> Tokenizer<'a> {
     }
 
     #[inline]
-    pub(crate) fn tokenize_data(&mut self) -> Option<DataColumnIterator> {
+    pub(crate) fn tokenize_data(&mut self) -> Option<DataColumnIterator<'_>> {
         // Returns `None` when data ends
         self.lines.next().map(|(lineno, line)| DataColumnIterator {
             line,
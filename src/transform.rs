@@ -0,0 +1,409 @@
+use crate::error::{CoordError, MismatchError};
+use crate::geometry::{flip_index, shift_lon};
+use crate::ordering::grid_flips_or_default;
+use crate::{Coord, CoordUnits, Data, DataBounds, DataOrdering, DataUnits, Grid, ISG};
+
+/// Convention of longitude, see [`ISG::normalize_longitudes`]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Convention {
+    /// `[-180, 180]`
+    Signed180,
+    /// `[0, 360]`
+    Unsigned360,
+}
+
+/// Rounding of decimal degrees into DMS,
+/// see [`ISG::convert_coord_units_with`] and [`HeaderBuilder::dms_rounding`](crate::HeaderBuilder::dms_rounding).
+///
+/// A value within 1e-6 second of a whole second is taken as exact,
+/// so that float errors, e.g. of `39.0 + 50.0 / 60.0`, do not matter.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum DmsRounding {
+    /// Rounds to the nearest second, half away from zero (default)
+    Nearest,
+    /// Truncates toward zero, which never moves a coordinate away from zero
+    Truncate,
+    /// Fails on a value which is not a whole second
+    Exact,
+}
+
+impl Default for DmsRounding {
+    #[inline]
+    fn default() -> Self {
+        Self::Nearest
+    }
+}
+
+impl ISG {
+    /// Returns a new [`ISG`] of which defined values are mapped by `f`.
+    ///
+    /// Header, comment and shape are preserved, and nodata cells are untouched.
+    ///
+    /// ```
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// // meters to centimeters
+    /// let cm = isg.map_values(|v| v * 100.0);
+    /// assert_eq!(cm.data[(0, 0)], Some(3012.34));
+    /// assert_eq!(cm.data[(3, 5)], None);
+    /// ```
+    pub fn map_values<F>(&self, f: F) -> ISG
+    where
+        F: FnMut(f64) -> f64,
+    {
+        let mut isg = self.clone();
+        isg.map_values_in_place(f);
+        isg
+    }
+
+    /// Maps defined values by `f` in place, see [`ISG::map_values`].
+    pub fn map_values_in_place<F>(&mut self, mut f: F)
+    where
+        F: FnMut(f64) -> f64,
+    {
+        match &mut self.data {
+            Data::Grid(data) => data
+                .as_mut_slice()
+                .iter_mut()
+                .flatten()
+                .for_each(|v| *v = f(*v)),
+            Data::Sparse(data) => data.iter_mut().for_each(|(_, _, v)| *v = f(*v)),
+        }
+    }
+
+    /// Applies `scale * value + offset` to defined values in place,
+    /// nodata cells are untouched.
+    ///
+    /// `data units` is updated when `data_units` is [`Some`].
+    ///
+    /// ```
+    /// # use libisg::DataUnits;
+    /// # let mut isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// // meters to feet
+    /// isg.apply_linear(1.0 / 0.3048, 0.0, Some(DataUnits::Feet));
+    /// assert_eq!(isg.header.data_units, Some(DataUnits::Feet));
+    /// ```
+    pub fn apply_linear(&mut self, scale: f64, offset: f64, data_units: Option<DataUnits>) {
+        self.map_values_in_place(|v| scale * v + offset);
+
+        if data_units.is_some() {
+            self.header.data_units = data_units;
+        }
+    }
+
+    /// Converts `data bounds` and sparse coordinates into `coord_units`,
+    /// and updates `coord units`.
+    ///
+    /// Supported conversions are between `dms` and `deg`, and between `meters` and `feet`
+    /// (international foot, 0.3048 m).
+    /// On conversion into `dms`, values are rounded to the nearest second,
+    /// see [`ISG::convert_coord_units_with`] for other rounding.
+    ///
+    /// Returns `false` and does nothing when the conversion is not supported,
+    /// or a value is not representable in `dms`, e.g. non-finite.
+    ///
+    /// ```
+    /// # use libisg::{Coord, CoordUnits, DataBounds};
+    /// # let mut isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// assert!(isg.convert_coord_units(CoordUnits::Deg));
+    /// assert!(matches!(
+    ///     isg.header.data_bounds,
+    ///     DataBounds::GridGeodetic { lat_max, .. } if lat_max == Coord::with_dec(41.0 + 10.0 / 60.0)
+    /// ));
+    ///
+    /// assert!(!isg.convert_coord_units(CoordUnits::Meters));
+    /// ```
+    pub fn convert_coord_units(&mut self, coord_units: CoordUnits) -> bool {
+        self.convert_coord_units_with(coord_units, DmsRounding::Nearest)
+            .unwrap_or(false)
+    }
+
+    /// Converts `data bounds` and sparse coordinates into `coord_units` with `rounding` into `dms`,
+    /// and updates `coord units`, see [`ISG::convert_coord_units`].
+    ///
+    /// Returns `Ok(false)` and does nothing when the conversion is not supported.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] and does nothing when a value is not representable in `dms` by `rounding`.
+    ///
+    /// ```
+    /// # use libisg::{Coord, CoordUnits, DataBounds, DmsRounding};
+    /// # let mut isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.2.isg").unwrap()).unwrap();
+    /// // `lon max` is 121.666667, 121°40'00.0012"
+    /// assert!(isg.clone().convert_coord_units_with(CoordUnits::DMS, DmsRounding::Exact).is_err());
+    ///
+    /// assert_eq!(isg.convert_coord_units_with(CoordUnits::DMS, DmsRounding::Truncate), Ok(true));
+    /// assert!(matches!(
+    ///     isg.header.data_bounds,
+    ///     DataBounds::GridGeodetic { lon_max, .. } if lon_max == Coord::with_dms(121, 40, 0)
+    /// ));
+    /// ```
+    pub fn convert_coord_units_with(
+        &mut self,
+        coord_units: CoordUnits,
+        rounding: DmsRounding,
+    ) -> Result<bool, CoordError> {
+        let convert: fn(&Coord, DmsRounding) -> Option<Coord> =
+            match (&self.header.coord_units, &coord_units) {
+                (CoordUnits::DMS, CoordUnits::DMS)
+                | (CoordUnits::Deg, CoordUnits::Deg)
+                | (CoordUnits::Meters, CoordUnits::Meters)
+                | (CoordUnits::Feet, CoordUnits::Feet) => return Ok(true),
+                (CoordUnits::DMS, CoordUnits::Deg) => |c, _| Some(Coord::Dec(c.as_f64())),
+                (CoordUnits::Deg, CoordUnits::DMS) => {
+                    |c, rounding| Coord::dms_from_f64_with(c.as_f64(), rounding)
+                }
+                (CoordUnits::Meters, CoordUnits::Feet) => {
+                    |c, _| Some(Coord::Dec(c.as_f64() / 0.3048))
+                }
+                (CoordUnits::Feet, CoordUnits::Meters) => {
+                    |c, _| Some(Coord::Dec(c.as_f64() * 0.3048))
+                }
+                _ => return Ok(false),
+            };
+
+        // converts all before updating, so that `self` is untouched on error
+        let mut err = None;
+        let mut convert = |c: &Coord| match convert(c, rounding) {
+            Some(coord) => coord,
+            None => {
+                err.get_or_insert_with(|| CoordError::unrepresentable(c.as_f64()));
+                *c
+            }
+        };
+
+        let mut data_bounds = self.header.data_bounds.clone();
+        map_bounds(&mut data_bounds, &mut convert);
+        let coords: Option<Vec<_>> = match &self.data {
+            Data::Grid(_) => None,
+            Data::Sparse(data) => Some(
+                data.iter()
+                    .map(|(a, b, _)| (convert(a), convert(b)))
+                    .collect(),
+            ),
+        };
+        if let Some(err) = err {
+            return Err(err);
+        }
+
+        self.header.data_bounds = data_bounds;
+        if let (Data::Sparse(data), Some(coords)) = (&mut self.data, coords) {
+            for ((a, b, _), (a_, b_)) in data.iter_mut().zip(coords) {
+                *a = a_;
+                *b = b_;
+            }
+        }
+        self.header.coord_units = coord_units;
+
+        Ok(true)
+    }
+
+    /// Converts longitudes of `data bounds` and sparse coordinates into `convention`.
+    ///
+    /// Grid data is not moved, so a grid may become crossing the antimeridian
+    /// (`lon max` is less than `lon min`) or the prime meridian.
+    /// Returns `false` and does nothing when `self` is projected.
+    ///
+    /// ```
+    /// # use libisg::{Convention, Coord, CoordUnits, DataBounds};
+    /// # let mut isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// # isg.convert_coord_units(CoordUnits::Deg);
+    /// # if let DataBounds::GridGeodetic { lon_min, lon_max, .. } = &mut isg.header.data_bounds {
+    /// #     *lon_min = Coord::with_dec(190.0);
+    /// #     *lon_max = Coord::with_dec(200.0);
+    /// # }
+    /// assert!(isg.normalize_longitudes(Convention::Signed180));
+    /// assert!(matches!(
+    ///     isg.header.data_bounds,
+    ///     DataBounds::GridGeodetic { lon_min, lon_max, .. }
+    ///         if lon_min == Coord::with_dec(-170.0) && lon_max == Coord::with_dec(-160.0)
+    /// ));
+    /// ```
+    pub fn normalize_longitudes(&mut self, convention: Convention) -> bool {
+        let normalize = |lon: &mut Coord| {
+            let value = lon.as_f64();
+            let turns = match convention {
+                Convention::Signed180 if !(-180.0..=180.0).contains(&value) => {
+                    -((value + 180.0) / 360.0).floor() as i32
+                }
+                Convention::Unsigned360 if !(0.0..=360.0).contains(&value) => {
+                    -(value / 360.0).floor() as i32
+                }
+                _ => 0,
+            };
+            *lon = shift_lon(lon, turns);
+        };
+
+        match &mut self.header.data_bounds {
+            DataBounds::GridGeodetic {
+                lon_min, lon_max, ..
+            }
+            | DataBounds::SparseGeodetic {
+                lon_min, lon_max, ..
+            } => {
+                normalize(lon_min);
+                normalize(lon_max);
+            }
+            DataBounds::GridProjected { .. } | DataBounds::SparseProjected { .. } => return false,
+        }
+
+        if let Data::Sparse(data) = &mut self.data {
+            let swap = matches!(self.header.data_ordering, Some(DataOrdering::EastNorthN));
+            for (a, b, _) in data.iter_mut() {
+                normalize(if swap { a } else { b });
+            }
+        }
+
+        true
+    }
+
+    /// Returns cell-wise difference, `self - other`.
+    ///
+    /// Two grids must share `data bounds`, shape, `coord units` and `data units`,
+    /// and a cell is nodata if it is nodata in either grid.
+    /// Cells are matched by coordinate, so `data ordering` may differ.
+    /// Header and comment are copied from `self`.
+    ///
+    /// ```
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// let biased = isg.map_values(|v| v + 0.5);
+    /// let diff = biased.difference(&isg).unwrap();
+    /// assert!(diff.data.values().all(|v| (v - 0.5).abs() < 1e-9));
+    /// ```
+    pub fn difference(&self, other: &ISG) -> Result<ISG, MismatchError> {
+        self.zip_with(other, |a, b| a - b)
+    }
+
+    /// Returns cell-wise sum, `self + other`, see [`ISG::difference`].
+    pub fn add(&self, other: &ISG) -> Result<ISG, MismatchError> {
+        self.zip_with(other, |a, b| a + b)
+    }
+
+    fn zip_with<F>(&self, other: &ISG, mut f: F) -> Result<ISG, MismatchError>
+    where
+        F: FnMut(f64, f64) -> f64,
+    {
+        check_compatible(self, other)?;
+
+        let (a, b) = match (&self.data, &other.data) {
+            (Data::Grid(a), Data::Grid(b)) => (a, b),
+            _ => unreachable!(),
+        };
+
+        // `other` is read in `data ordering` of `self`
+        let (rows_a, cols_a) = grid_flips_or_default(self.header.data_ordering);
+        let (rows_b, cols_b) = grid_flips_or_default(other.header.data_ordering);
+        let flips = (rows_a != rows_b, cols_a != cols_b);
+        let shape = (a.nrows(), a.ncols());
+
+        let values = a
+            .as_slice()
+            .iter()
+            .enumerate()
+            .map(|(i, value)| {
+                // never fails, in bounds of the same shape
+                let (row, col) = flip_index((i / shape.1, i % shape.1), shape, flips).unwrap();
+                match (value, b.as_slice()[row * shape.1 + col]) {
+                    (Some(a), Some(b)) => Some(f(*a, b)),
+                    _ => None,
+                }
+            })
+            .collect();
+
+        Ok(ISG {
+            comment: self.comment.clone(),
+            header: self.header.clone(),
+            data: Data::Grid(Grid::from_vec_unchecked(a.nrows(), a.ncols(), values)),
+        })
+    }
+}
+
+/// Checks two grids share `data bounds`, shape, `coord units` and `data units`.
+pub(crate) fn check_compatible(a: &ISG, b: &ISG) -> Result<(), MismatchError> {
+    let (grid_a, grid_b) = match (&a.data, &b.data) {
+        (Data::Grid(a), Data::Grid(b)) => (a, b),
+        _ => return Err(MismatchError::not_grid()),
+    };
+
+    if a.header.coord_units != b.header.coord_units {
+        return Err(MismatchError::coord_units());
+    }
+
+    if a.header.data_units != b.header.data_units {
+        return Err(MismatchError::data_units());
+    }
+
+    if a.header.data_bounds != b.header.data_bounds {
+        return Err(MismatchError::data_bounds());
+    }
+
+    let (shape_a, shape_b) = (
+        (grid_a.nrows(), grid_a.ncols()),
+        (grid_b.nrows(), grid_b.ncols()),
+    );
+    if shape_a != shape_b {
+        return Err(MismatchError::shape(shape_a, shape_b));
+    }
+
+    Ok(())
+}
+
+/// Maps all coordinates of `bounds` by `f`.
+pub(crate) fn map_bounds<F>(bounds: &mut DataBounds, mut f: F)
+where
+    F: FnMut(&Coord) -> Coord,
+{
+    match bounds {
+        DataBounds::GridGeodetic {
+            lat_min,
+            lat_max,
+            lon_min,
+            lon_max,
+            delta_lat,
+            delta_lon,
+        } => {
+            for c in [lat_min, lat_max, lon_min, lon_max, delta_lat, delta_lon] {
+                *c = f(c);
+            }
+        }
+        DataBounds::GridProjected {
+            north_min,
+            north_max,
+            east_min,
+            east_max,
+            delta_north,
+            delta_east,
+        } => {
+            for c in [
+                north_min,
+                north_max,
+                east_min,
+                east_max,
+                delta_north,
+                delta_east,
+            ] {
+                *c = f(c);
+            }
+        }
+        DataBounds::SparseGeodetic {
+            lat_min,
+            lat_max,
+            lon_min,
+            lon_max,
+        } => {
+            for c in [lat_min, lat_max, lon_min, lon_max] {
+                *c = f(c);
+            }
+        }
+        DataBounds::SparseProjected {
+            north_min,
+            north_max,
+            east_min,
+            east_max,
+        } => {
+            for c in [north_min, north_max, east_min, east_max] {
+                *c = f(c);
+            }
+        }
+    }
+}
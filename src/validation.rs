@@ -14,10 +14,19 @@ impl ISG {
     /// Validate adaptation to ISG-format
     ///
     /// This checks:
+    /// - no line of `comment` starts with `begin_of_head`
     /// - `data_bounds` by `data_format` and `coord_type`
     /// - data format of `data_bounds` and data by `coord_units`
+    /// - `nrows` and `ncols` of grid do not exceed `data_bounds`
     /// - data length by `nrows` and `ncols`
     pub fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(lineno) = self
+            .comment_lines()
+            .position(|line| line.starts_with("begin_of_head"))
+        {
+            return Err(ValidationError::comment(lineno + 1));
+        }
+
         self.header.validate()?;
         self.data.validate(&self.header)?;
 
@@ -27,7 +36,7 @@ impl ISG {
 
 impl Header {
     #[inline]
-    fn validate(&self) -> Result<(), ValidationError> {
+    pub(crate) fn validate(&self) -> Result<(), ValidationError> {
         if self.ISG_format != "2.0" {
             return Err(ValidationError::isg_format());
         }
@@ -153,6 +162,13 @@ impl Header {
             }
         };
 
+        if matches!(self.data_format, DataFormat::Grid) {
+            match self.data_bounds.max_shape(&self.coord_units) {
+                Some((nrows, ncols)) if self.nrows <= nrows && self.ncols <= ncols => {}
+                _ => return Err(ValidationError::shape(self.nrows, self.ncols)),
+            }
+        }
+
         Ok(())
     }
 }
@@ -169,14 +185,12 @@ impl Data {
 
         match &self {
             Data::Grid(data) => {
-                if data.len() != header.nrows {
-                    return Err(ValidationError::nrows(header.nrows, data.len()));
+                if data.nrows() != header.nrows {
+                    return Err(ValidationError::nrows(header.nrows, data.nrows()));
                 }
 
-                for row in data {
-                    if row.len() != header.ncols {
-                        return Err(ValidationError::ncols(header.ncols, Some(row.len())));
-                    }
+                if data.nrows() != 0 && data.ncols() != header.ncols {
+                    return Err(ValidationError::ncols(header.ncols, Some(data.ncols())));
                 }
             }
             Data::Sparse(data) => {
@@ -0,0 +1,279 @@
+use std::ops::Range;
+
+use crate::geometry::{is_signed, wrap_lon};
+use crate::ordering::grid_flips_or_default;
+use crate::{Data, DataBounds, Grid, Header, ISG};
+
+/// Borrowed rectangular region of grid data, see [`ISG::view`].
+///
+/// The view does not copy values, and has its own `data bounds`.
+#[derive(Debug, Clone)]
+pub struct GridView<'a> {
+    header: &'a Header,
+    grid: &'a Grid,
+    rows: Range<usize>,
+    cols: Range<usize>,
+    data_bounds: DataBounds,
+}
+
+impl ISG {
+    /// Returns a view of the region of `rows` × `cols`.
+    ///
+    /// Returns [`None`] when `self` is sparse or the region is empty or out of bounds.
+    ///
+    /// ```
+    /// # use libisg::{Coord, DataBounds};
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// let view = isg.view(1..3, 2..6).unwrap();
+    ///
+    /// assert_eq!((view.nrows(), view.ncols()), (2, 4));
+    /// assert_eq!(view.get(0, 0), isg.data.get(1, 2));
+    /// assert!(matches!(
+    ///     view.data_bounds(),
+    ///     DataBounds::GridGeodetic { lat_max, lon_min, .. }
+    ///         if *lat_max == Coord::with_dms(40, 50, 0) && *lon_min == Coord::with_dms(120, 30, 0)
+    /// ));
+    /// ```
+    pub fn view(&self, rows: Range<usize>, cols: Range<usize>) -> Option<GridView<'_>> {
+        let grid = match &self.data {
+            Data::Grid(grid) => grid,
+            Data::Sparse(_) => return None,
+        };
+
+        if rows.start >= rows.end
+            || cols.start >= cols.end
+            || rows.end > grid.nrows()
+            || cols.end > grid.ncols()
+        {
+            return None;
+        }
+
+        let data_bounds = sub_bounds(&self.header, &rows, &cols)?;
+
+        Some(GridView {
+            header: &self.header,
+            grid,
+            rows,
+            cols,
+            data_bounds,
+        })
+    }
+
+    /// Returns a new [`ISG`] of the region of `rows` × `cols`,
+    /// of which `data bounds`, `nrows` and `ncols` are recomputed.
+    ///
+    /// The comment and the other header fields are copied from `self`.
+    /// Returns [`None`] when `self` is sparse or the region is empty or out of bounds,
+    /// see [`ISG::view`] to avoid copying.
+    ///
+    /// ```
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// let sub = isg.subgrid(2..4, 0..4).unwrap();
+    ///
+    /// assert_eq!((sub.header.nrows, sub.header.ncols), (2, 4));
+    /// assert_eq!(sub.data[(0, 0)], isg.data[(2, 0)]);
+    /// assert_eq!(sub.nodata_count(), 0);
+    ///
+    /// assert!(isg.subgrid(2..5, 0..4).is_none());
+    /// ```
+    pub fn subgrid(&self, rows: Range<usize>, cols: Range<usize>) -> Option<ISG> {
+        self.view(rows, cols).map(|view| ISG {
+            comment: self.comment.clone(),
+            ..view.to_isg()
+        })
+    }
+
+    /// Returns an iterator of views of tiles in row-major order,
+    /// each of which has `tile_rows` × `tile_cols` cells at most.
+    ///
+    /// Tiles on the south and east edges are smaller if the shape is not divisible.
+    /// This yields nothing when `self` is sparse.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `tile_rows` or `tile_cols` is `0`.
+    ///
+    /// ```
+    /// # let isg = libisg::from_str(&std::fs::read_to_string("rsc/isg/example.1.isg").unwrap()).unwrap();
+    /// let shapes: Vec<_> = isg.tiles(3, 4).map(|view| (view.nrows(), view.ncols())).collect();
+    /// assert_eq!(shapes, vec![(3, 4), (3, 2), (1, 4), (1, 2)]);
+    /// ```
+    pub fn tiles(&self, tile_rows: usize, tile_cols: usize) -> impl Iterator<Item = GridView<'_>> {
+        assert!(
+            tile_rows != 0 && tile_cols != 0,
+            "tile size must be non-zero"
+        );
+
+        let (nrows, ncols) = match &self.data {
+            Data::Grid(grid) => (grid.nrows(), grid.ncols()),
+            Data::Sparse(_) => (0, 0),
+        };
+
+        (0..nrows).step_by(tile_rows).flat_map(move |row| {
+            (0..ncols).step_by(tile_cols).filter_map(move |col| {
+                self.view(
+                    row..(row + tile_rows).min(nrows),
+                    col..(col + tile_cols).min(ncols),
+                )
+            })
+        })
+    }
+}
+
+impl<'a> GridView<'a> {
+    /// Returns the view of the whole `grid`.
+    #[inline]
+    pub(crate) fn whole(header: &'a Header, grid: &'a Grid) -> Self {
+        Self {
+            header,
+            grid,
+            rows: 0..grid.nrows(),
+            cols: 0..grid.ncols(),
+            data_bounds: header.data_bounds.clone(),
+        }
+    }
+
+    /// Returns the number of rows.
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns the number of columns.
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.cols.len()
+    }
+
+    /// Returns `(row, col)` of the first cell in the original grid.
+    #[inline]
+    pub fn offset(&self) -> (usize, usize) {
+        (self.rows.start, self.cols.start)
+    }
+
+    /// Returns `data bounds` of the view.
+    #[inline]
+    pub fn data_bounds(&self) -> &DataBounds {
+        &self.data_bounds
+    }
+
+    /// Returns a reference to the value at (`row`, `col`) of the view,
+    /// [`None`] when out of bounds.
+    #[inline]
+    pub fn get(&self, row: usize, col: usize) -> Option<&'a Option<f64>> {
+        if row < self.nrows() && col < self.ncols() {
+            self.grid.get(self.rows.start + row, self.cols.start + col)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the `i`-th row of the view, [`None`] when out of bounds.
+    #[inline]
+    pub fn row(&self, i: usize) -> Option<&'a [Option<f64>]> {
+        if i < self.nrows() {
+            self.grid
+                .row(self.rows.start + i)
+                .map(|row| &row[self.cols.clone()])
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator of rows of the view.
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = &'a [Option<f64>]> + DoubleEndedIterator {
+        let grid = self.grid;
+        let cols = self.cols.clone();
+        self.rows.clone().map(move |i| {
+            // in bounds, checked on construction
+            &grid.row(i).unwrap()[cols.clone()]
+        })
+    }
+
+    /// Copies the view into a new [`ISG`], of which comment is empty.
+    ///
+    /// The header is copied from the original except `data bounds`, `nrows` and `ncols`.
+    pub fn to_isg(&self) -> ISG {
+        let mut header = self.header.clone();
+        header.data_bounds = self.data_bounds.clone();
+        header.nrows = self.nrows();
+        header.ncols = self.ncols();
+
+        let values = self.rows().flatten().copied().collect();
+
+        ISG {
+            comment: String::new(),
+            header,
+            data: Data::Grid(Grid::from_vec_unchecked(self.nrows(), self.ncols(), values)),
+        }
+    }
+}
+
+/// Returns `data bounds` of the region of `rows` × `cols`.
+///
+/// Bounds keep the margin between the outermost cells and the original bounds,
+/// so the whole region has the same bounds as `header`.
+/// `rows` and `cols` are of data, so they are flipped by `data ordering`.
+pub(crate) fn sub_bounds(
+    header: &Header,
+    rows: &Range<usize>,
+    cols: &Range<usize>,
+) -> Option<DataBounds> {
+    let (nrows, ncols) = (header.nrows, header.ncols);
+    if rows.end > nrows || cols.end > ncols {
+        return None;
+    }
+
+    // into rows from `a max` and columns from `b min`
+    let (flip_rows, flip_cols) = grid_flips_or_default(header.data_ordering);
+    let rows = &if flip_rows {
+        nrows - rows.end..nrows - rows.start
+    } else {
+        rows.clone()
+    };
+    let cols = &if flip_cols {
+        ncols - cols.end..ncols - cols.start
+    } else {
+        cols.clone()
+    };
+
+    match &header.data_bounds {
+        DataBounds::GridGeodetic {
+            lat_min,
+            lat_max,
+            lon_min,
+            lon_max,
+            delta_lat,
+            delta_lon,
+        } => Some(DataBounds::GridGeodetic {
+            lat_min: lat_min + delta_lat * (nrows - rows.end),
+            lat_max: lat_max - delta_lat * rows.start,
+            lon_min: wrap_lon(
+                lon_min + delta_lon * cols.start,
+                is_signed(lon_min, lon_max),
+            ),
+            lon_max: wrap_lon(
+                lon_max - delta_lon * (ncols - cols.end),
+                is_signed(lon_min, lon_max),
+            ),
+            delta_lat: *delta_lat,
+            delta_lon: *delta_lon,
+        }),
+        DataBounds::GridProjected {
+            north_min,
+            north_max,
+            east_min,
+            east_max,
+            delta_north,
+            delta_east,
+        } => Some(DataBounds::GridProjected {
+            north_min: north_min + delta_north * (nrows - rows.end),
+            north_max: north_max - delta_north * rows.start,
+            east_min: east_min + delta_east * cols.start,
+            east_max: east_max - delta_east * (ncols - cols.end),
+            delta_north: *delta_north,
+            delta_east: *delta_east,
+        }),
+        DataBounds::SparseGeodetic { .. } | DataBounds::SparseProjected { .. } => None,
+    }
+}
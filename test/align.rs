@@ -0,0 +1,50 @@
+use std::fs;
+
+use libisg::{
+    from_str, CoordType, CoordUnits, DataOrdering, DataUnits, Method, Misalignment, Registration,
+};
+
+#[test]
+fn is_aligned_with() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    assert_eq!(isg.is_aligned_with(&isg), Ok(()));
+    // extents may differ
+    for (rows, cols) in [(0..2, 0..3), (1..4, 3..6), (3..4, 5..6)] {
+        let sub = isg.subgrid(rows, cols).unwrap();
+        assert_eq!(isg.is_aligned_with(&sub), Ok(()));
+        assert_eq!(sub.is_aligned_with(&isg), Ok(()));
+    }
+
+    let mut other = isg.clone();
+    assert!(other.shift_registration(Registration::CellCenters));
+    assert_eq!(isg.is_aligned_with(&other), Err(Misalignment::Origin));
+
+    let mut other = isg.clone();
+    assert!(other.reorder(DataOrdering::S2NW2E));
+    assert_eq!(isg.is_aligned_with(&other), Err(Misalignment::DataOrdering));
+    other.header.data_ordering = None;
+    assert!(other.reorder(DataOrdering::N2SW2E));
+    assert_eq!(isg.is_aligned_with(&other), Ok(()));
+
+    let mut other = isg.clone();
+    other.header.data_units = Some(DataUnits::Feet);
+    assert_eq!(isg.is_aligned_with(&other), Err(Misalignment::DataUnits));
+
+    let mut other = isg.clone();
+    assert!(other.convert_coord_units(CoordUnits::Deg));
+    assert_eq!(isg.is_aligned_with(&other), Err(Misalignment::CoordUnits));
+
+    let mut other = isg.clone();
+    other.header.coord_type = CoordType::Projected;
+    assert_eq!(isg.is_aligned_with(&other), Err(Misalignment::CoordType));
+
+    let fine = isg.upsample(2, Method::Bilinear).unwrap();
+    assert_eq!(isg.is_aligned_with(&fine), Err(Misalignment::Delta));
+    assert_eq!(Misalignment::Delta.to_string(), "delta mismatch");
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let sparse = from_str(&s).unwrap();
+    assert_eq!(isg.is_aligned_with(&sparse), Err(Misalignment::NotGrid));
+}
@@ -0,0 +1,351 @@
+use std::fs;
+
+use libisg::{
+    from_str, Coord, CoordType, CoordUnits, Data, DataBounds, ReconcilePolicy, Registration,
+};
+
+#[test]
+fn recompute_bounds() {
+    // the registration is kept
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    let expected = isg.header.data_bounds.clone();
+    assert!(isg.recompute_bounds());
+    assert_eq!(isg.header.data_bounds, expected);
+
+    if let DataBounds::GridGeodetic { lon_max, .. } = &mut isg.header.data_bounds {
+        *lon_max = Coord::with_dms(125, 0, 0);
+    }
+    assert!(isg.recompute_bounds());
+    assert_eq!(isg.header.data_bounds, expected);
+
+    let s = fs::read_to_string("rsc/isg/example.2.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    if let DataBounds::GridGeodetic { lat_min, .. } = &mut isg.header.data_bounds {
+        *lat_min = Coord::Dec(0.0);
+    }
+    assert!(isg.recompute_bounds());
+    assert!((isg.extent().south - 40.0).abs() < 1e-5);
+    assert_eq!(isg.header.registration(), Some(Registration::CellCenters));
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    let expected = isg.header.data_bounds.clone();
+    isg.header.data_bounds = DataBounds::SparseGeodetic {
+        lat_min: Coord::Dec(0.0),
+        lat_max: Coord::Dec(0.0),
+        lon_min: Coord::Dec(0.0),
+        lon_max: Coord::Dec(0.0),
+    };
+    assert!(isg.recompute_bounds());
+    assert_eq!(isg.header.data_bounds, expected);
+
+    isg.data = Data::Sparse(Vec::new());
+    assert!(!isg.recompute_bounds());
+}
+
+#[test]
+fn reconcile_header() {
+    let s = fs::read_to_string("rsc/isg/example.2.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    assert!(isg
+        .reconcile_header(ReconcilePolicy::TrustDeltas)
+        .is_empty());
+
+    isg.header.nrows = 5;
+    let report = isg.reconcile_header(ReconcilePolicy::TrustBounds);
+    assert_eq!(report.nrows, Some((5, 4)));
+    assert_eq!(report.ncols, None);
+    assert_eq!(report.data_bounds, None);
+    assert_eq!(isg.header.nrows, 4);
+
+    // reconciles to itself in the registration of the header
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let expected = from_str(&s).unwrap();
+    for policy in [ReconcilePolicy::TrustDeltas, ReconcilePolicy::TrustBounds] {
+        let mut isg = expected.clone();
+        assert!(isg.reconcile_header(policy).is_empty());
+        assert_eq!(isg, expected);
+    }
+
+    let mut isg = expected.clone();
+    if let DataBounds::GridGeodetic { lat_min, .. } = &mut isg.header.data_bounds {
+        *lat_min = Coord::with_dms(40, 0, 0);
+    }
+    let old = isg.header.data_bounds.clone();
+    let report = isg.reconcile_header(ReconcilePolicy::TrustDeltas);
+    assert_eq!(
+        report.data_bounds,
+        Some((old, expected.header.data_bounds.clone()))
+    );
+    assert_eq!(isg, expected);
+
+    let mut isg = expected.clone();
+    if let DataBounds::GridGeodetic { delta_lon, .. } = &mut isg.header.data_bounds {
+        *delta_lon = Coord::with_dms(0, 30, 0);
+    }
+    assert!(!isg
+        .reconcile_header(ReconcilePolicy::TrustBounds)
+        .is_empty());
+    assert_eq!(isg, expected);
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    assert!(isg
+        .reconcile_header(ReconcilePolicy::TrustBounds)
+        .is_empty());
+}
+
+#[test]
+fn accessors() {
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    let bounds = &isg.header.data_bounds;
+    assert_eq!(bounds.a_min(), Coord::Dec(40.0));
+    assert_eq!(bounds.a_max(), Coord::Dec(41.0));
+    assert_eq!(bounds.b_min(), Coord::Dec(120.0));
+    assert_eq!(bounds.delta_a(), None);
+    assert_eq!(bounds.delta_b(), None);
+
+    let bounds = DataBounds::GridProjected {
+        north_min: Coord::Dec(100.0),
+        north_max: Coord::Dec(200.0),
+        east_min: Coord::Dec(300.0),
+        east_max: Coord::Dec(400.0),
+        delta_north: Coord::Dec(10.0),
+        delta_east: Coord::Dec(20.0),
+    };
+    assert_eq!(bounds.a_min(), Coord::Dec(100.0));
+    assert_eq!(bounds.a_max(), Coord::Dec(200.0));
+    assert_eq!(bounds.b_min(), Coord::Dec(300.0));
+    assert_eq!(bounds.b_max(), Coord::Dec(400.0));
+    assert_eq!(bounds.delta_a(), Some(Coord::Dec(10.0)));
+    assert_eq!(bounds.delta_b(), Some(Coord::Dec(20.0)));
+}
+
+#[test]
+fn expected_shape() {
+    let s = fs::read_to_string("rsc/isg/example.2.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    let bounds = &isg.header.data_bounds;
+    assert_eq!(bounds.expected_shape(&CoordUnits::Deg), Some((4, 6)));
+    assert_eq!(bounds.expected_shape(&CoordUnits::DMS), None);
+    assert!(isg.validate().is_ok());
+
+    isg.header.data_bounds = DataBounds::GridGeodetic {
+        lat_min: Coord::Dec(40.0),
+        lat_max: Coord::Dec(40.5),
+        lon_min: Coord::Dec(120.0),
+        lon_max: Coord::Dec(121.7),
+        delta_lat: Coord::Dec(1.0 / 3.0),
+        delta_lon: Coord::Dec(1.0 / 3.0),
+    };
+    let bounds = &isg.header.data_bounds;
+    assert_eq!(bounds.expected_shape(&CoordUnits::Deg), None);
+    assert_eq!(
+        isg.validate().unwrap_err().to_string(),
+        "nrows: 4 and ncols: 6 exceed `data_bounds`"
+    );
+
+    // bounds which denote cell edges
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    let bounds = &isg.header.data_bounds;
+    assert_eq!(bounds.expected_shape(&CoordUnits::DMS), Some((5, 7)));
+    assert!(isg.validate().is_ok());
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    let bounds = &isg.header.data_bounds;
+    assert_eq!(bounds.expected_shape(&CoordUnits::Deg), None);
+}
+
+#[test]
+fn cell_size() {
+    let s = fs::read_to_string("rsc/isg/example.2.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    assert_eq!(isg.header.cell_size(), Some((0.333333, 0.333333)));
+
+    isg.header.coord_type = CoordType::Projected;
+    isg.header.coord_units = CoordUnits::Feet;
+    isg.header.data_bounds = DataBounds::GridProjected {
+        north_min: Coord::Dec(0.0),
+        north_max: Coord::Dec(300.0),
+        east_min: Coord::Dec(0.0),
+        east_max: Coord::Dec(1000.0),
+        delta_north: Coord::Dec(100.0),
+        delta_east: Coord::Dec(200.0),
+    };
+    let (delta_north, delta_east) = isg.header.cell_size().unwrap();
+    assert!((delta_north - 30.48).abs() < 1e-9);
+    assert!((delta_east - 60.96).abs() < 1e-9);
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    assert_eq!(isg.header.cell_size(), None);
+}
+
+#[test]
+fn extent() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    let extent = isg.extent();
+    assert!((extent.west - (119.0 + 50.0 / 60.0)).abs() < 1e-12);
+    assert!((extent.south - (39.0 + 50.0 / 60.0)).abs() < 1e-12);
+    assert!((extent.east - (121.0 + 50.0 / 60.0)).abs() < 1e-12);
+    assert!((extent.north - (41.0 + 10.0 / 60.0)).abs() < 1e-12);
+
+    isg.header.coord_type = CoordType::Projected;
+    isg.header.coord_units = CoordUnits::Feet;
+    isg.header.data_bounds = DataBounds::GridProjected {
+        north_min: Coord::Dec(0.0),
+        north_max: Coord::Dec(300.0),
+        east_min: Coord::Dec(-100.0),
+        east_max: Coord::Dec(1000.0),
+        delta_north: Coord::Dec(100.0),
+        delta_east: Coord::Dec(200.0),
+    };
+    let extent = isg.extent();
+    assert!((extent.west + 30.48).abs() < 1e-9);
+    assert!((extent.south - 0.0).abs() < 1e-9);
+    assert!((extent.east - 304.8).abs() < 1e-9);
+    assert!((extent.north - 91.44).abs() < 1e-9);
+}
+
+#[test]
+fn registration() {
+    let s = fs::read_to_string("rsc/isg/example.2.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    assert_eq!(isg.header.registration(), Some(Registration::CellCenters));
+    assert_eq!(Registration::default(), Registration::CellCenters);
+
+    let extent = isg.extent();
+    assert_eq!(isg.extent_with(Registration::CellCenters), extent);
+    let edges = isg.extent_with(Registration::CellEdges);
+    assert!((edges.west - (extent.west - 0.333333 / 2.0)).abs() < 1e-9);
+    assert!((edges.south - (extent.south - 0.333333 / 2.0)).abs() < 1e-9);
+    assert!((edges.east - (extent.east + 0.333333 / 2.0)).abs() < 1e-9);
+    assert!((edges.north - (extent.north + 0.333333 / 2.0)).abs() < 1e-9);
+
+    isg.header.nrows = 3;
+    isg.header.ncols = 5;
+    assert_eq!(isg.header.registration(), Some(Registration::CellEdges));
+    isg.header.nrows = 4;
+    assert_eq!(isg.header.registration(), None);
+
+    // span is delta * n
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    assert_eq!(isg.header.registration(), Some(Registration::CellEdges));
+
+    isg.header.coord_type = CoordType::Projected;
+    isg.header.coord_units = CoordUnits::Feet;
+    isg.header.data_bounds = DataBounds::GridProjected {
+        north_min: Coord::Dec(0.0),
+        north_max: Coord::Dec(300.0),
+        east_min: Coord::Dec(0.0),
+        east_max: Coord::Dec(1000.0),
+        delta_north: Coord::Dec(100.0),
+        delta_east: Coord::Dec(200.0),
+    };
+    assert_eq!(isg.header.registration(), Some(Registration::CellCenters));
+    let edges = isg.extent_with(Registration::CellEdges);
+    assert!((edges.west + 30.48).abs() < 1e-9);
+    assert!((edges.south + 15.24).abs() < 1e-9);
+    assert!((edges.east - 335.28).abs() < 1e-9);
+    assert!((edges.north - 106.68).abs() < 1e-9);
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    assert_eq!(isg.header.registration(), None);
+    assert_eq!(isg.extent_with(Registration::CellEdges), isg.extent());
+}
+
+#[test]
+fn shift_registration() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    let original = isg.header.data_bounds.clone();
+
+    // already
+    assert!(!isg.shift_registration(Registration::CellEdges));
+    assert_eq!(isg.header.data_bounds, original);
+
+    assert!(isg.shift_registration(Registration::CellCenters));
+    assert_eq!(
+        isg.header.data_bounds,
+        DataBounds::GridGeodetic {
+            lat_min: Coord::with_dms(40, 0, 0),
+            lat_max: Coord::with_dms(41, 0, 0),
+            lon_min: Coord::with_dms(120, 0, 0),
+            lon_max: Coord::with_dms(121, 40, 0),
+            delta_lat: Coord::with_dms(0, 20, 0),
+            delta_lon: Coord::with_dms(0, 20, 0),
+        }
+    );
+    assert_eq!(isg.header.registration(), Some(Registration::CellCenters));
+    assert!(isg.is_valid());
+
+    assert!(isg.shift_registration(Registration::CellEdges));
+    assert_eq!(isg.header.data_bounds, original);
+
+    // odd seconds
+    let mut odd = isg.clone();
+    odd.header.data_bounds = DataBounds::GridGeodetic {
+        lat_min: Coord::with_dms(40, 0, 0),
+        lat_max: Coord::with_dms(40, 0, 4),
+        lon_min: Coord::with_dms(120, 0, 0),
+        lon_max: Coord::with_dms(120, 0, 6),
+        delta_lat: Coord::with_dms(0, 0, 1),
+        delta_lon: Coord::with_dms(0, 0, 1),
+    };
+    assert_eq!(odd.header.registration(), Some(Registration::CellEdges));
+    assert!(!odd.shift_registration(Registration::CellCenters));
+    assert_eq!(odd.header.data_bounds.a_max(), Coord::with_dms(40, 0, 4));
+
+    // exceeds the pole
+    let s = fs::read_to_string("rsc/isg/example.2.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    if let DataBounds::GridGeodetic {
+        lat_min, lat_max, ..
+    } = &mut isg.header.data_bounds
+    {
+        *lat_min = Coord::Dec(89.0);
+        *lat_max = Coord::Dec(90.0);
+    }
+    let original = isg.header.data_bounds.clone();
+    assert!(!isg.shift_registration(Registration::CellEdges));
+    assert_eq!(isg.header.data_bounds, original);
+
+    // projected
+    isg.header.coord_type = CoordType::Projected;
+    isg.header.coord_units = CoordUnits::Meters;
+    isg.header.data_bounds = DataBounds::GridProjected {
+        north_min: Coord::Dec(0.0),
+        north_max: Coord::Dec(300.0),
+        east_min: Coord::Dec(0.0),
+        east_max: Coord::Dec(1000.0),
+        delta_north: Coord::Dec(100.0),
+        delta_east: Coord::Dec(200.0),
+    };
+    assert!(isg.shift_registration(Registration::CellEdges));
+    assert_eq!(
+        isg.header.data_bounds,
+        DataBounds::GridProjected {
+            north_min: Coord::Dec(-50.0),
+            north_max: Coord::Dec(350.0),
+            east_min: Coord::Dec(-100.0),
+            east_max: Coord::Dec(1100.0),
+            delta_north: Coord::Dec(100.0),
+            delta_east: Coord::Dec(200.0),
+        }
+    );
+
+    // not inferred
+    isg.header.nrows = 2;
+    assert!(!isg.shift_registration(Registration::CellCenters));
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    assert!(!isg.shift_registration(Registration::CellCenters));
+}
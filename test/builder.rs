@@ -0,0 +1,422 @@
+use std::fs;
+
+use libisg::{
+    from_str, Coord, CoordType, CoordUnits, CreationDate, DataBounds, DataFormat, DataOrdering,
+    DataType, DataUnits, DmsRounding, Header, ModelType, TideSystem, ISG,
+};
+
+#[test]
+fn header_builder() {
+    let s = fs::read_to_string("rsc/isg/example.2.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    let header = Header::builder()
+        .model_name("EXAMPLE")
+        .model_year("2020")
+        .model_type(ModelType::Gravimetric)
+        .data_type(DataType::Geoid)
+        .data_units(DataUnits::Meters)
+        .data_ordering(DataOrdering::N2SW2E)
+        .ref_ellipsoid("GRS80")
+        .ref_frame("ITRF2014")
+        .tide_system(TideSystem::MeanTide)
+        .coord_type(CoordType::Geodetic)
+        .coord_units(CoordUnits::Deg)
+        .epsg_code("7912")
+        .bounds(
+            Coord::Dec(40.0),
+            Coord::Dec(41.0),
+            Coord::Dec(120.0),
+            Coord::Dec(121.666667),
+        )
+        .deltas(Coord::Dec(0.333333), Coord::Dec(0.333333))
+        .nodata(-9999.0)
+        .creation_date(CreationDate::new(2020, 5, 31))
+        .build()
+        .unwrap();
+    assert_eq!(header, isg.header);
+
+    let header = Header::builder()
+        .coord_type(CoordType::Projected)
+        .coord_units(CoordUnits::Meters)
+        .bounds(
+            Coord::Dec(0.0),
+            Coord::Dec(100.0),
+            Coord::Dec(0.0),
+            Coord::Dec(200.0),
+        )
+        .nrows(20)
+        .build()
+        .unwrap();
+    assert_eq!(header.data_format, DataFormat::Sparse);
+    assert_eq!((header.nrows, header.ncols), (20, 3));
+}
+
+#[test]
+fn dms_rounding() {
+    let builder = Header::builder()
+        .coord_type(CoordType::Geodetic)
+        .coord_units(CoordUnits::DMS)
+        .bounds(
+            Coord::Dec(40.0),
+            Coord::with_dms(41, 0, 0),
+            Coord::Dec(-0.5),
+            Coord::Dec(1.0),
+        )
+        .deltas(Coord::Dec(0.333333), Coord::Dec(0.5));
+
+    let header = builder
+        .clone()
+        .dms_rounding(DmsRounding::Nearest)
+        .build()
+        .unwrap();
+    assert_eq!(
+        header.data_bounds,
+        DataBounds::GridGeodetic {
+            lat_min: Coord::with_dms(40, 0, 0),
+            lat_max: Coord::with_dms(41, 0, 0),
+            lon_min: Coord::with_signed_dms(true, 0, 30, 0),
+            lon_max: Coord::with_dms(1, 0, 0),
+            delta_lat: Coord::with_dms(0, 20, 0),
+            delta_lon: Coord::with_dms(0, 30, 0),
+        }
+    );
+    assert_eq!((header.nrows, header.ncols), (4, 4));
+
+    // 0.333333 is 0°19'59.9988"
+    let header = builder
+        .clone()
+        .nrows(4)
+        .ncols(4)
+        .dms_rounding(DmsRounding::Truncate)
+        .build()
+        .unwrap();
+    assert!(matches!(
+        header.data_bounds,
+        DataBounds::GridGeodetic { delta_lat, .. } if delta_lat == Coord::with_dms(0, 19, 59)
+    ));
+
+    let err = builder
+        .clone()
+        .dms_rounding(DmsRounding::Exact)
+        .build()
+        .unwrap_err();
+    assert_eq!(err.to_string(), "`delta lat` is not representable in DMS");
+
+    let err = builder
+        .clone()
+        .bounds(
+            Coord::Dec(f64::NAN),
+            Coord::Dec(41.0),
+            Coord::Dec(120.0),
+            Coord::Dec(121.0),
+        )
+        .dms_rounding(DmsRounding::Nearest)
+        .build()
+        .unwrap_err();
+    assert_eq!(err.to_string(), "`lat min` is not representable in DMS");
+
+    // no conversion without rounding
+    let err = builder.build().unwrap_err();
+    assert_eq!(err.to_string(), "unexpected data format on `lat min`");
+}
+
+#[test]
+fn header_builder_err() {
+    let builder = Header::builder()
+        .coord_type(CoordType::Geodetic)
+        .coord_units(CoordUnits::Deg)
+        .bounds(
+            Coord::Dec(40.0),
+            Coord::Dec(41.0),
+            Coord::Dec(120.0),
+            Coord::Dec(122.0),
+        );
+
+    assert_eq!(
+        Header::builder().build().unwrap_err().to_string(),
+        "missing header key: `coord type`"
+    );
+    assert_eq!(
+        builder.clone().build().unwrap_err().to_string(),
+        "missing header key: `nrows`"
+    );
+    assert_eq!(
+        builder
+            .clone()
+            .data_format(DataFormat::Grid)
+            .build()
+            .unwrap_err()
+            .to_string(),
+        "missing header key: `delta lat`"
+    );
+    assert_eq!(
+        builder
+            .clone()
+            .data_format(DataFormat::Sparse)
+            .deltas(Coord::Dec(0.5), Coord::Dec(0.5))
+            .build()
+            .unwrap_err()
+            .to_string(),
+        "unexpected `data_bounds`, expected DataBounds::SparseGeodetic"
+    );
+    assert_eq!(
+        builder
+            .clone()
+            .deltas(Coord::Dec(0.3), Coord::Dec(0.5))
+            .build()
+            .unwrap_err()
+            .to_string(),
+        "missing header key: `nrows`"
+    );
+    assert_eq!(
+        builder
+            .deltas(Coord::Dec(0.5), Coord::with_dms(0, 30, 0))
+            .build()
+            .unwrap_err()
+            .to_string(),
+        "unexpected data format on `delta lon`"
+    );
+}
+
+#[test]
+fn isg_builder() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    let rows: Vec<Vec<_>> = isg.data.grid_data().rows().map(<[_]>::to_vec).collect();
+    let actual = ISG::builder()
+        .comment(isg.comment.clone())
+        .header(
+            Header::builder()
+                .model_name("EXAMPLE")
+                .model_year("2020")
+                .model_type(ModelType::Gravimetric)
+                .data_type(DataType::Geoid)
+                .data_units(DataUnits::Meters)
+                .data_ordering(DataOrdering::N2SW2E)
+                .ref_ellipsoid("GRS80")
+                .ref_frame("ITRF2014")
+                .tide_system(TideSystem::MeanTide)
+                .coord_type(CoordType::Geodetic)
+                .coord_units(CoordUnits::DMS)
+                .epsg_code("7912")
+                .bounds(
+                    Coord::with_dms(39, 50, 0),
+                    Coord::with_dms(41, 10, 0),
+                    Coord::with_dms(119, 50, 0),
+                    Coord::with_dms(121, 50, 0),
+                )
+                .deltas(Coord::with_dms(0, 20, 0), Coord::with_dms(0, 20, 0))
+                .nodata(-9999.0)
+                .creation_date(CreationDate::new(2020, 5, 31)),
+        )
+        .grid(rows)
+        .build()
+        .unwrap();
+    assert_eq!(actual, isg);
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    let records = isg.data.sparse_data().clone();
+    let actual = ISG::builder()
+        .header(
+            Header::builder()
+                .coord_type(CoordType::Geodetic)
+                .coord_units(CoordUnits::Deg)
+                .bounds(
+                    Coord::Dec(40.0),
+                    Coord::Dec(41.0),
+                    Coord::Dec(120.0),
+                    Coord::Dec(121.666667),
+                ),
+        )
+        .sparse(records)
+        .build()
+        .unwrap();
+    assert_eq!(actual.header.data_format, DataFormat::Sparse);
+    assert_eq!((actual.header.nrows, actual.header.ncols), (20, 3));
+    assert_eq!(actual.data, isg.data);
+}
+
+#[test]
+fn isg_builder_err() {
+    let header = Header::builder()
+        .coord_type(CoordType::Projected)
+        .coord_units(CoordUnits::Meters)
+        .bounds(
+            Coord::Dec(0.0),
+            Coord::Dec(100.0),
+            Coord::Dec(0.0),
+            Coord::Dec(200.0),
+        )
+        .deltas(Coord::Dec(100.0), Coord::Dec(100.0));
+
+    assert_eq!(
+        ISG::builder()
+            .header(header.clone())
+            .build()
+            .unwrap_err()
+            .to_string(),
+        "missing data"
+    );
+    assert_eq!(
+        ISG::builder()
+            .header(header.clone())
+            .grid(vec![vec![1.0, 2.0], vec![3.0]])
+            .build()
+            .unwrap_err()
+            .to_string(),
+        "unexpected data length, ncols: 2 but actual: 1"
+    );
+    assert_eq!(
+        ISG::builder()
+            .header(header.clone().nrows(1))
+            .grid(vec![vec![1.0, 2.0], vec![3.0, 4.0]])
+            .build()
+            .unwrap_err()
+            .to_string(),
+        "unexpected data length, nrows: 1 but actual: 2"
+    );
+    assert_eq!(
+        ISG::builder()
+            .header(header)
+            .grid(vec![vec![1.0; 4]; 2])
+            .build()
+            .unwrap_err()
+            .to_string(),
+        "nrows: 2 and ncols: 4 exceed `data_bounds`"
+    );
+}
+
+#[test]
+fn derive() {
+    for path in [
+        "rsc/isg/example.1.isg",
+        "rsc/isg/example.2.isg",
+        "rsc/isg/example.3.isg",
+    ] {
+        let s = fs::read_to_string(path).unwrap();
+        let isg = from_str(&s).unwrap();
+        assert_eq!(isg.header.derive().build().unwrap(), isg.header);
+    }
+
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    let corrected = ISG::builder()
+        .comment(isg.comment.clone())
+        .header(isg.header.derive().model_name("EXAMPLE-CORRECTED"))
+        .data(isg.map_values(|v| v + 1.0).data)
+        .build()
+        .unwrap();
+    assert_eq!(
+        corrected.header.model_name.as_deref(),
+        Some("EXAMPLE-CORRECTED")
+    );
+    assert_eq!(corrected.header.data_bounds, isg.header.data_bounds);
+    assert_eq!(corrected.data[(0, 0)], Some(31.1234));
+}
+
+#[test]
+fn constructors() {
+    let s = fs::read_to_string("rsc/isg/example.2.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    let header = Header::grid_geodetic(
+        CoordUnits::Deg,
+        Coord::Dec(40.0),
+        Coord::Dec(41.0),
+        Coord::Dec(120.0),
+        Coord::Dec(121.666667),
+        Coord::Dec(0.333333),
+        Coord::Dec(0.333333),
+        4,
+        6,
+    );
+    assert_eq!(header.data_bounds, isg.header.data_bounds);
+    assert_eq!(
+        (header.data_format, header.coord_type),
+        (isg.header.data_format, isg.header.coord_type)
+    );
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    let header = Header::sparse_geodetic(
+        CoordUnits::Deg,
+        Coord::Dec(40.0),
+        Coord::Dec(41.0),
+        Coord::Dec(120.0),
+        Coord::Dec(121.666667),
+        20,
+    );
+    assert_eq!(header.data_bounds, isg.header.data_bounds);
+    assert_eq!((header.nrows, header.ncols), (20, 3));
+    assert_eq!(
+        (header.data_format, header.coord_type),
+        (isg.header.data_format, isg.header.coord_type)
+    );
+
+    let expected = Header::builder()
+        .coord_type(CoordType::Projected)
+        .coord_units(CoordUnits::Feet)
+        .bounds(
+            Coord::Dec(0.0),
+            Coord::Dec(100.0),
+            Coord::Dec(0.0),
+            Coord::Dec(200.0),
+        )
+        .deltas(Coord::Dec(50.0), Coord::Dec(50.0))
+        .build()
+        .unwrap();
+    let header = Header::grid_projected(
+        CoordUnits::Feet,
+        Coord::Dec(0.0),
+        Coord::Dec(100.0),
+        Coord::Dec(0.0),
+        Coord::Dec(200.0),
+        Coord::Dec(50.0),
+        Coord::Dec(50.0),
+        3,
+        5,
+    );
+    assert_eq!(header, expected);
+
+    let expected = Header::builder()
+        .coord_type(CoordType::Projected)
+        .coord_units(CoordUnits::Meters)
+        .bounds(
+            Coord::Dec(0.0),
+            Coord::Dec(100.0),
+            Coord::Dec(0.0),
+            Coord::Dec(200.0),
+        )
+        .nrows(10)
+        .build()
+        .unwrap();
+    let header = Header::sparse_projected(
+        CoordUnits::Meters,
+        Coord::Dec(0.0),
+        Coord::Dec(100.0),
+        Coord::Dec(0.0),
+        Coord::Dec(200.0),
+        10,
+    );
+    assert_eq!(header, expected);
+}
+
+#[test]
+fn parts() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    let (comment, header, data) = isg.clone().into_parts();
+    assert_eq!(ISG::from_parts(comment, header, data).unwrap(), isg);
+
+    let (comment, mut header, data) = isg.into_parts();
+    header.nrows = 3;
+    assert_eq!(
+        ISG::from_parts(comment, header, data)
+            .unwrap_err()
+            .to_string(),
+        "unexpected data length, nrows: 3 but actual: 4"
+    );
+}
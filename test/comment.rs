@@ -0,0 +1,61 @@
+use std::fs;
+
+use libisg::from_str;
+
+#[test]
+fn comment_lines() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+
+    assert_eq!(isg.comment_lines().count(), 16);
+    assert_eq!(
+        isg.comment_lines().next(),
+        Some("These data are freely available under a Creative Commons Attribution 4.0")
+    );
+
+    isg.comment = "first".into();
+    isg.push_comment_line("second");
+    isg.push_comment_line("third");
+    assert_eq!(isg.comment, "first\nsecond\nthird\n");
+    assert_eq!(
+        isg.comment_lines().collect::<Vec<_>>(),
+        vec!["first", "second", "third"]
+    );
+    assert!(isg.validate().is_ok());
+
+    isg.push_comment_line("begin_of_head");
+    assert_eq!(
+        isg.validate().unwrap_err().to_string(),
+        "comment line starts with `begin_of_head` (line: 4)"
+    );
+}
+
+#[test]
+fn comment_metadata() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+
+    // free-form comment has no entry
+    assert!(isg.comment_metadata().is_empty());
+
+    isg.comment = "Some description\nDOI: 10.5880/isg.2020.001\nlicense: CC BY 4.0\n".into();
+    let mut metadata = isg.comment_metadata();
+    assert_eq!(metadata.len(), 2);
+    assert_eq!(metadata.get("DOI"), Some("10.5880/isg.2020.001"));
+    assert_eq!(metadata.get("license"), Some("CC BY 4.0"));
+
+    assert_eq!(metadata.insert("license", "CC0"), Some("CC BY 4.0".into()));
+    assert_eq!(metadata.insert("processing chain", "v1 -> v2"), None);
+    assert_eq!(metadata.remove("DOI"), Some("10.5880/isg.2020.001".into()));
+    assert_eq!(
+        metadata.to_string(),
+        "license: CC0\nprocessing chain: v1 -> v2\n"
+    );
+
+    isg.set_comment_metadata(&metadata);
+    assert_eq!(
+        isg.comment,
+        "Some description\nlicense: CC0\nprocessing chain: v1 -> v2\n"
+    );
+    assert_eq!(isg.comment_metadata(), metadata);
+}
@@ -0,0 +1,168 @@
+use std::fs;
+
+use libisg::{
+    from_str, Coord, Data, DataOrdering, DataUnits, FieldDiff, Method, Registration,
+    ResolutionPolicy,
+};
+
+#[test]
+fn compare() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    let report = isg.compare(&isg, 0.0);
+    assert!(report.passed());
+    assert!(report.header.is_empty());
+    assert_eq!(report.shape, None);
+    assert_eq!((report.compared, report.nodata_mismatches), (20, 0));
+    assert_eq!((report.max_abs_diff, report.mean_abs_diff), (0.0, 0.0));
+    assert!(report.worst.is_empty());
+
+    let mut other = isg.clone();
+    other.header.model_name = Some("other".into());
+    other.data[(0, 0)] = Some(30.1234 + 0.5);
+    other.data[(1, 2)] = Some(43.3333 - 2.0);
+    other.data[(2, 0)] = Some(51.4321 + 1e-4);
+    other.data[(3, 5)] = Some(0.0);
+
+    let report = isg.compare(&other, 1e-3);
+    assert!(!report.passed());
+    assert_eq!(
+        report.header,
+        vec![FieldDiff {
+            field: "model_name",
+            left: "Some(\"EXAMPLE\")".into(),
+            right: "Some(\"other\")".into(),
+        }]
+    );
+    assert_eq!((report.compared, report.nodata_mismatches), (20, 1));
+    assert_eq!(report.exceeded, 2);
+    assert!((report.max_abs_diff - 2.0).abs() < 1e-9);
+    assert!((report.mean_abs_diff - (2.5 + 1e-4) / 20.0).abs() < 1e-9);
+    // the worst first
+    assert_eq!(
+        report
+            .worst
+            .iter()
+            .map(|cell| (cell.row, cell.col))
+            .collect::<Vec<_>>(),
+        [(1, 2), (0, 0)]
+    );
+    assert_eq!(report.worst[1].a, Coord::with_dms(41, 10, 0));
+    assert_eq!(report.worst[1].b, Coord::with_dms(119, 50, 0));
+    assert_eq!(report.worst[1].left, 30.1234);
+    assert!(report.to_string().contains("data (1, 2)"));
+
+    // respects data ordering
+    let mut reordered = isg.clone();
+    assert!(reordered.reorder(DataOrdering::S2NE2W));
+    let report = isg.compare(&reordered, 0.0);
+    assert_eq!(report.header.len(), 1);
+    assert_eq!(report.compared, 20);
+    assert!(report.passed());
+
+    // shape mismatch
+    let sub = isg.subgrid(0..2, 0..6).unwrap();
+    let report = isg.compare(&sub, 0.0);
+    assert_eq!(report.shape, Some(((4, 6), (2, 6))));
+    assert_eq!(report.compared, 0);
+    assert!(!report.passed());
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let sparse = from_str(&s).unwrap();
+    let report = isg.compare(&sparse, 0.0);
+    assert_eq!(report.shape, Some(((4, 6), (20, 3))));
+    assert!(report.header.iter().any(|field| field.field == "data"));
+
+    let mut other = sparse.clone();
+    if let Data::Sparse(data) = &mut other.data {
+        data[19].2 += 1.0;
+    }
+    let report = sparse.compare(&other, 0.5);
+    assert_eq!((report.compared, report.exceeded), (20, 1));
+    assert_eq!(
+        (report.worst[0].row, report.worst[0].col, report.worst[0].a),
+        (19, 2, Coord::Dec(41.0))
+    );
+}
+
+#[test]
+fn rms_difference() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    let stats = isg.rms_difference(&isg, ResolutionPolicy::Strict).unwrap();
+    assert_eq!(stats.count, 20);
+    assert_eq!((stats.rms, stats.mean, stats.max), (0.0, 0.0, 0.0));
+
+    // overlap only, respecting data ordering
+    let mut other = isg.subgrid(1..4, 2..6).unwrap().map_values(|v| v - 1.0);
+    other.data[(0, 0)] = Some(43.3333 - 3.0);
+    assert!(other.reorder(DataOrdering::S2NE2W));
+    let stats = isg
+        .rms_difference(&other, ResolutionPolicy::Strict)
+        .unwrap();
+    assert_eq!(stats.count, 8);
+    assert!((stats.mean - 10.0 / 8.0).abs() < 1e-9);
+    assert!((stats.rms - 2.0_f64.sqrt()).abs() < 1e-9);
+    assert!((stats.max - 3.0).abs() < 1e-9);
+    // sign is of `self - other`
+    let stats = other
+        .rms_difference(&isg, ResolutionPolicy::Strict)
+        .unwrap();
+    assert!((stats.mean + 10.0 / 8.0).abs() < 1e-9);
+    assert_eq!(
+        isg.rms_difference(&other, ResolutionPolicy::default()),
+        isg.rms_difference(&other, ResolutionPolicy::Resample(Method::Bilinear))
+    );
+
+    // resamples the coarser
+    let fine = isg.upsample(2, Method::Bilinear).unwrap();
+    for (left, right) in [(&isg, &fine), (&fine, &isg)] {
+        let stats = left
+            .rms_difference(right, ResolutionPolicy::Resample(Method::Bilinear))
+            .unwrap();
+        assert!(stats.count > 20);
+        assert!(stats.rms < 1e-9);
+    }
+    let shifted = fine.map_values(|v| v + 0.5);
+    let stats = isg
+        .rms_difference(&shifted, ResolutionPolicy::Resample(Method::Bilinear))
+        .unwrap();
+    assert!((stats.mean + 0.5).abs() < 1e-9);
+    assert!((stats.rms - 0.5).abs() < 1e-9);
+
+    // errors
+    let err = isg
+        .rms_difference(&fine, ResolutionPolicy::Strict)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "delta mismatch");
+
+    let mut misaligned = isg.clone();
+    assert!(misaligned.shift_registration(Registration::CellCenters));
+    let err = isg
+        .rms_difference(&misaligned, ResolutionPolicy::Strict)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "grid nodes are not aligned");
+
+    let north = isg.subgrid(0..2, 0..6).unwrap();
+    let south = isg.subgrid(2..4, 0..6).unwrap();
+    let err = north
+        .rms_difference(&south, ResolutionPolicy::Strict)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "no overlapping cell");
+
+    let mut feet = isg.clone();
+    feet.header.data_units = Some(DataUnits::Feet);
+    let err = isg
+        .rms_difference(&feet, ResolutionPolicy::Strict)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "`data units` mismatch");
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let sparse = from_str(&s).unwrap();
+    let err = isg
+        .rms_difference(&sparse, ResolutionPolicy::Strict)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "data is not grid");
+}
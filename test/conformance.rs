@@ -0,0 +1,23 @@
+use libisg::{conformance_cases, run_conformance, IsgCodec, LibIsg};
+
+#[test]
+fn reference() {
+    let report = run_conformance(LibIsg);
+    assert!(report.is_ok(), "{:?}", report.failed);
+    assert_eq!(report.passed.len(), conformance_cases().len());
+}
+
+#[test]
+fn broken() {
+    struct Echo;
+    impl IsgCodec for Echo {
+        fn reserialize(&self, input: &str) -> Option<String> {
+            Some(input.into())
+        }
+    }
+
+    let report = run_conformance(Echo);
+    assert!(!report.is_ok());
+    assert!(report.passed.contains(&"grid_dms"));
+    assert!(report.failed.iter().any(|f| f.name == "minified_header"));
+}
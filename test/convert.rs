@@ -0,0 +1,149 @@
+use std::fs;
+
+use libisg::{from_str, Coord, Data, DataBounds, DataFormat, DataOrdering, SparseColumns};
+
+#[test]
+fn to_sparse_to_grid() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    let sparse = isg.to_sparse();
+    assert_eq!(sparse.header.data_format, DataFormat::Sparse);
+    assert_eq!(sparse.header.data_ordering, Some(DataOrdering::LatLonN));
+    assert_eq!(sparse.header.nrows, 20);
+    assert_eq!(
+        sparse.header.data_bounds,
+        DataBounds::SparseGeodetic {
+            lat_min: Coord::with_dms(40, 10, 0),
+            lat_max: Coord::with_dms(41, 10, 0),
+            lon_min: Coord::with_dms(119, 50, 0),
+            lon_max: Coord::with_dms(121, 30, 0),
+        }
+    );
+    assert_eq!(sparse.to_sparse(), sparse);
+
+    let grid = sparse
+        .to_grid(Coord::with_dms(0, 20, 0), Coord::with_dms(0, 20, 0))
+        .unwrap();
+    assert_eq!((grid.header.nrows, grid.header.ncols), (4, 6));
+    assert_eq!(grid.data, isg.data);
+    assert_eq!(grid.to_sparse(), sparse);
+
+    assert!(isg
+        .to_grid(Coord::with_dms(0, 20, 0), Coord::with_dms(0, 20, 0))
+        .is_err());
+}
+
+#[test]
+fn to_grid_err() {
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    let delta = Coord::with_dec(1.0 / 3.0);
+
+    assert_eq!(
+        isg.to_grid(delta, Coord::with_dec(0.5))
+            .unwrap_err()
+            .to_string(),
+        "record is off grid (index: 1)"
+    );
+    assert_eq!(
+        isg.to_grid(delta, Coord::with_dec(0.0))
+            .unwrap_err()
+            .to_string(),
+        "delta must be positive"
+    );
+
+    let mut duplicated = isg.clone();
+    if let Data::Sparse(data) = &mut duplicated.data {
+        data.push(data[0]);
+    }
+    assert_eq!(
+        duplicated.to_grid(delta, delta).unwrap_err().to_string(),
+        "record is duplicated on grid (index: 20)"
+    );
+}
+
+#[test]
+fn rasterize_idw() {
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    // the same as to_grid on the nodes of records
+    let delta = Coord::with_dec(1.0 / 3.0);
+    let grid = isg.rasterize_idw(delta, delta, 2.0, 0.01).unwrap();
+    assert_eq!(grid.header, isg.to_grid(delta, delta).unwrap().header);
+    assert_eq!(grid.data, isg.to_grid(delta, delta).unwrap().data);
+
+    let delta = Coord::with_dec(1.0 / 6.0);
+    let grid = isg.rasterize_idw(delta, delta, 2.0, 0.2).unwrap();
+    assert_eq!(grid.header.data_format, DataFormat::Grid);
+    assert_eq!((grid.header.nrows, grid.header.ncols), (7, 11));
+    assert!(grid.is_valid());
+
+    assert_eq!(grid.data[(0, 0)], Some(61.9999));
+    // (41.0, 120.1667), two records at (almost) the same distance
+    let value = grid.data[(0, 1)].unwrap();
+    assert!((value - (61.9999 + 62.8888) / 2.0).abs() < 1e-4);
+    // (41.0, 121.6667), no record within radius
+    assert_eq!(grid.data[(0, 10)], None);
+
+    // weighted means are within the range of records
+    let grid = isg.rasterize_idw(delta, delta, 1.0, 1.0).unwrap();
+    assert_eq!(grid.nodata_count(), 0);
+    assert!(grid
+        .cells()
+        .all(|(_, _, value)| (30.1234..=64.6666).contains(&value.unwrap())));
+
+    assert_eq!(
+        isg.rasterize_idw(delta, delta, 0.0, 0.2)
+            .unwrap_err()
+            .to_string(),
+        "`power` must be positive"
+    );
+    assert_eq!(
+        isg.rasterize_idw(delta, delta, 2.0, -1.0)
+            .unwrap_err()
+            .to_string(),
+        "`radius` must be positive"
+    );
+    assert_eq!(
+        isg.rasterize_idw(delta, Coord::with_dec(0.0), 2.0, 0.2)
+            .unwrap_err()
+            .to_string(),
+        "delta must be positive"
+    );
+
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    assert_eq!(
+        isg.rasterize_idw(delta, delta, 2.0, 0.2)
+            .unwrap_err()
+            .to_string(),
+        "data is not sparse"
+    );
+}
+
+#[test]
+fn sparse_columns() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    assert!(isg.sparse_columns().is_none());
+
+    // east, north, N
+    let mut isg = isg.to_sparse();
+    isg.header.data_ordering = Some(DataOrdering::EastNorthN);
+    if let Data::Sparse(data) = &mut isg.data {
+        for (a, b, _) in data.iter_mut() {
+            std::mem::swap(a, b);
+        }
+    }
+    let columns = isg.sparse_columns().unwrap();
+    assert_eq!(columns.len(), 20);
+    assert!((columns.lats()[0] - (40.0 + 10.0 / 60.0)).abs() < 1e-12);
+    assert!((columns.lons()[0] - (119.0 + 50.0 / 60.0)).abs() < 1e-12);
+    assert_eq!(columns.values()[0], 61.9999);
+
+    let (lats, lons, values) = columns.clone().into_vecs();
+    assert_eq!(SparseColumns::new(lats, lons, values), Some(columns));
+    assert_eq!(SparseColumns::new(vec![0.0], vec![], vec![0.0]), None);
+}
@@ -0,0 +1,308 @@
+use std::cmp::Ordering;
+
+use libisg::{Coord, CoordTrig, CoordUnits, DataBounds};
+
+#[test]
+fn decimal_degrees() {
+    assert_eq!(Coord::with_dms(0, 0, 0).to_decimal_degrees(), 0.0);
+    assert_eq!(
+        Coord::with_dms(121, 50, 30).to_decimal_degrees(),
+        121.0 + 50.0 / 60.0 + 30.0 / 3600.0
+    );
+    assert_eq!(
+        Coord::with_dms(-39, 50, 0).to_decimal_degrees(),
+        -(39.0 + 50.0 / 60.0)
+    );
+    assert_eq!(Coord::with_dec(-0.25).to_decimal_degrees(), -0.25);
+
+    // round trip
+    for (degree, minutes, second) in [(0, 0, 1), (39, 50, 0), (-179, 59, 59), (359, 0, 30)] {
+        let coord = Coord::with_dms(degree, minutes, second);
+        assert_eq!(
+            Coord::from_decimal_degrees(coord.to_decimal_degrees(), CoordUnits::DMS),
+            Some(coord)
+        );
+    }
+
+    // rounded to the nearest second
+    assert_eq!(
+        Coord::from_decimal_degrees(10.0 + 0.4 / 3600.0, CoordUnits::DMS),
+        Some(Coord::with_dms(10, 0, 0))
+    );
+    assert_eq!(
+        Coord::from_decimal_degrees(10.0 + 59.6 / 3600.0, CoordUnits::DMS),
+        Some(Coord::with_dms(10, 1, 0))
+    );
+    assert_eq!(
+        Coord::from_decimal_degrees(-(10.0 + 59.6 / 3600.0), CoordUnits::DMS),
+        Some(Coord::with_dms(-10, 1, 0))
+    );
+
+    assert_eq!(
+        Coord::from_decimal_degrees(1.5, CoordUnits::Deg),
+        Some(Coord::with_dec(1.5))
+    );
+    assert_eq!(Coord::from_decimal_degrees(1.5, CoordUnits::Feet), None);
+    assert_eq!(Coord::from_decimal_degrees(f64::NAN, CoordUnits::DMS), None);
+    assert_eq!(
+        Coord::from_decimal_degrees(f64::INFINITY, CoordUnits::DMS),
+        None
+    );
+    assert_eq!(Coord::from_decimal_degrees(70_000.0, CoordUnits::DMS), None);
+}
+
+#[test]
+fn try_with_dms() {
+    for (degree, minutes, second) in [
+        (0, 0, 0),
+        (-90, 0, 0),
+        (359, 59, 59),
+        (-360, 0, 0),
+        (360, 0, 0),
+    ] {
+        assert_eq!(
+            Coord::try_with_dms(degree, minutes, second),
+            Ok(Coord::with_dms(degree, minutes, second))
+        );
+    }
+
+    let err = Coord::try_with_dms(10, 60, 0).unwrap_err();
+    assert_eq!(err.to_string(), "minutes must be less than 60, actual: 60");
+    let err = Coord::try_with_dms(10, 0, 99).unwrap_err();
+    assert_eq!(err.to_string(), "second must be less than 60, actual: 99");
+    let err = Coord::try_with_dms(-361, 0, 0).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "coordinate out of range, expected: [-360°, 360°] but actual: -361°"
+    );
+    assert!(Coord::try_with_dms(360, 0, 1).is_err());
+    assert!(Coord::try_with_dms(i16::MIN, 0, 0).is_err());
+}
+
+#[test]
+fn ordering() {
+    let s = std::fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = libisg::from_str(&s).unwrap();
+    let (lat_min, lat_max) = match isg.header.data_bounds {
+        DataBounds::GridGeodetic {
+            lat_min, lat_max, ..
+        } => (lat_min, lat_max),
+        _ => unreachable!(),
+    };
+    let p = Coord::with_dms(40, 30, 0);
+    assert!(lat_min <= p && p <= lat_max);
+    let p = Coord::with_dms(41, 10, 1);
+    assert!(!(lat_min <= p && p <= lat_max));
+
+    // DMS by value, across zero
+    let sorted = [
+        Coord::with_dms(-10, 0, 1),
+        Coord::with_dms(-10, 0, 0),
+        Coord::with_dms(-9, 59, 59),
+        Coord::with_dms(0, 0, 0),
+        Coord::with_dms(0, 0, 1),
+        Coord::with_dms(0, 59, 59),
+        Coord::with_dms(1, 0, 0),
+    ];
+    for (i, a) in sorted.iter().enumerate() {
+        for (j, b) in sorted.iter().enumerate() {
+            assert_eq!(a.partial_cmp(b), Some(i.cmp(&j)), "{:?} {:?}", a, b);
+            assert_eq!(a.total_cmp(b), i.cmp(&j));
+        }
+    }
+    // unnormalized fields
+    assert_eq!(
+        Coord::with_dms(0, 60, 0).partial_cmp(&Coord::with_dms(1, 0, 0)),
+        Some(Ordering::Greater)
+    );
+
+    assert!(Coord::with_dec(-0.5) < Coord::with_dec(0.5));
+    assert_eq!(
+        Coord::with_dec(f64::NAN).partial_cmp(&Coord::with_dec(0.0)),
+        None
+    );
+    assert_eq!(
+        Coord::with_dms(0, 30, 0).partial_cmp(&Coord::with_dec(0.5)),
+        None
+    );
+
+    // total
+    assert_eq!(
+        Coord::with_dec(-0.0).total_cmp(&Coord::with_dec(0.0)),
+        Ordering::Less
+    );
+    assert_eq!(
+        Coord::with_dec(f64::NAN).total_cmp(&Coord::with_dec(f64::INFINITY)),
+        Ordering::Greater
+    );
+    assert_eq!(
+        Coord::with_dms(0, 30, 0).total_cmp(&Coord::with_dec(0.5)),
+        Ordering::Less
+    );
+    assert_eq!(
+        Coord::with_dec(0.5).total_cmp(&Coord::with_dms(0, 30, 0)),
+        Ordering::Greater
+    );
+    assert_eq!(
+        Coord::with_dec(0.6).total_cmp(&Coord::with_dms(0, 30, 0)),
+        Ordering::Greater
+    );
+
+    let mut records = vec![
+        Coord::with_dec(3.0),
+        Coord::with_dec(-1.0),
+        Coord::with_dec(2.0),
+    ];
+    records.sort_by(Coord::total_cmp);
+    assert_eq!(
+        records,
+        [
+            Coord::with_dec(-1.0),
+            Coord::with_dec(2.0),
+            Coord::with_dec(3.0)
+        ]
+    );
+}
+
+#[test]
+fn negative_near_zero() {
+    let coord = Coord::with_signed_dms(true, 0, 20, 0);
+    assert_eq!(coord.to_decimal_degrees(), -20.0 / 60.0);
+    assert_eq!(coord.to_string(), "-0°20'00\"");
+    assert_eq!("-0°20'00\"".parse::<Coord>().unwrap(), coord);
+    assert_eq!("+0°20'00\"".parse::<Coord>().unwrap(), -coord);
+    assert!("--0°20'00\"".parse::<Coord>().is_err());
+    assert!("-+0°20'00\"".parse::<Coord>().is_err());
+
+    // zero is not signed
+    assert_eq!(
+        Coord::with_signed_dms(true, 0, 0, 0),
+        Coord::with_dms(0, 0, 0)
+    );
+    assert_eq!(
+        "-0°00'00\"".parse::<Coord>().unwrap(),
+        Coord::with_dms(0, 0, 0)
+    );
+    assert_eq!(-Coord::with_dms(0, 0, 0), Coord::with_dms(0, 0, 0));
+
+    assert_eq!(
+        Coord::from_decimal_degrees(-20.0 / 60.0, CoordUnits::DMS),
+        Some(coord)
+    );
+    assert_eq!(
+        Coord::from_decimal_degrees(-0.1 / 3600.0, CoordUnits::DMS),
+        Some(Coord::with_dms(0, 0, 0))
+    );
+
+    // arithmetic
+    assert_eq!(-Coord::with_dms(0, 20, 0), coord);
+    assert_eq!(Coord::with_dms(0, 10, 0) - Coord::with_dms(0, 30, 0), coord);
+    assert_eq!(coord + Coord::with_dms(0, 20, 0), Coord::with_dms(0, 0, 0));
+    assert_eq!(coord * 4_u8, Coord::with_dms(-1, 20, 0));
+    assert_eq!(Coord::with_dms(-1, 20, 0) / 4_u8, coord);
+    assert_eq!(coord * 0.5, Coord::with_signed_dms(true, 0, 10, 0));
+
+    // ordering
+    assert!(Coord::with_dms(-1, 0, 0) < coord);
+    assert!(coord < Coord::with_dms(0, 0, 0));
+    assert_eq!(coord.total_cmp(&Coord::with_dms(0, 20, 0)), Ordering::Less);
+
+    // ordering agrees with equality on the sign of zero
+    let negative_zero = Coord::DMS {
+        negative: true,
+        degree: 0,
+        minutes: 0,
+        second: 0,
+    };
+    assert_ne!(negative_zero, Coord::with_dms(0, 0, 0));
+    assert_eq!(
+        negative_zero.partial_cmp(&Coord::with_dms(0, 0, 0)),
+        Some(Ordering::Less)
+    );
+    assert_eq!(
+        negative_zero.total_cmp(&Coord::with_dms(0, 0, 0)),
+        Ordering::Less
+    );
+    assert_eq!(negative_zero.total_cmp(&negative_zero), Ordering::Equal);
+    assert!(coord < negative_zero);
+
+    // bounds south of the equator are written and read back
+    let s = std::fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = libisg::from_str(&s).unwrap();
+    match &mut isg.header.data_bounds {
+        DataBounds::GridGeodetic {
+            lat_min, lat_max, ..
+        } => {
+            *lat_min = coord;
+            *lat_max = Coord::with_dms(0, 40, 0);
+        }
+        _ => unreachable!(),
+    }
+    let s = libisg::to_string(&isg);
+    assert!(s.contains("lat min        =   -0°20'00\""), "{}", s);
+    assert_eq!(libisg::from_str(&s).unwrap(), isg);
+}
+
+#[test]
+fn hemisphere() {
+    assert_eq!(
+        "39°50'00\"N".parse::<Coord>().unwrap(),
+        Coord::with_dms(39, 50, 0)
+    );
+    assert_eq!(
+        "121°50'00\"E".parse::<Coord>().unwrap(),
+        Coord::with_dms(121, 50, 0)
+    );
+    assert_eq!(
+        "39°50'00\"S".parse::<Coord>().unwrap(),
+        Coord::with_dms(-39, 50, 0)
+    );
+    assert_eq!(
+        "121°50'00\"W".parse::<Coord>().unwrap(),
+        Coord::with_dms(-121, 50, 0)
+    );
+    assert_eq!(
+        "0°20'00\"S".parse::<Coord>().unwrap(),
+        Coord::with_signed_dms(true, 0, 20, 0)
+    );
+
+    // both of sign and hemisphere
+    assert!("-39°50'00\"S".parse::<Coord>().is_err());
+    assert!("+39°50'00\"N".parse::<Coord>().is_err());
+    // unknown or lowercase letter
+    assert!("39°50'00\"X".parse::<Coord>().is_err());
+    assert!("39°50'00\"n".parse::<Coord>().is_err());
+    assert!("39°50'00\"NN".parse::<Coord>().is_err());
+    assert!("39°50'00N".parse::<Coord>().is_err());
+}
+
+#[test]
+fn radians() {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    assert_eq!(Coord::with_dms(0, 0, 0).to_radians(), 0.0);
+    assert_eq!(Coord::with_dms(45, 0, 0).to_radians(), FRAC_PI_4);
+    assert_eq!(Coord::with_dms(-90, 0, 0).to_radians(), -FRAC_PI_2);
+    assert_eq!(Coord::with_dec(180.0).to_radians(), PI);
+    assert_eq!(
+        Coord::with_signed_dms(true, 0, 30, 0).to_radians(),
+        Coord::with_dec(-0.5).to_radians()
+    );
+    assert_eq!(
+        Coord::with_dms(39, 50, 0).to_radians(),
+        (39.0_f64 + 50.0 / 60.0).to_radians()
+    );
+
+    for coord in [
+        Coord::with_dms(39, 50, 0),
+        Coord::with_dms(-121, 50, 30),
+        Coord::with_dec(0.25),
+    ] {
+        let radians = coord.to_radians();
+        assert_eq!(coord.sin(), radians.sin());
+        assert_eq!(coord.cos(), radians.cos());
+        assert_eq!(coord.sin_cos(), radians.sin_cos());
+    }
+    assert!((Coord::with_dms(30, 0, 0).sin() - 0.5).abs() < 1e-15);
+    assert!(Coord::with_dms(90, 0, 0).cos().abs() < 1e-15);
+}
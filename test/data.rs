@@ -0,0 +1,270 @@
+use std::convert::TryFrom;
+use std::fs;
+
+use libisg::{from_str, Coord, Data, Grid};
+
+#[test]
+fn accessor() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut grid = from_str(&s).unwrap();
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let mut sparse = from_str(&s).unwrap();
+
+    assert_eq!(grid.data.as_grid().map(|grid| grid.nrows()), Some(4));
+    assert_eq!(grid.data.as_sparse(), None);
+    assert_eq!(sparse.data.as_grid(), None);
+    assert_eq!(sparse.data.as_sparse(), Some(sparse.data.sparse_data()));
+
+    *grid.data.as_grid_mut().unwrap().get_mut(0, 0).unwrap() = None;
+    assert_eq!(grid.data.grid_data().get(0, 0), Some(&None));
+    assert!(grid.data.as_sparse_mut().is_none());
+
+    sparse.data.as_sparse_mut().unwrap().clear();
+    assert!(sparse.data.sparse_data().is_empty());
+    assert!(sparse.data.as_grid_mut().is_none());
+}
+
+#[test]
+fn into() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let grid = from_str(&s).unwrap();
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let sparse = from_str(&s).unwrap();
+
+    assert_eq!(
+        grid.data.clone().into_grid().as_ref(),
+        Ok(grid.data.grid_data())
+    );
+    assert_eq!(grid.data.clone().into_sparse(), Err(grid.data));
+    assert_eq!(sparse.data.clone().into_grid(), Err(sparse.data.clone()));
+    assert_eq!(
+        sparse.data.clone().into_sparse().as_ref(),
+        Ok(sparse.data.sparse_data())
+    );
+}
+
+#[test]
+fn grid() {
+    let mut grid = Grid::filled(2, 3, None);
+    assert_eq!((grid.nrows(), grid.ncols()), (2, 3));
+
+    *grid.get_mut(1, 2).unwrap() = Some(1.0);
+    grid.row_mut(0).unwrap()[1] = Some(2.0);
+    assert_eq!(grid.get(1, 2), Some(&Some(1.0)));
+    assert_eq!(grid.get(2, 0), None);
+    assert_eq!(grid.get(0, 3), None);
+    assert_eq!(unsafe { *grid.get_unchecked(0, 1) }, Some(2.0));
+    assert_eq!(grid.rows().len(), 2);
+
+    let rows = grid.clone().into_rows();
+    assert_eq!(Grid::try_from(rows), Ok(grid));
+
+    let err = Grid::try_from(vec![vec![Some(1.0), None], vec![None]]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "unexpected data length, ncols: 2 but actual: 1"
+    );
+}
+
+#[test]
+fn grid_flat() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    let grid = isg.data.grid_data();
+
+    assert_eq!(grid.as_slice().len(), grid.nrows() * grid.ncols());
+    assert_eq!(
+        grid.as_slice()[grid.ncols()..2 * grid.ncols()],
+        *grid.row(1).unwrap()
+    );
+
+    let values = grid.clone().into_vec();
+    assert_eq!(
+        Grid::from_vec(grid.nrows(), grid.ncols(), values).as_ref(),
+        Some(grid)
+    );
+    assert!(Grid::from_vec(grid.nrows(), grid.ncols(), vec![]).is_none());
+}
+
+#[test]
+fn grid_shared() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    let mut clone = isg.clone();
+    assert_eq!(
+        clone.data.grid_data().as_slice().as_ptr(),
+        isg.data.grid_data().as_slice().as_ptr()
+    );
+
+    clone.data[(0, 0)] = Some(0.0);
+    assert_ne!(
+        clone.data.grid_data().as_slice().as_ptr(),
+        isg.data.grid_data().as_slice().as_ptr()
+    );
+    assert_eq!(clone.data[(0, 0)], Some(0.0));
+    assert_eq!(isg.data[(0, 0)], Some(30.1234));
+
+    let values = isg.data.grid_data().clone().into_vec();
+    assert_eq!(values.as_slice(), isg.data.grid_data().as_slice());
+}
+
+#[test]
+fn grid_rle() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    let grid = isg.data.grid_data();
+
+    let rle = grid.to_rle();
+    assert_eq!((rle.nrows(), rle.ncols()), (4, 6));
+    assert_eq!(rle.nodata_count(), 4);
+    for row in 0..4 {
+        for col in 0..6 {
+            assert_eq!(rle.get(row, col), Some(grid[(row, col)]));
+        }
+    }
+    assert_eq!(rle.get(4, 0), None);
+    assert_eq!(rle.get(0, 6), None);
+    assert!(rle.iter().eq(grid.as_slice().iter().copied()));
+    assert_eq!(&rle.to_grid(), grid);
+
+    for grid in [
+        Grid::filled(2, 3, None),
+        Grid::filled(2, 3, Some(1.0)),
+        Grid::filled(0, 0, None),
+    ] {
+        assert_eq!(grid.to_rle().to_grid(), grid);
+    }
+}
+
+#[test]
+fn grid_f32() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    let grid = isg.data.grid_data();
+
+    let grid32 = grid.to_f32();
+    assert_eq!(
+        (grid32.nrows(), grid32.ncols()),
+        (grid.nrows(), grid.ncols())
+    );
+    assert_eq!(grid32.get(0, 0), Some(&Some(30.1234_f32)));
+    assert_eq!(grid32.get(3, 5), Some(&None));
+
+    let grid64 = Grid::from(grid32);
+    for (a, b) in grid.as_slice().iter().zip(grid64.as_slice()) {
+        match (a, b) {
+            (Some(a), Some(b)) => assert!((a - b).abs() < 1e-5),
+            (a, b) => assert_eq!(a, b),
+        }
+    }
+}
+
+#[test]
+fn grid_nan() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    let grid = isg.data.grid_data();
+
+    let values = grid.to_nan_grid();
+    assert_eq!(values.len(), grid.nrows() * grid.ncols());
+    assert_eq!(values.iter().filter(|v| v.is_nan()).count(), 4);
+
+    let actual = Grid::from_nan_grid(grid.nrows(), grid.ncols(), values).unwrap();
+    assert_eq!(&actual, grid);
+    assert!(Grid::from_nan_grid(1, 1, vec![]).is_none());
+}
+
+#[test]
+fn values() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let grid = from_str(&s).unwrap();
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let sparse = from_str(&s).unwrap();
+
+    assert_eq!(grid.data.values().count(), 20);
+    assert_eq!(grid.data.values().next(), Some(30.1234));
+    let indices: Vec<_> = grid.data.values_with_index().skip(16).collect();
+    assert_eq!(
+        indices,
+        vec![
+            (3, 0, 61.9999),
+            (3, 1, 62.8888),
+            (3, 2, 63.7777),
+            (3, 3, 64.6666)
+        ]
+    );
+
+    assert_eq!(sparse.data.values().count(), sparse.header.nrows);
+    assert_eq!(
+        sparse.data.values_with_index().nth(1),
+        Some((1, 2, sparse.data.sparse_data()[1].2))
+    );
+}
+
+#[test]
+fn index() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+
+    assert_eq!(isg.data[(0, 1)], Some(31.2222));
+    assert_eq!(isg.data[(3, 5)], None);
+    assert_eq!(isg.data.grid_data()[(0, 1)], Some(31.2222));
+    assert_eq!(isg.data.get(0, 1), Some(&Some(31.2222)));
+    assert_eq!(isg.data.get(4, 0), None);
+
+    isg.data[(3, 5)] = Some(1.0);
+    assert_eq!(isg.data.get(3, 5), Some(&Some(1.0)));
+    *isg.data.get_mut(3, 5).unwrap() = None;
+    assert_eq!(isg.data[(3, 5)], None);
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    assert_eq!(isg.data.get(0, 0), None);
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds: the shape is (4, 6) but the index is (0, 6)")]
+fn index_out_of_bounds() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    let _ = isg.data[(0, 6)];
+}
+
+#[test]
+fn set() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+
+    assert!(isg.data.set(3, 5, 1.0));
+    assert_eq!(isg.data[(3, 5)], Some(1.0));
+    assert!(isg.data.set_nodata(0, 0));
+    assert_eq!(isg.data[(0, 0)], None);
+
+    assert!(!isg.data.set(isg.header.nrows, 0, 1.0));
+    assert!(!isg.data.set_nodata(0, isg.header.ncols));
+    assert!(!isg.data.set(0, 0, f64::NAN));
+    assert_eq!(isg.data[(0, 0)], None);
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    assert!(!isg.data.set(0, 0, 1.0));
+    assert!(!isg.data.set_nodata(0, 0));
+}
+
+#[test]
+fn shape() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    assert_eq!(isg.data.shape(), (isg.header.nrows, isg.header.ncols));
+    assert_eq!(isg.data.len(), 24);
+    assert!(!isg.data.is_empty());
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    assert_eq!(isg.data.shape(), (isg.header.nrows, isg.header.ncols));
+    assert_eq!(isg.data.len(), isg.header.nrows);
+
+    assert!(Data::new_sparse(Vec::<(Coord, Coord, f64)>::new()).is_empty());
+    assert!(Data::Grid(Grid::filled(3, 0, None)).is_empty());
+}
@@ -0,0 +1,138 @@
+use std::fs;
+
+use libisg::{
+    from_str, to_string_with_options, Coord, CoordUnits, CreationDateFormat, Data, DataBounds,
+    DmsFormat, OptionalHeaders, WriteOptions,
+};
+
+#[test]
+fn dms_format() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    let options = WriteOptions::default();
+    assert_eq!(to_string_with_options(&isg, &options), s);
+
+    let options = WriteOptions {
+        dms: DmsFormat {
+            degree_width: 3,
+            zero_pad: true,
+            plus_sign: false,
+            hemisphere: false,
+        },
+        ..Default::default()
+    };
+    let actual = to_string_with_options(&isg, &options);
+    assert!(actual.contains("lat min        = 039°50'00\"\n"));
+    assert!(actual.contains("lon max        = 121°50'00\"\n"));
+    assert!(actual.contains("delta lat      = 000°20'00\"\n"));
+    assert_eq!(from_str(&actual).unwrap(), isg);
+
+    let options = WriteOptions {
+        dms: DmsFormat {
+            degree_width: 5,
+            zero_pad: false,
+            plus_sign: true,
+            hemisphere: false,
+        },
+        ..Default::default()
+    };
+    let actual = to_string_with_options(&isg, &options);
+    assert!(actual.contains("lat min        =   +39°50'00\"\n"));
+    assert!(actual.contains("delta lat      =    +0°20'00\"\n"));
+    assert_eq!(from_str(&actual).unwrap(), isg);
+}
+
+#[test]
+fn dms_hemisphere() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    if let DataBounds::GridGeodetic {
+        lat_min,
+        lon_min,
+        lon_max,
+        ..
+    } = &mut isg.header.data_bounds
+    {
+        *lat_min = Coord::with_signed_dms(true, 0, 20, 0);
+        *lon_min = Coord::with_dms(-121, 50, 0);
+        *lon_max = Coord::with_dms(-119, 50, 0);
+    }
+
+    let options = WriteOptions {
+        dms: DmsFormat {
+            plus_sign: true,
+            hemisphere: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let actual = to_string_with_options(&isg, &options);
+    assert!(actual.contains("lat min        =    0°20'00\"S\n"));
+    assert!(actual.contains("lat max        =   41°10'00\"N\n"));
+    assert!(actual.contains("lon min        =  121°50'00\"W\n"));
+    assert!(actual.contains("lon max        =  119°50'00\"W\n"));
+    assert!(actual.contains("delta lat      =   +0°20'00\"\n"));
+    assert_eq!(from_str(&actual).unwrap(), isg);
+
+    // sparse data
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    assert!(isg.convert_coord_units(CoordUnits::DMS));
+    let actual = to_string_with_options(&isg, &options);
+    assert!(actual.contains("  40°00'00\"N  120°00'00\"E"));
+    assert_eq!(from_str(&actual).unwrap(), isg);
+}
+
+#[test]
+fn creation_date_format() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    let options = WriteOptions {
+        creation_date: CreationDateFormat::Iso,
+        ..Default::default()
+    };
+    let actual = to_string_with_options(&isg, &options);
+    assert!(actual.contains("creation date  =  2020-05-31\n"));
+    assert_eq!(from_str(&actual).unwrap(), isg);
+}
+
+#[test]
+fn overflow_width() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    if let Data::Grid(data) = &mut isg.data {
+        data[(0, 0)] = Some(123456.789);
+    }
+
+    let s = isg.to_string();
+    let lines: Vec<_> = s
+        .lines()
+        .skip_while(|line| !line.starts_with("end_of_head"))
+        .skip(1)
+        .collect();
+    assert_eq!(
+        lines[0],
+        "123456.7890     31.2222     32.3456     33.4444     34.5678     36.6666"
+    );
+    assert!(lines.iter().all(|line| line.len() == lines[0].len()));
+    assert_eq!(from_str(&s).unwrap(), isg);
+}
+
+#[test]
+fn optional_headers() {
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    let options = WriteOptions {
+        optional_headers: OptionalHeaders::Omit,
+        ..Default::default()
+    };
+    let omitted = to_string_with_options(&isg, &options);
+    assert!(!omitted.contains("---"));
+    assert!(omitted.contains("nodata"));
+    assert_eq!(from_str(&omitted).unwrap(), isg);
+
+    assert_eq!(to_string_with_options(&isg, &WriteOptions::default()), s);
+}
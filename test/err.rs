@@ -637,3 +637,127 @@ end_of_head ==================================================
         "too short data row, expected 20 row(s)"
     );
 }
+
+#[test]
+fn huge_data_shape() {
+    let s = r##"begin_of_head ================================================
+model name     : EXAMPLE
+model year     : 2020
+model type     : gravimetric
+data type      : geoid
+data units     : meters
+data format    : grid
+data ordering  : N-to-S, W-to-E
+ref ellipsoid  : GRS80
+ref frame      : ITRF2014
+height datum   : ---
+tide system    : mean-tide
+coord type     : geodetic
+coord units    : dms
+map projection : ---
+EPSG code      : 7912
+lat min        =   39°50'00"
+lat max        =   41°10'00"
+lon min        =  119°50'00"
+lon max        =  121°50'00"
+delta lat      =    0°20'00"
+delta lon      =    0°20'00"
+nrows          =      200000
+ncols          =      200000
+nodata         =  -9999.0000
+creation date  =  31/05/2020
+ISG format     =         2.0
+end_of_head ==================================================
+   30.1234    31.2222    32.3456    33.4444    34.5678    36.6666
+"##;
+    let a = from_str(s);
+    assert_eq!(
+        a.unwrap_err().to_string(),
+        "too short data column, expected 200000 column(s) (line: 29)"
+    );
+
+    let s = s
+        .replace("data format    : grid", "data format    : sparse")
+        .replace(
+            "data ordering  : N-to-S, W-to-E",
+            "data ordering  : lat, lon, N",
+        )
+        .replace("coord units    : dms", "coord units    : deg")
+        .replace(
+            "lat min        =   39°50'00\"",
+            "lat min        =   40.000000",
+        )
+        .replace(
+            "lat max        =   41°10'00\"",
+            "lat max        =   41.000000",
+        )
+        .replace(
+            "lon min        =  119°50'00\"",
+            "lon min        =  120.000000",
+        )
+        .replace(
+            "lon max        =  121°50'00\"",
+            "lon max        =  121.666667",
+        )
+        .replace("delta lat      =    0°20'00\"", "delta lat      = ---")
+        .replace("delta lon      =    0°20'00\"", "delta lon      = ---")
+        .replace(
+            "ncols          =      200000",
+            "ncols          =           3",
+        )
+        .replace(
+            "nrows          =      200000",
+            &format!("nrows          = {}", usize::MAX),
+        )
+        .replace(
+            "   30.1234    31.2222    32.3456    33.4444    34.5678    36.6666",
+            "  40.000000  120.000000    30.1234",
+        );
+    let a = from_str(&s);
+    assert_eq!(
+        a.unwrap_err().to_string(),
+        format!("too short data row, expected {} row(s)", usize::MAX)
+    );
+}
+
+#[test]
+fn stray_line() {
+    let s = r##"begin_of_head ================================================
+model name     : EXAMPLE
+model year     : 2020
+model type     : gravimetric
+data type      : geoid
+data units     : meters
+data format    : sparse
+data ordering  : lat, lon, N
+ref ellipsoid  : GRS80
+ref frame      : ITRF2014
+height datum   : ---
+tide system    : mean-tide
+coord type     : geodetic
+coord units    : deg
+map projection : ---
+EPSG code      : 7912
+lat min        =   40.000000
+lat max        =   41.000000
+lon min        =  120.000000
+lon max        =  121.666667
+delta lat      = ---
+delta lon      = ---
+nrows          =           2
+ncols          =           3
+nodata         =  -9999.0000
+creation date  =  31/05/2020
+ISG format     =         2.0
+end_of_head ==================================================
+  40.000000  120.000000    30.1234
+nrows          =           2
+  40.000000  120.666667    32.3456
+"##;
+    let a = from_str(s).unwrap_err();
+    assert!(a.is_data_section());
+    assert_eq!(
+        a.to_string(),
+        "unexpected non-data line: `nrows          =           2` (line: 30)"
+    );
+}
@@ -0,0 +1,43 @@
+use std::fs;
+
+use libisg::{from_str, FillMethod};
+
+#[test]
+fn fill_nodata() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    let mut nearest = isg.clone();
+    assert_eq!(nearest.fill_nodata(FillMethod::Nearest, 0), 0);
+    assert_eq!(nearest.fill_nodata(FillMethod::Nearest, 2), 4);
+    assert_eq!(nearest.data[(2, 4)], isg.data[(1, 4)]);
+    assert_eq!(nearest.data[(3, 5)], isg.data[(1, 5)]);
+    assert_eq!(nearest.nodata_count(), 0);
+
+    let mut idw = isg.clone();
+    assert_eq!(idw.fill_nodata(FillMethod::IDW, 1), 3);
+    // (2, 5): (1, 4) at distance^2 = 2 and (1, 5) at distance^2 = 1
+    let (a, b) = (isg.data[(1, 4)].unwrap(), isg.data[(1, 5)].unwrap());
+    let expected = (a / 2.0 + b) / (1.0 / 2.0 + 1.0);
+    assert!((idw.data[(2, 5)].unwrap() - expected).abs() < 1e-9);
+    assert_eq!(idw.data[(3, 5)], None);
+
+    let mut laplace = isg.clone();
+    assert_eq!(laplace.fill_nodata(FillMethod::Laplace, 2), 4);
+    // harmonic, each filled cell is the mean of its neighbors
+    let data = &laplace.data;
+    let mean = |values: &[f64]| values.iter().sum::<f64>() / values.len() as f64;
+    let expected = mean(&[
+        data[(2, 3)].unwrap(),
+        data[(1, 4)].unwrap(),
+        data[(3, 4)].unwrap(),
+        data[(2, 5)].unwrap(),
+    ]);
+    assert!((data[(2, 4)].unwrap() - expected).abs() < 1e-6);
+    let expected = mean(&[data[(2, 5)].unwrap(), data[(3, 4)].unwrap()]);
+    assert!((data[(3, 5)].unwrap() - expected).abs() < 1e-6);
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    assert_eq!(isg.fill_nodata(FillMethod::Nearest, 1), 0);
+}
@@ -0,0 +1,126 @@
+use std::fs;
+
+use libisg::{from_str, Coord, DataOrdering, Spike, SpikeAction};
+
+#[test]
+fn smooth_gaussian() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    assert_eq!(isg.smooth_gaussian(0.0).unwrap(), isg);
+
+    let smoothed = isg.smooth_gaussian(0.5).unwrap();
+    assert_eq!(smoothed.header, isg.header);
+    assert_eq!(smoothed.data[(3, 4)], None);
+
+    // normalized by weights of defined cells, the kernel is truncated at 2 cells
+    let (mut sum, mut weight) = (0.0, 0.0);
+    for i in 1..4_usize {
+        for j in 1..6_usize {
+            if let Some(v) = isg.data[(i, j)] {
+                let d2 = ((i as f64 - 3.0).powi(2) + (j as f64 - 3.0).powi(2)) / 0.25;
+                let w = (-0.5 * d2).exp();
+                sum += w * v;
+                weight += w;
+            }
+        }
+    }
+    assert!((smoothed.data[(3, 3)].unwrap() - sum / weight).abs() < 1e-9);
+
+    // a constant surface is unchanged
+    let constant = isg.map_values(|_| 1.0).smooth_gaussian(2.0).unwrap();
+    assert!(constant.data.values().all(|v| (v - 1.0).abs() < 1e-12));
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    assert!(isg.smooth_gaussian(1.0).is_none());
+}
+
+#[test]
+fn despike() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let expected = from_str(&s).unwrap();
+
+    // no spikes on the smooth surface
+    let mut isg = expected.clone();
+    assert!(isg.despike(1, 15.0, SpikeAction::Replace).is_empty());
+    assert_eq!(isg, expected);
+
+    isg.data[(0, 0)] = Some(-100.0);
+    isg.data[(2, 1)] = Some(500.0);
+    let spikes = isg.despike(1, 15.0, SpikeAction::Replace);
+    assert_eq!(
+        spikes,
+        vec![
+            Spike {
+                row: 0,
+                col: 0,
+                value: -100.0,
+                median: (31.2222 + 41.1111) / 2.0,
+            },
+            Spike {
+                row: 2,
+                col: 1,
+                value: 500.0,
+                median: 53.6543,
+            },
+        ]
+    );
+    assert_eq!(isg.data[(0, 0)], Some((31.2222 + 41.1111) / 2.0));
+    assert_eq!(isg.data[(2, 1)], Some(53.6543));
+    assert_eq!(isg.nodata_count(), expected.nodata_count());
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    assert!(isg.despike(1, 0.0, SpikeAction::Nodata).is_empty());
+}
+
+#[test]
+fn detect_outliers() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    assert!(isg.detect_outliers(1, 3.0).is_empty());
+
+    isg.data[(1, 2)] = Some(1000.0);
+    let outliers = isg.detect_outliers(1, 3.0);
+    assert_eq!(outliers.len(), 1);
+    let outlier = outliers[0];
+    assert_eq!((outlier.row, outlier.col, outlier.value), (1, 2, 1000.0));
+    assert_eq!(
+        (outlier.a, outlier.b),
+        (Coord::with_dms(40, 50, 0), Coord::with_dms(120, 30, 0))
+    );
+    // of the neighbors, excluding the cell itself
+    let neighbors = [
+        31.2222, 32.3456, 33.4444, 42.2345, 44.4567, 52.9753, 53.6543, 54.8642,
+    ];
+    let mean = neighbors.iter().sum::<f64>() / 8.0;
+    let var = neighbors.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / 8.0;
+    assert!((outlier.mean - mean).abs() < 1e-9);
+    assert!((outlier.std - var.sqrt()).abs() < 1e-9);
+    // not modified
+    assert_eq!(isg.data[(1, 2)], Some(1000.0));
+
+    // a constant surface
+    let mut constant = isg.map_values(|_| 1.0);
+    assert!(constant.detect_outliers(2, 1.0).is_empty());
+    constant.data[(0, 0)] = Some(1.5);
+    let outliers = constant.detect_outliers(2, 1.0);
+    assert_eq!(
+        outliers.iter().map(|o| (o.row, o.col)).collect::<Vec<_>>(),
+        [(0, 0)]
+    );
+
+    // coordinates respect data ordering
+    assert!(isg.reorder(DataOrdering::S2NW2E));
+    let outliers = isg.detect_outliers(1, 3.0);
+    assert_eq!((outliers[0].row, outliers[0].col), (2, 2));
+    assert_eq!(outliers[0].a, Coord::with_dms(40, 50, 0));
+
+    // no neighbors
+    assert!(isg.detect_outliers(0, 0.0).is_empty());
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    assert!(isg.detect_outliers(1, 0.0).is_empty());
+}
@@ -0,0 +1,32 @@
+use std::fs;
+
+use libisg::{from_str, GridFlags};
+
+#[test]
+fn flags() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+
+    let mut flags = GridFlags::new(isg.header.nrows, isg.header.ncols);
+    assert!(flags.set(0, 1, 2));
+    assert!(flags.set(1, 0, 3));
+    assert!(flags.set(3, 5, 2));
+    assert!(!flags.set(4, 0, 1));
+    assert_eq!(flags.get(0, 1), Some(2));
+    assert_eq!(flags.get(4, 0), None);
+
+    assert_eq!(
+        flags.filter(|flag| flag >= 2).collect::<Vec<_>>(),
+        vec![(0, 1), (1, 0), (3, 5)]
+    );
+
+    // companion file
+    let companion = flags.to_isg(&isg.header).to_string();
+    let actual = GridFlags::from_isg(&from_str(&companion).unwrap()).unwrap();
+    assert_eq!(actual, flags);
+
+    // (3, 5) is already nodata
+    assert_eq!(isg.mask_by_flags(&flags, |flag| flag == 2), 1);
+    assert_eq!(isg.data.grid_data().get(0, 1), Some(&None));
+    assert_eq!(isg.data.grid_data().get(1, 0), Some(&Some(41.1111)));
+}
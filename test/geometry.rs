@@ -0,0 +1,292 @@
+use std::fs;
+
+use libisg::{
+    from_str, CellRef, ConflictPolicy, Coord, CoordUnits, Data, DataBounds, DataOrdering, Grid, ISG,
+};
+
+#[test]
+fn join_points() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    let actual = isg.join_points([(41.1, 119.9), (40.2, 121.5), (40.5, 120.5), (0.0, 0.0)]);
+    assert_eq!(
+        actual,
+        vec![
+            CellRef::Value { row: 0, col: 0 },
+            CellRef::Nodata { row: 3, col: 5 },
+            CellRef::Value { row: 2, col: 2 },
+            CellRef::Outside,
+        ]
+    );
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    assert_eq!(isg.join_points([(40.0, 120.0)]), vec![CellRef::Outside]);
+}
+
+#[test]
+fn cells() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    let cells: Vec<_> = isg.cells().collect();
+    assert_eq!(cells.len(), 24);
+    assert_eq!(
+        cells[1],
+        (
+            Coord::with_dms(41, 10, 0),
+            Coord::with_dms(120, 10, 0),
+            Some(31.2222)
+        )
+    );
+    assert_eq!(
+        cells[23],
+        (
+            Coord::with_dms(40, 10, 0),
+            Coord::with_dms(121, 30, 0),
+            None
+        )
+    );
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    let expected = (
+        Coord::with_dec(40.0),
+        Coord::with_dec(120.333333),
+        Some(31.2222),
+    );
+    assert_eq!(isg.cells().nth(1), Some(expected));
+
+    // (east, north) records are swapped
+    isg.header.data_ordering = Some(DataOrdering::EastNorthN);
+    assert_eq!(
+        isg.cells().nth(1),
+        Some((expected.1, expected.0, expected.2))
+    );
+}
+
+#[test]
+fn value_at() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+
+    assert_eq!(isg.value_at(40.5, 120.5), Some(isg.data[(2, 2)].unwrap()));
+    assert_eq!(isg.value_at(40.2, 121.5), None);
+    assert_eq!(isg.value_at(0.0, 0.0), None);
+
+    assert!(isg.set_at(40.2, 121.5, 1.0));
+    assert_eq!(isg.data[(3, 5)], Some(1.0));
+    assert!(!isg.set_at(0.0, 0.0, 1.0));
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    assert_eq!(isg.value_at(40.0, 120.0), None);
+    assert!(!isg.set_at(40.0, 120.0, 1.0));
+}
+
+#[test]
+fn cell_index() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+
+    // cell centers
+    for (row, col) in [(0, 0), (1, 2), (3, 5)] {
+        let (a, b) = (
+            41.0 + 10.0 / 60.0 - row as f64 / 3.0,
+            119.0 + 50.0 / 60.0 + col as f64 / 3.0,
+        );
+        assert_eq!(isg.cell_index(a, b), Some((row, col)));
+    }
+
+    // borders
+    assert_eq!(isg.cell_index(41.0, 120.0), Some((1, 1)));
+    assert_eq!(
+        isg.cell_index(41.0 + 20.0 / 60.0, 119.0 + 40.0 / 60.0),
+        Some((0, 0))
+    );
+    assert_eq!(isg.cell_index(40.0, 121.0 + 40.0 / 60.0), Some((3, 5)));
+
+    // outside
+    assert_eq!(isg.cell_index(40.0 - 1e-6, 120.0), None);
+    assert_eq!(isg.cell_index(41.0 + 20.0 / 60.0 + 1e-6, 120.0), None);
+    assert_eq!(isg.cell_index(40.5, 121.0 + 40.0 / 60.0 + 1e-6), None);
+
+    // respects ordering
+    let value = isg.value_at(41.1, 119.9);
+    assert!(isg.reorder(DataOrdering::S2NE2W));
+    assert_eq!(isg.cell_index(41.1, 119.9), Some((3, 5)));
+    assert_eq!(isg.value_at(41.1, 119.9), value);
+    assert!(isg.set_at(41.1, 119.9, 1.0));
+    assert_eq!(isg.data[(3, 5)], Some(1.0));
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    assert_eq!(isg.cell_index(40.0, 120.0), None);
+}
+
+#[test]
+fn cell_coord() {
+    fn to_f64(coord: Coord) -> f64 {
+        match coord {
+            Coord::DMS {
+                negative,
+                degree,
+                minutes,
+                second,
+            } => {
+                let value = degree as f64 + minutes as f64 / 60.0 + second as f64 / 3600.0;
+                if negative {
+                    -value
+                } else {
+                    value
+                }
+            }
+            Coord::Dec(value) => value,
+        }
+    }
+
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+
+    assert_eq!(
+        isg.cell_coord(0, 0),
+        Some((Coord::with_dms(41, 10, 0), Coord::with_dms(119, 50, 0)))
+    );
+    assert_eq!(
+        isg.cell_coord(3, 5),
+        Some((Coord::with_dms(40, 10, 0), Coord::with_dms(121, 30, 0)))
+    );
+    assert_eq!(isg.cell_coord(4, 0), None);
+    assert_eq!(isg.cell_coord(0, 6), None);
+
+    let key = |c: &(Coord, Coord, Option<f64>)| format!("{} {}", c.0, c.1);
+    let mut cells: Vec<_> = isg.cells().collect();
+    cells.sort_by_key(key);
+
+    for ordering in [
+        DataOrdering::N2SW2E,
+        DataOrdering::S2NW2E,
+        DataOrdering::N2SE2W,
+        DataOrdering::S2NE2W,
+    ] {
+        assert!(isg.reorder(ordering));
+
+        // inverse of cell_index
+        for row in 0..4 {
+            for col in 0..6 {
+                let (a, b) = isg.cell_coord(row, col).unwrap();
+                assert_eq!(isg.cell_index(to_f64(a), to_f64(b)), Some((row, col)));
+            }
+        }
+
+        // cells follow data ordering
+        let mut actual: Vec<_> = isg.cells().collect();
+        assert_eq!(actual[0].2, isg.data[(0, 0)]);
+        actual.sort_by_key(key);
+        assert_eq!(actual, cells);
+    }
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    assert_eq!(isg.cell_coord(0, 0), None);
+}
+
+#[test]
+fn antimeridian() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+
+    // 170°E to 170°W
+    isg.header.coord_units = CoordUnits::Deg;
+    isg.header.data_bounds = DataBounds::GridGeodetic {
+        lat_min: Coord::with_dec(0.0),
+        lat_max: Coord::with_dec(10.0),
+        lon_min: Coord::with_dec(170.0),
+        lon_max: Coord::with_dec(-170.0),
+        delta_lat: Coord::with_dec(5.0),
+        delta_lon: Coord::with_dec(5.0),
+    };
+    isg.header.nrows = 3;
+    isg.header.ncols = 5;
+    isg.data = Data::Grid(Grid::from_vec(3, 5, (0..15).map(|v| Some(v as f64)).collect()).unwrap());
+
+    // query
+    assert_eq!(isg.value_at(10.0, 175.0), Some(1.0));
+    assert_eq!(isg.value_at(10.0, -175.0), Some(3.0));
+    assert_eq!(isg.value_at(10.0, 185.0), Some(3.0));
+    assert_eq!(isg.value_at(0.0, -170.0), Some(14.0));
+    assert_eq!(isg.value_at(0.0, -160.0), None);
+    assert_eq!(isg.value_at(0.0, 160.0), None);
+    let lons: Vec<_> = isg.cells().take(5).map(|(_, b, _)| b).collect();
+    assert_eq!(
+        lons,
+        [170.0, 175.0, 180.0, -175.0, -170.0].map(Coord::with_dec)
+    );
+
+    // crop
+    let east = isg.subgrid(0..3, 2..5).unwrap();
+    assert!(matches!(
+        east.header.data_bounds,
+        DataBounds::GridGeodetic { lon_min, lon_max, .. }
+            if lon_min == Coord::with_dec(180.0) && lon_max == Coord::with_dec(-170.0)
+    ));
+    assert_eq!(east.value_at(5.0, -175.0), Some(8.0));
+    let west = isg.subgrid(0..3, 0..2).unwrap();
+    assert!(matches!(
+        west.header.data_bounds,
+        DataBounds::GridGeodetic { lon_min, lon_max, .. }
+            if lon_min == Coord::with_dec(170.0) && lon_max == Coord::with_dec(175.0)
+    ));
+
+    // merge
+    assert_eq!(
+        ISG::merge(&[&west, &east], ConflictPolicy::First).unwrap(),
+        isg
+    );
+    assert_eq!(
+        ISG::merge(&[&east, &west], ConflictPolicy::First)
+            .unwrap()
+            .data,
+        isg.data
+    );
+
+    // conversion
+    let sparse = isg.to_sparse();
+    assert!(matches!(
+        sparse.header.data_bounds,
+        DataBounds::SparseGeodetic { lon_max, .. } if lon_max == Coord::with_dec(-170.0)
+    ));
+    let grid = sparse
+        .to_grid(Coord::with_dec(5.0), Coord::with_dec(5.0))
+        .unwrap();
+    assert_eq!(grid.data, isg.data);
+}
+
+#[test]
+fn join_points_reordered() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    assert!(isg.reorder(DataOrdering::S2NW2E));
+
+    let actual = isg.join_points([(41.1, 119.9), (40.2, 121.5), (40.5, 120.5), (0.0, 0.0)]);
+    assert_eq!(
+        actual,
+        vec![
+            CellRef::Value { row: 3, col: 0 },
+            CellRef::Nodata { row: 0, col: 5 },
+            CellRef::Value { row: 1, col: 2 },
+            CellRef::Outside,
+        ]
+    );
+
+    assert!(isg.reorder(DataOrdering::N2SE2W));
+    let actual = isg.join_points([(41.1, 119.9), (40.2, 121.5)]);
+    assert_eq!(
+        actual,
+        vec![
+            CellRef::Value { row: 0, col: 5 },
+            CellRef::Nodata { row: 3, col: 0 },
+        ]
+    );
+}
@@ -0,0 +1,82 @@
+use std::fs;
+
+use libisg::{from_str, Coord, CoordUnits, Data, DataOrdering, Header};
+
+#[test]
+fn gradient() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+
+    // a plane of 1.0 per row (toward south) and 2.0 per column (toward east)
+    isg.data = Data::new_grid((0..4).map(|row| (0..6).map(move |col| (row + 2 * col) as f64)));
+    let (east, north) = isg.gradient().unwrap();
+    assert_eq!(east.header, isg.header);
+
+    let step = (20.0_f64 / 60.0).to_radians() * 6_371_008.8;
+    for row in 0..4 {
+        let lat = 41.0 + 10.0 / 60.0 - row as f64 / 3.0;
+        for col in 0..6 {
+            let expected = 2.0 / (step * lat.to_radians().cos());
+            assert!((east.data[(row, col)].unwrap() - expected).abs() < 1e-15);
+            let expected = -1.0 / step;
+            assert!((north.data[(row, col)].unwrap() - expected).abs() < 1e-15);
+        }
+    }
+
+    // respects data ordering
+    let mut reordered = isg.clone();
+    assert!(reordered.reorder(DataOrdering::S2NE2W));
+    let (reordered_east, reordered_north) = reordered.gradient().unwrap();
+    for row in 0..4 {
+        for col in 0..6 {
+            let (actual, expected) = (
+                reordered_east.data[(3 - row, 5 - col)].unwrap(),
+                east.data[(row, col)].unwrap(),
+            );
+            assert!((actual - expected).abs() < 1e-15);
+            let (actual, expected) = (
+                reordered_north.data[(3 - row, 5 - col)].unwrap(),
+                north.data[(row, col)].unwrap(),
+            );
+            assert!((actual - expected).abs() < 1e-15);
+        }
+    }
+
+    // one-sided differences next to nodata, and nodata
+    isg.data[(1, 1)] = None;
+    isg.data[(0, 2)] = Some(100.0);
+    let (east, north) = isg.gradient().unwrap();
+    assert_eq!(east.data[(1, 1)], None);
+    assert_eq!(north.data[(1, 1)], None);
+    let lat = (41.0 + 10.0 / 60.0_f64).to_radians();
+    let expected = (100.0 - 0.0) / (2.0 * step * lat.cos());
+    assert!((east.data[(0, 1)].unwrap() - expected).abs() < 1e-15);
+    let expected = (3.0 - 1.0) / (step * (lat - (20.0_f64 / 60.0).to_radians()).cos());
+    assert!((east.data[(1, 2)].unwrap() - expected).abs() < 1e-15);
+
+    // no defined neighbors
+    let mut isg = isg.subgrid(0..1, 0..1).unwrap();
+    let (east, north) = isg.gradient().unwrap();
+    assert_eq!((east.data[(0, 0)], north.data[(0, 0)]), (None, None));
+
+    // projected in feet
+    isg.header = Header::grid_projected(
+        CoordUnits::Feet,
+        Coord::Dec(0.0),
+        Coord::Dec(100.0),
+        Coord::Dec(0.0),
+        Coord::Dec(200.0),
+        Coord::Dec(100.0),
+        Coord::Dec(100.0),
+        2,
+        3,
+    );
+    isg.data = Data::new_grid([[0.0, 1.0, 2.0], [-3.0, -2.0, -1.0]]);
+    let (east, north) = isg.gradient().unwrap();
+    assert!((east.data[(0, 1)].unwrap() - 1.0 / 30.48).abs() < 1e-15);
+    assert!((north.data[(1, 1)].unwrap() - 3.0 / 30.48).abs() < 1e-15);
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    assert_eq!(isg.gradient(), None);
+}
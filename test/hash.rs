@@ -0,0 +1,56 @@
+use std::fs;
+
+use libisg::{from_str, Coord, Data};
+
+#[test]
+fn content_hash() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    let hash = isg.content_hash();
+
+    // independent of format quirks
+    for path in [
+        "rsc/isg/example.1.compact.isg",
+        "rsc/isg/example.1.empty_comment.isg",
+        "rsc/isg/example.1.many_space.isg",
+        "rsc/isg/example.1.minify.isg",
+    ] {
+        let s = fs::read_to_string(path).unwrap();
+        assert_eq!(from_str(&s).unwrap().content_hash(), hash, "{}", path);
+    }
+    assert_eq!(from_str(&isg.to_string()).unwrap().content_hash(), hash);
+
+    let mut other = isg.clone();
+    other.comment = "other".into();
+    other.header.nodata = Some(-99999.0);
+    assert_eq!(other.content_hash(), hash);
+
+    // stable across platforms and runs
+    assert_eq!(hash, 0x9e31_f2d7_4205_1d6e);
+
+    // content
+    let mut other = isg.clone();
+    other.header.model_name = None;
+    assert_ne!(other.content_hash(), hash);
+
+    let mut other = isg.clone();
+    other.data[(3, 5)] = Some(0.0);
+    assert_ne!(other.content_hash(), hash);
+    other.data[(3, 5)] = Some(-0.0);
+    let zero = other.content_hash();
+    other.data[(3, 5)] = Some(0.0);
+    assert_eq!(other.content_hash(), zero);
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let mut sparse = from_str(&s).unwrap();
+    let hash = sparse.content_hash();
+    if let Data::Sparse(data) = &mut sparse.data {
+        data.swap(0, 1);
+    }
+    assert_ne!(sparse.content_hash(), hash);
+    if let Data::Sparse(data) = &mut sparse.data {
+        data.swap(0, 1);
+        data[0].0 = Coord::Dec(40.0 + 1e-12);
+    }
+    assert_ne!(sparse.content_hash(), hash);
+}
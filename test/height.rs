@@ -0,0 +1,33 @@
+use std::fs;
+
+use libisg::{from_str, DataType, DataUnits};
+
+#[test]
+fn height() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    assert_eq!(isg.header.data_type, Some(DataType::Geoid));
+
+    let (lat, lon) = (41.0, 120.0);
+    let n = isg.interpolate(lat, lon).unwrap();
+    let h = isg.to_orthometric(lat, lon, 100.0).unwrap();
+    assert!((h - (100.0 - n)).abs() < 1e-9);
+    let h = isg.to_ellipsoidal(lat, lon, h).unwrap();
+    assert!((h - 100.0).abs() < 1e-9);
+
+    // normal height
+    isg.header.data_type = Some(DataType::QuasiGeoid);
+    let h = isg.to_orthometric(lat, lon, 100.0).unwrap();
+    assert!((h - (100.0 - n)).abs() < 1e-9);
+
+    // N in feet
+    isg.header.data_units = Some(DataUnits::Feet);
+    let h = isg.to_orthometric(lat, lon, 100.0).unwrap();
+    assert!((h - (100.0 - n * 0.3048)).abs() < 1e-9);
+    let h = isg.to_ellipsoidal(lat, lon, 100.0).unwrap();
+    assert!((h - (100.0 + n * 0.3048)).abs() < 1e-9);
+
+    // nodata and outside
+    assert_eq!(isg.to_orthometric(40.5, 121.5, 100.0), None);
+    assert_eq!(isg.to_ellipsoidal(0.0, 0.0, 100.0), None);
+}
@@ -0,0 +1,312 @@
+use std::fs;
+
+use libisg::{
+    from_str, Coord, Data, DataOrdering, Extrapolation, GridView, Interpolator, Method, Sample,
+};
+
+#[test]
+fn interpolate() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+
+    // cell centers
+    assert_eq!(
+        isg.interpolate(41.0 + 10.0 / 60.0, 119.0 + 50.0 / 60.0),
+        Some(30.1234)
+    );
+    assert_eq!(
+        isg.interpolate(40.0 + 10.0 / 60.0, 119.0 + 50.0 / 60.0),
+        isg.data[(3, 0)]
+    );
+
+    // center of four cells
+    let expected =
+        (30.1234 + 31.2222 + isg.data[(1, 0)].unwrap() + isg.data[(1, 1)].unwrap()) / 4.0;
+    let actual = isg.interpolate(41.0, 120.0).unwrap();
+    assert!((actual - expected).abs() < 1e-9);
+
+    // surrounded by nodata
+    assert_eq!(isg.interpolate(40.5, 121.5), None);
+    // outside
+    assert_eq!(isg.interpolate(41.5, 120.0), None);
+    assert_eq!(isg.interpolate(41.0, 119.0), None);
+
+    // respects data ordering
+    assert!(isg.reorder(DataOrdering::S2NE2W));
+    let actual = isg.interpolate(41.0, 120.0).unwrap();
+    assert!((actual - expected).abs() < 1e-9);
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    assert_eq!(isg.interpolate(40.0, 120.0), None);
+}
+
+#[test]
+fn bicubic() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+
+    // reproduces a linear surface
+    for row in 0..4 {
+        for col in 0..4 {
+            isg.data[(row, col)] = Some(10.0 * row as f64 + col as f64);
+        }
+    }
+    let actual = isg
+        .interpolate_with(40.75, 120.25, Method::Bicubic)
+        .unwrap();
+    let expected = isg
+        .interpolate_with(40.75, 120.25, Method::Bilinear)
+        .unwrap();
+    assert!((actual - expected).abs() < 1e-9);
+    assert!((actual - (10.0 * 1.25 + 1.25)).abs() < 1e-9);
+
+    // cell centers
+    let (a, b) = (
+        41.0 + 10.0 / 60.0 - 1.0 / 3.0,
+        119.0 + 50.0 / 60.0 + 1.0 / 3.0,
+    );
+    let actual = isg.interpolate_with(a, b, Method::Bicubic).unwrap();
+    assert!((actual - 11.0).abs() < 1e-9);
+
+    // differs from bilinear on a curved surface
+    isg.data[(1, 1)] = Some(100.0);
+    let actual = isg
+        .interpolate_with(40.75, 120.25, Method::Bicubic)
+        .unwrap();
+    let bilinear = isg
+        .interpolate_with(40.75, 120.25, Method::Bilinear)
+        .unwrap();
+    assert!((actual - bilinear).abs() > 1e-3);
+
+    // falls back to bilinear near edges and nodata
+    for (a, b) in [(41.1, 120.0), (40.5, 121.0)] {
+        assert_eq!(
+            isg.interpolate_with(a, b, Method::Bicubic),
+            isg.interpolate_with(a, b, Method::Bilinear)
+        );
+    }
+}
+
+#[test]
+fn nearest() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    assert_eq!(
+        isg.nearest(41.1, 119.9),
+        Some((
+            Coord::with_dms(41, 10, 0),
+            Coord::with_dms(119, 50, 0),
+            30.1234
+        ))
+    );
+    // outside of the grid
+    assert_eq!(
+        isg.nearest(50.0, 100.0),
+        Some((
+            Coord::with_dms(41, 10, 0),
+            Coord::with_dms(119, 50, 0),
+            30.1234
+        ))
+    );
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    assert_eq!(
+        isg.nearest(40.01, 120.01),
+        Some((Coord::Dec(40.0), Coord::Dec(120.0), 30.1234))
+    );
+
+    // wraps longitude
+    let mut isg = isg;
+    isg.data = Data::Sparse(vec![
+        (Coord::Dec(0.0), Coord::Dec(179.0), 1.0),
+        (Coord::Dec(0.0), Coord::Dec(170.0), 2.0),
+    ]);
+    assert_eq!(isg.nearest(0.0, -179.0).map(|cell| cell.2), Some(1.0));
+
+    isg.data = Data::Sparse(Vec::new());
+    assert_eq!(isg.nearest(0.0, 0.0), None);
+}
+
+#[test]
+fn interpolate_many() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+
+    let points = [(41.0, 120.0), (40.5, 121.5), (41.1, 119.9), (0.0, 0.0)];
+    let expected: Vec<_> = points.iter().map(|&(a, b)| isg.interpolate(a, b)).collect();
+    assert_eq!(isg.interpolate_many(&points), expected);
+    assert_eq!(isg.interpolate_many(&[]), vec![]);
+
+    // spans several chunks under the `rayon` feature
+    let many: Vec<_> = (0..10_000)
+        .map(|i| (40.0 + i as f64 / 10_000.0, 119.5 + i as f64 / 4_000.0))
+        .collect();
+    let values: Vec<_> = many.iter().map(|&(a, b)| isg.interpolate(a, b)).collect();
+    assert!(values.iter().any(Option::is_some));
+    assert_eq!(isg.interpolate_many(&many), values);
+
+    assert!(isg.reorder(DataOrdering::S2NE2W));
+    assert_eq!(isg.interpolate_many(&points), expected);
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    assert_eq!(isg.interpolate_many(&points), vec![None; 4]);
+}
+
+#[test]
+fn interpolator() {
+    struct Index;
+
+    impl Interpolator for Index {
+        fn sample(&self, grid: &GridView, frac_row: f64, frac_col: f64) -> Option<f64> {
+            assert_eq!((grid.nrows(), grid.ncols()), (4, 6));
+            Some(frac_row * 10.0 + frac_col)
+        }
+    }
+
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+
+    let value = isg
+        .interpolate_with(41.0 + 10.0 / 60.0, 120.0 + 10.0 / 60.0, Index)
+        .unwrap();
+    assert!((value - 1.0).abs() < 1e-9);
+    // out of bounds is passed as is
+    let value = isg
+        .interpolate_with(41.5, 120.0 + 10.0 / 60.0, &Index)
+        .unwrap();
+    assert!((value - -9.0).abs() < 1e-9);
+
+    // respects ordering
+    assert!(isg.reorder(DataOrdering::S2NE2W));
+    let value = isg
+        .interpolate_with(41.0 + 10.0 / 60.0, 120.0 + 10.0 / 60.0, Index)
+        .unwrap();
+    assert!((value - 34.0).abs() < 1e-9);
+
+    let method: &dyn Interpolator = &Method::Bilinear;
+    assert_eq!(
+        isg.interpolate_with(41.0, 120.0, method),
+        isg.interpolate(41.0, 120.0)
+    );
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    assert_eq!(isg.interpolate_with(40.0, 120.0, Index), None);
+}
+
+#[test]
+fn extrapolation() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    let extrapolate = |a, b, extrapolation| {
+        isg.interpolate_with_extrapolation(a, b, Method::Bilinear, extrapolation)
+    };
+
+    // inside
+    for extrapolation in [
+        Extrapolation::None,
+        Extrapolation::Clamp,
+        Extrapolation::Linear,
+    ] {
+        assert_eq!(
+            extrapolate(41.0, 120.0, extrapolation),
+            isg.interpolate(41.0, 120.0)
+        );
+    }
+
+    // a quarter cell north of (0, 0)
+    let (a, b) = (41.0 + 15.0 / 60.0, 119.0 + 50.0 / 60.0);
+    assert_eq!(extrapolate(a, b, Extrapolation::None), None);
+    assert_eq!(extrapolate(a, b, Extrapolation::Clamp), Some(30.1234));
+    let expected = 30.1234 + 0.25 * (30.1234 - isg.data[(1, 0)].unwrap());
+    let actual = extrapolate(a, b, Extrapolation::Linear).unwrap();
+    assert!((actual - expected).abs() < 1e-9);
+
+    // a quarter cell north-west of (0, 0)
+    let (a, b) = (41.0 + 15.0 / 60.0, 119.0 + 45.0 / 60.0);
+    assert_eq!(extrapolate(a, b, Extrapolation::Clamp), Some(30.1234));
+    let expected = 30.1234
+        + 0.25 * (30.1234 - isg.data[(1, 0)].unwrap())
+        + 0.25 * (30.1234 - isg.data[(0, 1)].unwrap());
+    let actual = extrapolate(a, b, Extrapolation::Linear).unwrap();
+    assert!((actual - expected).abs() < 1e-9);
+
+    // a quarter cell south of (3, 0)
+    let (a, b) = (40.0 + 5.0 / 60.0, 119.0 + 50.0 / 60.0);
+    assert_eq!(extrapolate(a, b, Extrapolation::Clamp), isg.data[(3, 0)]);
+    let expected =
+        isg.data[(3, 0)].unwrap() + 0.25 * (isg.data[(3, 0)].unwrap() - isg.data[(2, 0)].unwrap());
+    let actual = extrapolate(a, b, Extrapolation::Linear).unwrap();
+    assert!((actual - expected).abs() < 1e-9);
+
+    // beyond half a cell
+    let (a, b) = (41.0 + 21.0 / 60.0, 119.0 + 50.0 / 60.0);
+    assert_eq!(extrapolate(a, b, Extrapolation::Clamp), None);
+    assert_eq!(extrapolate(a, b, Extrapolation::Linear), None);
+
+    // nodata on the edge, south of (3, 5)
+    let (a, b) = (40.0 + 5.0 / 60.0, 121.0 + 30.0 / 60.0);
+    assert_eq!(extrapolate(a, b, Extrapolation::Clamp), None);
+
+    assert_eq!(Extrapolation::default(), Extrapolation::None);
+}
+
+#[test]
+fn sample() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    assert_eq!(
+        isg.sample(41.0 + 10.0 / 60.0, 119.0 + 50.0 / 60.0),
+        Sample::Value(30.1234)
+    );
+    assert_eq!(
+        isg.sample(41.0, 120.0).value(),
+        isg.interpolate(41.0, 120.0)
+    );
+    // nodata inside
+    assert_eq!(isg.sample(40.5, 121.5), Sample::Nodata);
+    assert_eq!(
+        isg.sample_with(40.0 + 10.0 / 60.0, 121.0 + 30.0 / 60.0, Method::Nearest),
+        Sample::Nodata
+    );
+    // outside
+    assert_eq!(isg.sample(41.5, 120.0), Sample::OutOfBounds);
+    assert_eq!(isg.sample(41.0, 119.0), Sample::OutOfBounds);
+    // within half a cell of the edge, the same bounds as `value_at`
+    assert_eq!(
+        isg.sample_with(41.0 + 15.0 / 60.0, 119.0 + 50.0 / 60.0, Method::Nearest),
+        Sample::Value(30.1234)
+    );
+    assert_eq!(
+        isg.sample_with(41.0 + 15.0 / 60.0, 119.0 + 50.0 / 60.0, Method::Bilinear),
+        Sample::Value(30.1234)
+    );
+    assert_eq!(
+        isg.sample(41.0 + 15.0 / 60.0, 119.0 + 45.0 / 60.0).value(),
+        isg.value_at(41.0 + 15.0 / 60.0, 119.0 + 45.0 / 60.0)
+    );
+    assert_eq!(
+        isg.sample(40.0 + 5.0 / 60.0, 121.0 + 10.0 / 60.0),
+        Sample::Nodata
+    );
+    assert_eq!(isg.value_at(40.0 + 5.0 / 60.0, 121.0 + 10.0 / 60.0), None);
+    assert!(isg
+        .cell_index(40.0 + 5.0 / 60.0, 121.0 + 10.0 / 60.0)
+        .is_some());
+    for (a, b) in [(41.0 + 21.0 / 60.0, 120.0), (40.5, 121.0 + 61.0 / 60.0)] {
+        assert_eq!(isg.sample(a, b), Sample::OutOfBounds);
+        assert_eq!(isg.cell_index(a, b), None);
+    }
+
+    assert_eq!(Option::<f64>::from(Sample::Value(1.0)), Some(1.0));
+    assert_eq!(Option::<f64>::from(Sample::Nodata), None);
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    assert_eq!(isg.sample(40.0, 120.0), Sample::OutOfBounds);
+}
@@ -0,0 +1,101 @@
+use std::fs;
+
+use libisg::{from_str, ConflictPolicy, Coord, DataBounds, DataOrdering, ISG};
+
+#[test]
+fn merge() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    let tiles: Vec<_> = isg.tiles(3, 4).map(|view| view.to_isg()).collect();
+    let refs: Vec<_> = tiles.iter().collect();
+    let merged = ISG::merge(&refs, ConflictPolicy::Last).unwrap();
+    assert_eq!(merged.header, isg.header);
+    assert_eq!(merged.data, isg.data);
+
+    // overlap on the column 2 and 3
+    let west = isg.subgrid(0..2, 0..4).unwrap();
+    let east = isg.subgrid(0..2, 2..6).unwrap().map_values(|v| v + 1.0);
+
+    let first = ISG::merge(&[&west, &east], ConflictPolicy::First).unwrap();
+    let last = ISG::merge(&[&west, &east], ConflictPolicy::Last).unwrap();
+    let mean = ISG::merge(&[&west, &east], ConflictPolicy::Mean).unwrap();
+    assert_eq!((first.header.nrows, first.header.ncols), (2, 6));
+    assert_eq!(first.data[(0, 3)], isg.data[(0, 3)]);
+    assert_eq!(last.data[(0, 3)], isg.data[(0, 3)].map(|v| v + 1.0));
+    assert_eq!(mean.data[(0, 3)], isg.data[(0, 3)].map(|v| v + 0.5));
+    assert_eq!(mean.data[(1, 0)], isg.data[(1, 0)]);
+    assert_eq!(mean.data[(1, 5)], isg.data[(1, 5)].map(|v| v + 1.0));
+
+    // not covered
+    let north_west = isg.subgrid(0..1, 0..1).unwrap();
+    let south_east = isg.subgrid(3..4, 3..4).unwrap();
+    let merged = ISG::merge(&[&north_west, &south_east], ConflictPolicy::First).unwrap();
+    assert_eq!((merged.header.nrows, merged.header.ncols), (4, 4));
+    assert_eq!(merged.nodata_count(), 14);
+    assert!(merged.validate().is_ok());
+}
+
+#[test]
+fn merge_err() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    assert_eq!(
+        ISG::merge(&[], ConflictPolicy::First)
+            .unwrap_err()
+            .to_string(),
+        "no grid is given"
+    );
+
+    let mut other = isg.clone();
+    if let DataBounds::GridGeodetic { delta_lat, .. } = &mut other.header.data_bounds {
+        *delta_lat = Coord::with_dms(0, 10, 0);
+    }
+    assert_eq!(
+        ISG::merge(&[&isg, &other], ConflictPolicy::First)
+            .unwrap_err()
+            .to_string(),
+        "delta mismatch"
+    );
+
+    let mut other = isg.clone();
+    if let DataBounds::GridGeodetic { lat_max, .. } = &mut other.header.data_bounds {
+        *lat_max = Coord::with_dms(41, 15, 0);
+    }
+    assert_eq!(
+        ISG::merge(&[&isg, &other], ConflictPolicy::First)
+            .unwrap_err()
+            .to_string(),
+        "grid nodes are not aligned"
+    );
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let sparse = from_str(&s).unwrap();
+    assert!(ISG::merge(&[&isg, &sparse], ConflictPolicy::First).is_err());
+}
+
+#[test]
+fn merge_reordered() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    let mut reordered = isg.clone();
+    assert!(reordered.reorder(DataOrdering::S2NE2W));
+
+    // mixed orderings
+    let mut tiles: Vec<_> = isg.tiles(3, 4).map(|view| view.to_isg()).collect();
+    assert!(tiles[0].reorder(DataOrdering::S2NW2E));
+    assert!(tiles[1].reorder(DataOrdering::N2SE2W));
+    let refs: Vec<_> = tiles.iter().collect();
+    let mut merged = ISG::merge(&refs, ConflictPolicy::Last).unwrap();
+    assert_eq!(merged.header.data_ordering, Some(DataOrdering::S2NW2E));
+    assert!(merged.reorder(DataOrdering::N2SW2E));
+    assert_eq!(merged.header, isg.header);
+    assert_eq!(merged.data, isg.data);
+
+    let tiles: Vec<_> = reordered.tiles(3, 4).map(|view| view.to_isg()).collect();
+    let refs: Vec<_> = tiles.iter().collect();
+    let merged = ISG::merge(&refs, ConflictPolicy::Last).unwrap();
+    assert_eq!(merged.header, reordered.header);
+    assert_eq!(merged.data, reordered.data);
+}
@@ -0,0 +1,98 @@
+use std::fs;
+
+use libisg::{from_str, to_string_with_options, Coord, Data, DataOrdering, WriteOptions};
+
+#[test]
+fn sort_sparse_by_ordering() {
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let expected = from_str(&s).unwrap();
+
+    let mut isg = expected.clone();
+    if let Data::Sparse(data) = &mut isg.data {
+        data.reverse();
+    }
+    assert_ne!(isg, expected);
+
+    // auto-sort on writing
+    let options = WriteOptions {
+        sort_sparse: true,
+        ..Default::default()
+    };
+    assert_eq!(to_string_with_options(&isg, &options), s);
+
+    isg.sort_sparse_by_ordering();
+    assert_eq!(isg, expected);
+
+    isg.header.data_ordering = Some(DataOrdering::N2SW2E);
+    isg.sort_sparse_by_ordering();
+    match &isg.data {
+        Data::Sparse(data) => {
+            assert_eq!(data[0].0, Coord::with_dec(41.0));
+            assert_eq!(data[0].1, Coord::with_dec(120.0));
+            assert_eq!(data[3].0, Coord::with_dec(41.0));
+            assert_eq!(data[3].1, Coord::with_dec(121.0));
+            assert_eq!(data[19].0, Coord::with_dec(40.0));
+        }
+        Data::Grid(_) => unreachable!(),
+    }
+}
+
+#[test]
+fn reorder() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let expected = from_str(&s).unwrap();
+
+    let mut isg = expected.clone();
+    assert!(isg.reorder(DataOrdering::S2NW2E));
+    assert_eq!(isg.header.data_ordering, Some(DataOrdering::S2NW2E));
+    assert_eq!(isg.data.get(0, 0), expected.data.get(3, 0));
+    assert_eq!(isg.data.get(3, 5), expected.data.get(0, 5));
+
+    assert!(isg.reorder(DataOrdering::N2SE2W));
+    assert_eq!(isg.data.get(0, 0), expected.data.get(0, 5));
+    assert_eq!(isg.data.get(3, 1), expected.data.get(3, 4));
+
+    assert!(!isg.reorder(DataOrdering::EastNorthN));
+    assert!(isg.reorder(DataOrdering::N2SW2E));
+    assert_eq!(isg, expected);
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let expected = from_str(&s).unwrap();
+
+    let mut isg = expected.clone();
+    assert!(isg.reorder(DataOrdering::EastNorthN));
+    match &isg.data {
+        Data::Sparse(data) => {
+            assert_eq!(data[0].0, Coord::with_dec(120.0));
+            assert_eq!(data[0].1, Coord::with_dec(40.0));
+        }
+        Data::Grid(_) => unreachable!(),
+    }
+
+    assert!(!isg.reorder(DataOrdering::Zeta));
+    assert!(!isg.reorder(DataOrdering::N2SW2E));
+    assert!(!isg.reorder(DataOrdering::S2NE2W));
+    assert!(isg.reorder(DataOrdering::LatLonN));
+    assert_eq!(isg, expected);
+}
+
+#[test]
+fn flip() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let expected = from_str(&s).unwrap();
+
+    let mut isg = expected.clone();
+    assert!(isg.flip_rows());
+    assert!(isg.flip_cols());
+    assert_eq!(isg.header.data_ordering, Some(DataOrdering::S2NE2W));
+    assert_eq!(isg.data.get(0, 0), expected.data.get(3, 5));
+    assert!(isg.flip_cols());
+    assert_eq!(isg.header.data_ordering, Some(DataOrdering::S2NW2E));
+    assert!(isg.reorder(DataOrdering::N2SW2E));
+    assert_eq!(isg, expected);
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    assert!(!isg.flip_rows());
+    assert!(!isg.flip_cols());
+}
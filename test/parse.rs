@@ -1,4 +1,4 @@
-use libisg::from_str;
+use libisg::{from_str, from_str_lenient, to_string_compact, Header};
 
 use std::fs;
 #[test]
@@ -22,6 +22,31 @@ fn example_3() {
     assert_eq!(s, isg.to_string())
 }
 
+#[test]
+fn hemisphere() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let expected = from_str(&s).unwrap();
+
+    let s = s
+        .replace(
+            "lat min        =   39°50'00\"",
+            "lat min        =   39°50'00\"N",
+        )
+        .replace(
+            "lat max        =   41°10'00\"",
+            "lat max        =   41°10'00\"N",
+        )
+        .replace(
+            "lon min        =  119°50'00\"",
+            "lon min        =  119°50'00\"E",
+        )
+        .replace(
+            "lon max        =  121°50'00\"",
+            "lon max        =  121°50'00\"E",
+        );
+    assert_eq!(from_str(&s).unwrap(), expected);
+}
+
 #[test]
 fn empty_comment() {
     let s = fs::read_to_string("rsc/isg/example.1.empty_comment.isg").unwrap();
@@ -48,3 +73,105 @@ fn many_space() {
     let expected = from_str(&s).unwrap();
     assert_eq!(minified, expected)
 }
+
+#[test]
+fn compact() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    let expected = fs::read_to_string("rsc/isg/example.1.compact.isg").unwrap();
+    let compact = to_string_compact(&isg);
+    assert_eq!(compact, expected);
+    assert_eq!(from_str(&compact).unwrap(), isg);
+}
+
+#[test]
+fn lenient() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let expected = from_str(&s).unwrap();
+
+    // repeats header and inserts blank line in data section
+    let (head, data) = s.split_at(s.find("end_of_head").unwrap());
+    let (eoh, data) = data.split_at(data.find('\n').unwrap() + 1);
+    let s = format!(
+        "{}{}{}\n{}{}",
+        head,
+        eoh,
+        &data[..data.find('\n').unwrap() + 1],
+        "model name     : EXAMPLE\nend_of_head ====\n",
+        &data[data.find('\n').unwrap() + 1..]
+    );
+
+    assert!(from_str(&s).is_err());
+
+    let (isg, warnings) = from_str_lenient(&s).unwrap();
+    assert_eq!(isg, expected);
+    assert_eq!(warnings.len(), 3);
+    assert_eq!(warnings[0].lineno(), 46);
+    assert_eq!(warnings[0].line(), "");
+    assert_eq!(
+        warnings[1].to_string(),
+        "skipped non-data line: `model name     : EXAMPLE` (line: 47)"
+    );
+    assert_eq!(warnings[2].line(), "end_of_head ====");
+}
+
+#[test]
+fn lenient_header() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let expected = from_str(&s).unwrap();
+
+    // interleaves free-text comments in header section
+    let s = s
+        .replace(
+            "model type     : gravimetric\n",
+            "model type     : gravimetric\nconverted from GRD format\n",
+        )
+        .replace(
+            "ref frame      : ITRF2014\n",
+            "ref frame      : ITRF2014\nNote: the frame is as distributed\n\n",
+        );
+
+    assert!(from_str(&s).is_err());
+
+    let (isg, warnings) = from_str_lenient(&s).unwrap();
+    assert_eq!(isg, expected);
+    assert_eq!(warnings.len(), 3);
+    assert_eq!(warnings[0].line(), "converted from GRD format");
+    assert_eq!(
+        warnings[1].to_string(),
+        format!(
+            "skipped non-data line: `Note: the frame is as distributed` (line: {})",
+            warnings[0].lineno() + 7
+        )
+    );
+    assert_eq!(warnings[2].line(), "");
+
+    // unknown key with `=` is not free-text
+    let s = s.replace(
+        "converted from GRD format\n",
+        "converted = from GRD format\n",
+    );
+    assert!(from_str_lenient(&s).is_err());
+}
+
+#[test]
+fn header() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    let start = s.find("model name").unwrap();
+    let end = s.find("end_of_head").unwrap();
+    let section = &s[start..end];
+
+    let header: Header = section.parse().unwrap();
+    assert_eq!(header, isg.header);
+    assert_eq!(header.to_string(), section);
+
+    let s = fs::read_to_string("rsc/isg/example.1.minify.isg").unwrap();
+    let start = s.find("model name").unwrap();
+    let end = s.find("end_of_head").unwrap();
+    assert_eq!(s[start..end].parse::<Header>().unwrap(), isg.header);
+
+    assert!("model name : EXAMPLE".parse::<Header>().is_err());
+}
@@ -0,0 +1,85 @@
+use std::fs;
+
+use libisg::{from_str, CoordType};
+
+#[test]
+fn profile() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    let (start, end) = ((41.0, 120.0), (40.5, 121.2));
+    let profile = isg.profile(start, end, 5);
+    assert_eq!(profile.len(), 5);
+    for (i, (distance, value)) in profile.iter().enumerate() {
+        let t = i as f64 / 4.0;
+        assert!((distance - t * 1.3).abs() < 1e-9);
+        assert_eq!(
+            *value,
+            isg.interpolate(start.0 + t * -0.5, start.1 + t * 1.2)
+        );
+    }
+    // passes nodata
+    assert!(profile.iter().any(|(_, value)| value.is_none()));
+    assert!(profile.iter().any(|(_, value)| value.is_some()));
+
+    assert_eq!(isg.profile(start, end, 0), vec![]);
+    assert_eq!(
+        isg.profile(start, end, 1),
+        vec![(0.0, isg.interpolate(start.0, start.1))]
+    );
+    // outside
+    assert_eq!(
+        isg.profile((0.0, 0.0), (1.0, 1.0), 2),
+        vec![(0.0, None), (2f64.sqrt(), None)]
+    );
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    let profile = isg.profile(start, end, 2);
+    assert_eq!(profile.len(), 2);
+    assert!(profile.iter().all(|(_, value)| value.is_none()));
+}
+
+#[test]
+fn great_circle_profile() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+
+    // along a meridian, the same points as the straight line
+    let (start, end) = ((41.1, 120.5), (40.1, 120.5));
+    let profile = isg.great_circle_profile(start, end, 5).unwrap();
+    let straight = isg.profile(start, end, 5);
+    assert_eq!(profile.len(), 5);
+    for ((distance, value), (degree, expected)) in profile.iter().zip(&straight) {
+        assert!((distance - degree.to_radians() * 6_371_008.8).abs() < 1e-6);
+        match (value, expected) {
+            (Some(value), Some(expected)) => assert!((value - expected).abs() < 1e-9),
+            (value, expected) => assert_eq!(value, expected),
+        }
+    }
+
+    // a quarter of the equator
+    let profile = isg
+        .great_circle_profile((0.0, 0.0), (0.0, 90.0), 3)
+        .unwrap();
+    let quarter = std::f64::consts::FRAC_PI_2 * 6_371_008.8;
+    assert!((profile[1].0 - quarter / 2.0).abs() < 1e-6);
+    assert!((profile[2].0 - quarter).abs() < 1e-6);
+    assert!(profile.iter().all(|(_, value)| value.is_none()));
+
+    // the same point
+    let profile = isg.great_circle_profile(start, start, 2).unwrap();
+    assert_eq!(profile.len(), 2);
+    assert_eq!(profile[1].0, 0.0);
+    assert_eq!(profile[0].1, profile[1].1);
+
+    assert_eq!(isg.great_circle_profile(start, end, 0), Some(vec![]));
+    // antipodal
+    assert_eq!(
+        isg.great_circle_profile((10.0, 20.0), (-10.0, -160.0), 3),
+        None
+    );
+
+    isg.header.coord_type = CoordType::Projected;
+    assert_eq!(isg.great_circle_profile(start, end, 3), None);
+}
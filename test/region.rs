@@ -0,0 +1,72 @@
+use std::fs;
+
+use libisg::{from_str, Data, DataOrdering, Region};
+
+/// Rectangle of `(south, north, west, east)`
+pub(crate) struct Rect(pub f64, pub f64, pub f64, pub f64);
+
+impl Region for Rect {
+    fn contains(&self, a: f64, b: f64) -> bool {
+        (self.0..=self.1).contains(&a) && (self.2..=self.3).contains(&b)
+    }
+}
+
+/// Region with a hole
+pub(crate) struct Holed<R, S>(pub R, pub S);
+
+impl<R: Region, S: Region> Region for Holed<R, S> {
+    fn contains(&self, a: f64, b: f64) -> bool {
+        self.0.contains(a, b) && !self.1.contains(a, b)
+    }
+}
+
+#[test]
+fn mask() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+
+    // the upper-left 2 × 2 cells, with a hole around (0, 0)
+    let polygon = Holed(
+        Rect(40.7, 41.3, 119.7, 120.3),
+        Rect(41.0, 41.3, 119.7, 120.0),
+    );
+
+    let clipped = isg.mask(&polygon, true);
+    assert_eq!(clipped.header, isg.header);
+    assert_eq!(clipped.comment, isg.comment);
+    assert_eq!(clipped.data[(0, 0)], None);
+    assert_eq!(clipped.data[(0, 1)], isg.data[(0, 1)]);
+    assert_eq!(clipped.data[(1, 0)], isg.data[(1, 0)]);
+    assert_eq!(clipped.data[(1, 1)], isg.data[(1, 1)]);
+    assert_eq!(clipped.nodata_count(), 21);
+
+    let masked = isg.mask(&polygon, false);
+    assert_eq!(masked.data[(0, 0)], isg.data[(0, 0)]);
+    assert_eq!(masked.data[(1, 1)], None);
+    assert_eq!(masked.nodata_count(), 4 + 3);
+
+    // respects data ordering
+    assert!(isg.reorder(DataOrdering::S2NW2E));
+    let clipped = isg.mask(&polygon, true);
+    assert_eq!(clipped.data[(3, 1)], isg.data[(3, 1)]);
+    assert_eq!(clipped.data[(3, 0)], None);
+    assert_eq!(clipped.data[(0, 1)], None);
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    let polygon = Rect(40.9, 41.5, 119.5, 120.5);
+    match isg.mask(&polygon, true).data {
+        Data::Sparse(data) => assert_eq!(
+            data.iter().map(|r| r.2).collect::<Vec<_>>(),
+            [61.9999, 62.8888]
+        ),
+        Data::Grid(_) => unreachable!(),
+    }
+    assert_eq!(isg.mask(&polygon, false).data.len(), 18);
+
+    for keep_inside in [true, false] {
+        let masked = isg.mask(&polygon, keep_inside);
+        assert_eq!(masked.header.nrows, masked.data.len());
+        assert_eq!(from_str(&masked.to_string()).unwrap(), masked);
+    }
+}
@@ -0,0 +1,87 @@
+use std::fs;
+
+use libisg::{from_str, Coord, DataBounds, DataOrdering, Method};
+
+#[test]
+fn decimate() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    assert_eq!(isg.decimate(1, 1).unwrap(), isg);
+
+    let overview = isg.decimate(2, 4).unwrap();
+    assert_eq!((overview.header.nrows, overview.header.ncols), (2, 2));
+    assert_eq!(
+        overview.header.data_bounds,
+        DataBounds::GridGeodetic {
+            lat_min: Coord::with_dms(40, 10, 0),
+            lat_max: Coord::with_dms(41, 10, 0),
+            lon_min: Coord::with_dms(119, 50, 0),
+            lon_max: Coord::with_dms(121, 30, 0),
+            delta_lat: Coord::with_dms(0, 40, 0),
+            delta_lon: Coord::with_dms(1, 20, 0),
+        }
+    );
+    assert_eq!(overview.data[(0, 1)], isg.data[(0, 4)]);
+    assert_eq!(overview.data[(1, 0)], isg.data[(2, 0)]);
+    assert_eq!(overview.data[(1, 1)], None);
+    assert!(overview.validate().is_ok());
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    assert!(isg.decimate(2, 2).is_none());
+}
+
+#[test]
+fn upsample() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    assert_eq!(isg.upsample(1, Method::Bilinear).unwrap(), isg);
+
+    let fine = isg.upsample(4, Method::Bilinear).unwrap();
+    assert_eq!((fine.header.nrows, fine.header.ncols), (13, 21));
+    assert_eq!(
+        fine.header.data_bounds,
+        DataBounds::GridGeodetic {
+            lat_min: Coord::with_dms(39, 50, 0),
+            lat_max: Coord::with_dms(41, 10, 0),
+            lon_min: Coord::with_dms(119, 50, 0),
+            lon_max: Coord::with_dms(121, 50, 0),
+            delta_lat: Coord::with_dms(0, 5, 0),
+            delta_lon: Coord::with_dms(0, 5, 0),
+        }
+    );
+    assert_eq!(fine.data[(4, 8)], isg.data[(1, 2)]);
+    assert_eq!(fine.data[(12, 20)], None);
+    let expected = (30.1234 + 31.2222 + 41.1111 + 42.2345) / 4.0;
+    assert!((fine.data[(2, 2)].unwrap() - expected).abs() < 1e-9);
+    // next to nodata cells
+    assert_eq!(fine.data[(8, 13)], None);
+    assert!(fine.data[(8, 12)].is_some());
+
+    let nearest = isg.upsample(4, Method::Nearest).unwrap();
+    assert_eq!(nearest.data[(1, 1)], isg.data[(0, 0)]);
+    assert_eq!(nearest.data[(1, 2)], isg.data[(0, 1)]);
+    assert_eq!(nearest.data[(2, 2)], isg.data[(1, 1)]);
+
+    // 20' / 7 is not whole seconds
+    assert!(isg.upsample(7, Method::Nearest).is_none());
+}
+
+#[test]
+fn decimate_reordered() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    let mut reordered = isg.clone();
+    assert!(reordered.reorder(DataOrdering::S2NE2W));
+
+    // starts from the south-east cell
+    let mut overview = reordered.decimate(2, 4).unwrap();
+    assert!(overview.validate().is_ok());
+    assert!(overview.reorder(DataOrdering::N2SW2E));
+
+    let expected = isg.subgrid(1..4, 1..6).unwrap().decimate(2, 4).unwrap();
+    assert_eq!(overview.header.data_bounds, expected.header.data_bounds);
+    assert_eq!(overview.data, expected.data);
+}
@@ -0,0 +1,77 @@
+use std::fs;
+
+use libisg::{check_seams, from_str, Coord, Data, DataBounds, DataOrdering, SeamSide};
+
+#[test]
+fn seams() {
+    let s = fs::read_to_string("rsc/isg/example.2.isg").unwrap();
+    let a = from_str(&s).unwrap();
+
+    // east neighbor sharing `lon max` of `a`
+    let mut b = a.clone();
+    if let DataBounds::GridGeodetic {
+        lon_min, lon_max, ..
+    } = &mut b.header.data_bounds
+    {
+        *lon_min = Coord::with_dec(121.666667);
+        *lon_max = Coord::with_dec(123.333333);
+    }
+    if let (Data::Grid(a), Data::Grid(b)) = (&a.data, &mut b.data) {
+        for (src, dst) in a.rows().zip(b.rows_mut()) {
+            dst[0] = src[5];
+        }
+        let v = b.get_mut(0, 0).unwrap();
+        *v = v.map(|v| v + 0.01);
+    }
+
+    let report = check_seams(&a, &b, 0.005).unwrap();
+    assert_eq!(report.side, SeamSide::East);
+    assert!(report.shared);
+    assert_eq!(report.count, 2);
+    assert!((report.max_abs_diff - 0.01).abs() < 1e-9);
+    assert!((report.mean_abs_diff - 0.005).abs() < 1e-9);
+    assert_eq!(report.exceeded.len(), 1);
+    assert_eq!(report.exceeded[0].0, 0);
+
+    let report = check_seams(&b, &a, 0.005).unwrap();
+    assert_eq!(report.side, SeamSide::West);
+    assert_eq!(report.count, 2);
+
+    // not adjacent
+    assert_eq!(check_seams(&a, &a, 0.005), None);
+}
+
+#[test]
+fn seams_reordered() {
+    let s = fs::read_to_string("rsc/isg/example.2.isg").unwrap();
+    let mut a = from_str(&s).unwrap();
+
+    // east neighbor sharing `lon max` of `a`
+    let mut b = a.clone();
+    if let DataBounds::GridGeodetic {
+        lon_min, lon_max, ..
+    } = &mut b.header.data_bounds
+    {
+        *lon_min = Coord::with_dec(121.666667);
+        *lon_max = Coord::with_dec(123.333333);
+    }
+    if let (Data::Grid(a), Data::Grid(b)) = (&a.data, &mut b.data) {
+        for (src, dst) in a.rows().zip(b.rows_mut()) {
+            dst[0] = src[5];
+        }
+        let v = b.get_mut(0, 0).unwrap();
+        *v = v.map(|v| v + 0.01);
+    }
+
+    assert!(a.reorder(DataOrdering::S2NE2W));
+    assert!(b.reorder(DataOrdering::S2NW2E));
+
+    let report = check_seams(&a, &b, 0.005).unwrap();
+    assert_eq!(report.side, SeamSide::East);
+    assert_eq!(report.count, 2);
+    assert!((report.max_abs_diff - 0.01).abs() < 1e-9);
+    assert!((report.mean_abs_diff - 0.005).abs() < 1e-9);
+    assert_eq!(report.exceeded.len(), 1);
+    // the northernmost row is the last row of data
+    assert_eq!(report.exceeded[0].0, a.header.nrows - 1);
+}
@@ -39,7 +39,7 @@ fn example_1() {
             creation_date: Some(CreationDate::new(2020, 5, 31)),
             ISG_format: "2.0".into(),
         },
-        data: Data::Grid(vec![
+        data: Data::new_grid(vec![
             vec![
                 Some(30.1234),
                 Some(31.2222),
@@ -380,7 +380,7 @@ fn example_2() {
             creation_date: Some(CreationDate::new(2020, 5, 31)),
             ISG_format: "2.0".into(),
         },
-        data: Data::Grid(vec![
+        data: Data::new_grid(vec![
             vec![
                 Some(30.1234),
                 Some(31.2222),
@@ -0,0 +1,165 @@
+use std::fs;
+
+use libisg::{from_str, Coord, CoordType, Data, Region};
+
+use crate::region::{Holed, Rect};
+
+#[test]
+fn coverage() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    assert_eq!(isg.nodata_count(), 4);
+    assert_eq!(isg.coverage(), 20.0 / 24.0);
+
+    for row in 0..isg.header.nrows {
+        for col in 0..isg.header.ncols {
+            isg.data.set_nodata(row, col);
+        }
+    }
+    assert_eq!(isg.nodata_count(), 24);
+    assert_eq!(isg.coverage(), 0.0);
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    assert_eq!(isg.nodata_count(), 0);
+    assert_eq!(isg.coverage(), 1.0);
+}
+
+#[test]
+fn stats() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+
+    let values: Vec<_> = isg.data.values().collect();
+    let mean = values.iter().sum::<f64>() / 20.0;
+    let var = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / 20.0;
+
+    let stats = isg.stats().unwrap();
+    assert_eq!(stats.count, 20);
+    assert_eq!((stats.min, stats.max), (30.1234, 64.6666));
+    assert!((stats.mean - mean).abs() < 1e-9);
+    assert!((stats.std - var.sqrt()).abs() < 1e-9);
+
+    // sparse of the same values
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let sparse = from_str(&s).unwrap();
+    let actual = sparse.stats().unwrap();
+    assert_eq!(actual.count, 20);
+    assert!((actual.mean - stats.mean).abs() < 1e-9);
+    assert!((actual.std - stats.std).abs() < 1e-9);
+
+    // constant
+    let stats = isg.map_values(|_| 1.5).stats().unwrap();
+    assert_eq!(
+        (stats.mean, stats.min, stats.max, stats.std),
+        (1.5, 1.5, 1.5, 0.0)
+    );
+
+    for row in 0..isg.header.nrows {
+        for col in 0..isg.header.ncols {
+            isg.data.set_nodata(row, col);
+        }
+    }
+    assert_eq!(isg.stats(), None);
+}
+
+#[test]
+fn weighted_stats() {
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+
+    let (weights, values): (Vec<_>, Vec<_>) = isg
+        .cells()
+        .map(|(a, _, value)| match a {
+            Coord::Dec(a) => (a.to_radians().cos(), value.unwrap()),
+            Coord::DMS { .. } => unreachable!(),
+        })
+        .unzip();
+    let sum: f64 = weights.iter().sum();
+    let mean = weights.iter().zip(&values).map(|(w, v)| w * v).sum::<f64>() / sum;
+    let var = weights
+        .iter()
+        .zip(&values)
+        .map(|(w, v)| w * (v - mean).powi(2))
+        .sum::<f64>()
+        / sum;
+
+    let stats = isg.weighted_stats().unwrap();
+    assert_eq!(stats.count, 20);
+    assert_eq!((stats.min, stats.max), (30.1234, 64.6666));
+    assert!((stats.mean - mean).abs() < 1e-9);
+    assert!((stats.std - var.sqrt()).abs() < 1e-9);
+
+    // DMS grid of the same values
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let grid = from_str(&s).unwrap();
+    assert!(grid.weighted_stats().unwrap().mean > grid.stats().unwrap().mean);
+
+    // poles have (almost) no weight
+    isg.data = Data::Sparse(vec![
+        (Coord::Dec(90.0), Coord::Dec(0.0), 100.0),
+        (Coord::Dec(0.0), Coord::Dec(0.0), 1.0),
+    ]);
+    let stats = isg.weighted_stats().unwrap();
+    assert!((stats.mean - 1.0).abs() < 1e-9);
+    assert_eq!((stats.count, stats.max), (2, 100.0));
+
+    isg.data = Data::Sparse(vec![(Coord::Dec(-90.0), Coord::Dec(0.0), 1.0)]);
+    let stats = isg.weighted_stats().unwrap();
+    assert_eq!(stats.mean, 1.0);
+
+    // projected
+    isg.header.coord_type = CoordType::Projected;
+    isg.data = grid.data.clone();
+    assert_eq!(isg.weighted_stats(), isg.stats());
+}
+
+#[test]
+fn zonal_stats() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    // the whole grid
+    let polygon = Rect(39.0, 42.0, 119.0, 122.0);
+    let stats = isg.zonal_stats(&polygon).unwrap();
+    let values: Vec<_> = isg.data.values().collect();
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    let var = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / values.len() as f64;
+    assert_eq!(stats.count, 20);
+    assert!((stats.mean - mean).abs() < 1e-9);
+    assert!((stats.std - var.sqrt()).abs() < 1e-9);
+    assert_eq!(
+        stats.min,
+        values.iter().copied().fold(f64::INFINITY, f64::min)
+    );
+    assert_eq!(
+        stats.max,
+        values.iter().copied().fold(f64::NEG_INFINITY, f64::max)
+    );
+
+    // a hole around (0, 0)
+    let polygon = Holed(polygon, Rect(41.0, 41.3, 119.7, 120.0));
+    assert_eq!(isg.zonal_stats(&polygon).unwrap().count, 19);
+
+    // a single cell
+    let polygon = Rect(41.1, 41.2, 119.8, 119.9);
+    let stats = isg.zonal_stats(&polygon).unwrap();
+    assert_eq!((stats.count, stats.mean, stats.std), (1, 30.1234, 0.0));
+
+    // nodata only
+    let polygon = Rect(39.8, 40.4, 121.2, 122.0);
+    assert_eq!(isg.zonal_stats(&polygon), None);
+
+    // custom region
+    struct North;
+    impl Region for North {
+        fn contains(&self, a: f64, _: f64) -> bool {
+            a > 40.9
+        }
+    }
+    assert_eq!(isg.zonal_stats(North).unwrap().count, 6);
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    assert_eq!(isg.zonal_stats(North).unwrap().count, 4);
+}
@@ -1,4 +1,34 @@
+mod align;
+mod bounds;
+mod builder;
+mod comment;
+mod compare;
+#[cfg(feature = "conformance")]
+mod conformance;
+mod convert;
+mod coord;
+mod data;
+mod display;
 mod err;
+mod fill;
+mod filter;
+mod flags;
+mod geometry;
+mod gradient;
+mod hash;
+mod height;
+mod interpolate;
+mod merge;
+mod ordering;
 mod parse;
+mod profile;
+mod region;
+mod resample;
+mod seam;
 #[cfg(feature = "serde")]
 mod serde;
+mod stats;
+#[cfg(feature = "testing")]
+mod testing;
+mod transform;
+mod view;
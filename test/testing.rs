@@ -0,0 +1,44 @@
+use std::fs;
+
+use libisg::{assert_isg_eq, from_str, Data, IsgDiff};
+
+#[test]
+fn diff() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let a = from_str(&s).unwrap();
+
+    assert!(IsgDiff::compare(&a, &a).is_empty());
+    assert_isg_eq!(a, a.clone());
+
+    let mut b = a.clone();
+    b.header.model_name = Some("OTHER".into());
+    if let Data::Grid(data) = &mut b.data {
+        data[(1, 2)] = Some(0.0);
+        data[(3, 5)] = Some(1.0);
+    }
+
+    let diff = IsgDiff::compare(&a, &b);
+    assert!(!diff.comment);
+    assert_eq!(diff.header.len(), 1);
+    assert_eq!(diff.header[0].field, "model_name");
+    assert_eq!(diff.data.len(), 2);
+    assert_eq!((diff.data[0].row, diff.data[0].col), (1, 2));
+    assert_eq!(
+        diff.to_string(),
+        "header `model_name`: left: Some(\"EXAMPLE\"), right: Some(\"OTHER\")\n\
+         data (1, 2): left: Some(43.3333), right: Some(0.0)\n\
+         data (3, 5): left: None, right: Some(1.0)\n"
+    );
+}
+
+#[test]
+#[should_panic(expected = "data (1, 2): left: Some(43.3333), right: Some(0.0)")]
+fn assert_isg_eq_failure() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let a = from_str(&s).unwrap();
+    let mut b = a.clone();
+    if let Data::Grid(data) = &mut b.data {
+        data[(1, 2)] = Some(0.0);
+    }
+    assert_isg_eq!(a, b);
+}
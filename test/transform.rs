@@ -0,0 +1,240 @@
+use std::fs;
+
+use libisg::{
+    from_str, Convention, Coord, CoordUnits, Data, DataBounds, DataOrdering, DataUnits, DmsRounding,
+};
+
+#[test]
+fn map_values() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    let actual = isg.map_values(|v| v.clamp(40.0, 50.0));
+    assert_eq!(actual.header, isg.header);
+    assert_eq!(actual.data[(0, 0)], Some(40.0));
+    assert_eq!(actual.data[(1, 2)], isg.data[(1, 2)]);
+    assert_eq!(actual.data[(3, 4)], None);
+    assert_eq!(actual.nodata_count(), isg.nodata_count());
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    let expected: Vec<_> = isg.data.values().map(|v| v - 30.0).collect();
+    isg.map_values_in_place(|v| v - 30.0);
+    assert_eq!(isg.data.values().collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn difference() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let a = from_str(&s).unwrap();
+    let mut b = a.map_values(|v| v - 1.0);
+    b.data.set_nodata(0, 0);
+
+    let diff = a.difference(&b).unwrap();
+    assert_eq!(diff.header, a.header);
+    assert_eq!(diff.data[(0, 0)], None);
+    assert_eq!(diff.data[(3, 5)], None);
+    assert!(diff.data.values().all(|v| (v - 1.0).abs() < 1e-9));
+    assert_eq!(diff.nodata_count(), 5);
+
+    let sum = a.add(&b).unwrap();
+    assert_eq!(sum.data[(0, 1)], Some(31.2222 * 2.0 - 1.0));
+
+    let mut c = a.clone();
+    c.header.data_units = Some(DataUnits::Feet);
+    assert_eq!(
+        a.difference(&c).unwrap_err().to_string(),
+        "`data units` mismatch"
+    );
+
+    let view = a.view(0..2, 0..6).unwrap().to_isg();
+    assert_eq!(
+        a.difference(&view).unwrap_err().to_string(),
+        "`data bounds` mismatch"
+    );
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let sparse = from_str(&s).unwrap();
+    assert_eq!(
+        sparse.difference(&sparse).unwrap_err().to_string(),
+        "data is not grid"
+    );
+}
+
+#[test]
+fn apply_linear() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let org = from_str(&s).unwrap();
+
+    let mut isg = org.clone();
+    isg.apply_linear(2.0, -1.0, None);
+    assert_eq!(isg.header, org.header);
+    assert_eq!(isg.data[(0, 0)], Some(2.0 * 30.1234 - 1.0));
+    assert_eq!(isg.data[(3, 5)], None);
+
+    isg.apply_linear(1.0 / 0.3048, 0.0, Some(DataUnits::Feet));
+    assert_eq!(isg.header.data_units, Some(DataUnits::Feet));
+}
+
+#[test]
+fn convert_coord_units() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let org = from_str(&s).unwrap();
+
+    let mut isg = org.clone();
+    assert!(isg.convert_coord_units(CoordUnits::Deg));
+    assert_eq!(isg.header.coord_units, CoordUnits::Deg);
+    assert!(isg.validate().is_ok());
+
+    assert!(isg.convert_coord_units(CoordUnits::DMS));
+    assert_eq!(isg, org);
+
+    assert!(!isg.convert_coord_units(CoordUnits::Feet));
+    assert_eq!(isg, org);
+
+    // rounding to the nearest second
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    assert!(isg.convert_coord_units(CoordUnits::DMS));
+    assert!(isg.validate().is_ok());
+    assert_eq!(isg.data.sparse_data()[1].1, Coord::with_dms(120, 20, 0));
+}
+
+#[test]
+fn convert_coord_units_with() {
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let org = from_str(&s).unwrap();
+
+    // 120.333333 is 120°19'59.9988"
+    let mut isg = org.clone();
+    assert_eq!(
+        isg.convert_coord_units_with(CoordUnits::DMS, DmsRounding::Truncate),
+        Ok(true)
+    );
+    assert_eq!(isg.data.sparse_data()[1].1, Coord::with_dms(120, 19, 59));
+    assert_eq!(isg.data.sparse_data()[0].1, Coord::with_dms(120, 0, 0));
+    assert!(matches!(
+        isg.header.data_bounds,
+        DataBounds::SparseGeodetic { lon_max, .. } if lon_max == Coord::with_dms(121, 40, 0)
+    ));
+
+    let mut isg = org.clone();
+    assert_eq!(
+        isg.convert_coord_units_with(CoordUnits::DMS, DmsRounding::Nearest),
+        Ok(true)
+    );
+    let mut expected = org.clone();
+    assert!(expected.convert_coord_units(CoordUnits::DMS));
+    assert_eq!(isg, expected);
+
+    // untouched on error
+    let mut isg = org.clone();
+    let err = isg
+        .convert_coord_units_with(CoordUnits::DMS, DmsRounding::Exact)
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "not representable in DMS, actual: 121.666667°"
+    );
+    assert_eq!(isg, org);
+
+    let mut isg = org.clone();
+    if let Data::Sparse(data) = &mut isg.data {
+        data[7].0 = Coord::with_dec(f64::NAN);
+    }
+    let modified = isg.clone();
+    assert!(isg
+        .convert_coord_units_with(CoordUnits::DMS, DmsRounding::Nearest)
+        .is_err());
+    assert!(!isg.convert_coord_units(CoordUnits::DMS));
+    assert_eq!(format!("{:?}", isg), format!("{:?}", modified));
+
+    // exact and other conversions
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let org = from_str(&s).unwrap();
+    let mut isg = org.clone();
+    assert!(isg.convert_coord_units(CoordUnits::Deg));
+    assert_eq!(
+        isg.convert_coord_units_with(CoordUnits::DMS, DmsRounding::Exact),
+        Ok(true)
+    );
+    assert_eq!(isg, org);
+    assert_eq!(
+        isg.convert_coord_units_with(CoordUnits::Feet, DmsRounding::Exact),
+        Ok(false)
+    );
+}
+
+#[test]
+fn normalize_longitudes() {
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let expected = from_str(&s).unwrap();
+
+    let mut isg = expected.clone();
+    assert!(isg.normalize_longitudes(Convention::Signed180));
+    assert!(isg.normalize_longitudes(Convention::Unsigned360));
+    assert_eq!(isg, expected);
+
+    // 120°E to 240°E
+    if let Data::Sparse(data) = &mut isg.data {
+        data[0].1 = Coord::with_dec(240.0);
+    }
+    if let DataBounds::SparseGeodetic { lon_max, .. } = &mut isg.header.data_bounds {
+        *lon_max = Coord::with_dec(240.0);
+    }
+    assert!(isg.normalize_longitudes(Convention::Signed180));
+    assert_eq!(isg.data.sparse_data()[0].1, Coord::with_dec(-120.0));
+    assert_eq!(
+        isg.data.sparse_data()[1].1,
+        expected.data.sparse_data()[1].1
+    );
+    assert!(matches!(
+        isg.header.data_bounds,
+        DataBounds::SparseGeodetic { lon_min, lon_max, .. }
+            if lon_min == Coord::with_dec(120.0) && lon_max == Coord::with_dec(-120.0)
+    ));
+    assert!(isg.normalize_longitudes(Convention::Unsigned360));
+    assert_eq!(isg.data.sparse_data()[0].1, Coord::with_dec(240.0));
+
+    // grid is not moved
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let mut isg = from_str(&s).unwrap();
+    if let DataBounds::GridGeodetic {
+        lon_min, lon_max, ..
+    } = &mut isg.header.data_bounds
+    {
+        *lon_min = Coord::with_dms(-1, 0, 0);
+        *lon_max = Coord::with_dms(1, 0, 0);
+    }
+    let value = isg.value_at(41.1, -1.0);
+    assert!(value.is_some());
+    assert!(isg.normalize_longitudes(Convention::Unsigned360));
+    assert!(matches!(
+        isg.header.data_bounds,
+        DataBounds::GridGeodetic { lon_min, lon_max, .. }
+            if lon_min == Coord::with_dms(359, 0, 0) && lon_max == Coord::with_dms(1, 0, 0)
+    ));
+    assert_eq!(isg.value_at(41.1, 359.0), value);
+    assert_eq!(isg.data, from_str(&s).unwrap().data);
+}
+
+#[test]
+fn difference_reordered() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    let mut reordered = isg.clone();
+    assert!(reordered.reorder(DataOrdering::S2NE2W));
+
+    let diff = reordered.difference(&isg).unwrap();
+    assert_eq!(diff.header.data_ordering, Some(DataOrdering::S2NE2W));
+    assert_eq!(diff.nodata_count(), isg.nodata_count());
+    assert!(diff
+        .data
+        .grid_data()
+        .rows()
+        .flatten()
+        .all(|v| v.map_or(true, |v| v == 0.0)));
+
+    let sum = isg.add(&reordered).unwrap();
+    assert_eq!(sum.data, isg.map_values(|v| 2.0 * v).data);
+}
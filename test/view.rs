@@ -0,0 +1,119 @@
+use std::fs;
+
+use libisg::{from_str, Coord, DataBounds, DataOrdering};
+
+#[test]
+fn view() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    let view = isg.view(0..4, 0..6).unwrap();
+    assert_eq!(view.data_bounds(), &isg.header.data_bounds);
+    assert_eq!(view.to_isg().data, isg.data);
+
+    let view = isg.view(1..3, 2..6).unwrap();
+    assert_eq!(view.offset(), (1, 2));
+    assert_eq!(
+        view.row(1).unwrap(),
+        &isg.data.grid_data().row(2).unwrap()[2..6]
+    );
+    assert_eq!(view.rows().count(), 2);
+    assert_eq!(view.get(1, 3), Some(&None));
+    assert_eq!(view.get(2, 0), None);
+    assert_eq!(
+        view.data_bounds(),
+        &DataBounds::GridGeodetic {
+            lat_min: Coord::with_dms(40, 10, 0),
+            lat_max: Coord::with_dms(40, 50, 0),
+            lon_min: Coord::with_dms(120, 30, 0),
+            lon_max: Coord::with_dms(121, 50, 0),
+            delta_lat: Coord::with_dms(0, 20, 0),
+            delta_lon: Coord::with_dms(0, 20, 0),
+        }
+    );
+
+    let sub = view.to_isg();
+    assert_eq!((sub.header.nrows, sub.header.ncols), (2, 4));
+    assert_eq!(sub.data[(0, 0)], isg.data[(1, 2)]);
+    assert!(sub.validate().is_ok());
+
+    assert!(isg.view(0..5, 0..6).is_none());
+    assert!(isg.view(1..1, 0..6).is_none());
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    assert!(isg.view(0..1, 0..1).is_none());
+}
+
+#[test]
+fn tiles() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    let tiles: Vec<_> = isg.tiles(2, 4).collect();
+    assert_eq!(tiles.len(), 4);
+    assert_eq!(
+        tiles.iter().map(|view| view.offset()).collect::<Vec<_>>(),
+        vec![(0, 0), (0, 4), (2, 0), (2, 4)]
+    );
+    assert_eq!(
+        tiles
+            .iter()
+            .map(|view| view.rows().flatten().count())
+            .sum::<usize>(),
+        24
+    );
+    assert_eq!(tiles[3].get(1, 1), isg.data.get(3, 5));
+    assert_eq!(
+        tiles[3].data_bounds(),
+        isg.view(2..4, 4..6).unwrap().data_bounds()
+    );
+
+    let s = fs::read_to_string("rsc/isg/example.3.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    assert_eq!(isg.tiles(1, 1).count(), 0);
+}
+
+#[test]
+fn subgrid() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+
+    assert_eq!(isg.subgrid(0..4, 0..6).unwrap(), isg);
+
+    let sub = isg.subgrid(1..3, 2..6).unwrap();
+    assert_eq!(sub.comment, isg.comment);
+    assert_eq!(
+        &sub.header.data_bounds,
+        isg.view(1..3, 2..6).unwrap().data_bounds()
+    );
+    assert_eq!(sub.data[(1, 3)], None);
+    assert!(sub.validate().is_ok());
+
+    assert!(isg.subgrid(3..3, 0..6).is_none());
+    assert!(isg.subgrid(0..4, 0..7).is_none());
+}
+
+#[test]
+fn view_reordered() {
+    let s = fs::read_to_string("rsc/isg/example.1.isg").unwrap();
+    let isg = from_str(&s).unwrap();
+    let mut reordered = isg.clone();
+    assert!(reordered.reorder(DataOrdering::S2NE2W));
+
+    // the first row of data is the southernmost, and the first column is the easternmost
+    let view = reordered.view(0..1, 0..2).unwrap();
+    let expected = isg.view(3..4, 4..6).unwrap();
+    assert_eq!(view.data_bounds(), expected.data_bounds());
+
+    let mut sub = reordered.subgrid(1..3, 2..5).unwrap();
+    assert!(sub.validate().is_ok());
+    assert!(sub.reorder(DataOrdering::N2SW2E));
+    let expected = isg.subgrid(1..3, 1..4).unwrap();
+    assert_eq!(sub.header.data_bounds, expected.header.data_bounds);
+    assert_eq!(sub.data, expected.data);
+
+    let tile = reordered.tiles(2, 4).next().unwrap();
+    let expected = isg.view(2..4, 2..6).unwrap();
+    assert_eq!(tile.data_bounds(), expected.data_bounds());
+}